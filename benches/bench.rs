@@ -1,7 +1,12 @@
 #[allow(unused_imports)]
 use criterion::{black_box, criterion_main, Criterion};
+use rand::Rng;
 use rand_pcg::Lcg64Xsh32;
 use rkyv::Archived;
+#[cfg(feature = "apache-avro")]
+use rust_serialization_benchmark::bench_avro;
+#[cfg(feature = "bebop")]
+use rust_serialization_benchmark::bench_bebop;
 #[cfg(feature = "bilrost")]
 use rust_serialization_benchmark::bench_bilrost;
 #[cfg(feature = "bincode")]
@@ -12,6 +17,10 @@ use rust_serialization_benchmark::bench_bincode1;
 use rust_serialization_benchmark::bench_bitcode;
 #[cfg(feature = "borsh")]
 use rust_serialization_benchmark::bench_borsh;
+#[cfg(feature = "bson")]
+use rust_serialization_benchmark::bench_bson;
+#[cfg(feature = "bytemuck")]
+use rust_serialization_benchmark::bench_bytemuck;
 #[cfg(feature = "capnp")]
 use rust_serialization_benchmark::bench_capnp;
 #[cfg(feature = "cbor4ii")]
@@ -22,10 +31,20 @@ use rust_serialization_benchmark::bench_ciborium;
 use rust_serialization_benchmark::bench_databuf;
 #[cfg(feature = "dlhn")]
 use rust_serialization_benchmark::bench_dlhn;
+#[cfg(feature = "ssz")]
+use rust_serialization_benchmark::bench_ethereum_ssz;
 #[cfg(feature = "flatbuffers")]
 use rust_serialization_benchmark::bench_flatbuffers;
+#[cfg(feature = "flexbuffers")]
+use rust_serialization_benchmark::bench_flexbuffers;
+#[cfg(feature = "ion-rs")]
+use rust_serialization_benchmark::bench_ion_rs;
 #[cfg(feature = "msgpacker")]
 use rust_serialization_benchmark::bench_msgpacker;
+#[cfg(feature = "musli")]
+use rust_serialization_benchmark::bench_musli;
+#[cfg(feature = "musli-zerocopy")]
+use rust_serialization_benchmark::bench_musli_zerocopy;
 #[cfg(feature = "nachricht-serde")]
 use rust_serialization_benchmark::bench_nachricht_serde;
 #[cfg(feature = "nanoserde")]
@@ -38,6 +57,14 @@ use rust_serialization_benchmark::bench_postcard;
 use rust_serialization_benchmark::bench_pot;
 #[cfg(feature = "prost")]
 use rust_serialization_benchmark::bench_prost;
+#[cfg(feature = "protobuf")]
+use rust_serialization_benchmark::bench_protobuf;
+#[cfg(feature = "quick-protobuf")]
+use rust_serialization_benchmark::bench_quick_protobuf;
+#[cfg(feature = "quick-xml")]
+use rust_serialization_benchmark::bench_quick_xml;
+#[cfg(feature = "rasn")]
+use rust_serialization_benchmark::bench_rasn;
 #[cfg(feature = "rkyv")]
 use rust_serialization_benchmark::bench_rkyv;
 #[cfg(feature = "rmp-serde")]
@@ -54,79 +81,287 @@ use rust_serialization_benchmark::bench_serde_brief;
 use rust_serialization_benchmark::bench_serde_cbor;
 #[cfg(feature = "serde_json")]
 use rust_serialization_benchmark::bench_serde_json;
+#[cfg(feature = "serde_yaml")]
+use rust_serialization_benchmark::bench_serde_yaml;
 #[cfg(feature = "simd-json")]
 use rust_serialization_benchmark::bench_simd_json;
+#[cfg(feature = "sonic-rs")]
+use rust_serialization_benchmark::bench_sonic_rs;
 #[cfg(feature = "speedy")]
 use rust_serialization_benchmark::bench_speedy;
+#[cfg(feature = "thrift")]
+use rust_serialization_benchmark::bench_thrift;
+#[cfg(feature = "toml")]
+use rust_serialization_benchmark::bench_toml;
+#[cfg(feature = "transmute")]
+use rust_serialization_benchmark::bench_transmute;
 #[cfg(feature = "wiring")]
 use rust_serialization_benchmark::bench_wiring;
+#[cfg(feature = "zerocopy")]
+use rust_serialization_benchmark::bench_zerocopy;
+use rust_serialization_benchmark::datasets::log::{Log, Logs};
+use rust_serialization_benchmark::datasets::mesh::{Mesh, Triangle};
+use rust_serialization_benchmark::datasets::minecraft_savedata::{Player, Players};
+use rust_serialization_benchmark::datasets::mk48::Updates;
 use rust_serialization_benchmark::datasets::page::{Page, PageHeader};
-use rust_serialization_benchmark::{bench_transmute, generate_vec};
+use rust_serialization_benchmark::datasets::tree::Trees;
+use rust_serialization_benchmark::{bench_scale, bench_seed, generate_vec};
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::io::{self, BufReader};
 use std::mem::transmute;
+use std::path::Path;
 
-fn bench_log(c: &mut Criterion) {
-    use rust_serialization_benchmark::datasets::log::{Log, Logs};
+/// The number of elements read by the `read_random` benchmarks.
+const RANDOM_READS: usize = 100;
+
+/// Picks `RANDOM_READS` indices into `len` elements from a fixed PCG sequence, so that every crate
+/// reads the same elements in the same order.
+fn random_indices(len: usize) -> Vec<usize> {
+    // nothing up our sleeves, state and stream are the next 20 digits of pi
+    const STATE: u64 = 6264338327;
+    const STREAM: u64 = 9502884197;
+
+    let mut rng = Lcg64Xsh32::new(STATE, STREAM);
+    (0..RANDOM_READS).map(|_| rng.gen_range(0..len)).collect()
+}
+
+/// The element counts to generate a data set with, paired with the name to report it under.
+///
+/// `BENCH_SCALE` overrides the default count with a comma-separated list such as `1,10,1k,100k`.
+/// Each count then gets its own `<name>-<count>` data set so that the formatter can compare them.
+fn scales(name: &'static str, default: usize) -> Vec<(&'static str, usize)> {
+    let Ok(scales) = std::env::var("BENCH_SCALE") else {
+        return vec![(name, default)];
+    };
 
-    const BENCH: &'static str = "log";
+    scales
+        .split(',')
+        .map(|scale| {
+            let scale = scale.trim();
+            let (digits, factor) = if let Some(digits) = scale.strip_suffix('k') {
+                (digits, 1_000)
+            } else if let Some(digits) = scale.strip_suffix('m') {
+                (digits, 1_000_000)
+            } else {
+                (scale, 1)
+            };
+            let len = digits
+                .parse::<usize>()
+                .ok()
+                .map(|n| n * factor)
+                .filter(|&len| len > 0)
+                .unwrap_or_else(|| panic!("invalid BENCH_SCALE element count {scale:?}"));
+            // The bench functions take `&'static str` names, and this only leaks one per scale
+            let name: &'static str = Box::leak(format!("{name}-{len}").into_boxed_str());
+            (name, len)
+        })
+        .collect()
+}
 
-    // nothing up our sleeves, state and stream are first 20 digits of pi
+/// The PCG states to generate a data set from, paired with the name to report it under.
+///
+/// `BENCH_SEEDS` replaces the default state with a comma-separated list of them. Each state then
+/// gets its own `<name>-seed<state>` data set, so that the formatter can show how much each crate's
+/// results depend on the particular values it was given.
+fn seeds(name: &'static str) -> Vec<(&'static str, u64)> {
+    // nothing up our sleeves, state is the first 10 digits of pi
     const STATE: u64 = 3141592653;
-    const STREAM: u64 = 5897932384;
 
-    let mut rng = Lcg64Xsh32::new(STATE, STREAM);
+    let Ok(seeds) = std::env::var("BENCH_SEEDS") else {
+        return vec![(name, STATE)];
+    };
 
-    const LOGS: usize = 10_000;
-    let data = Logs {
-        logs: generate_vec::<_, Log>(&mut rng, LOGS..LOGS + 1),
+    seeds
+        .split(',')
+        .map(|seed| {
+            let state = seed
+                .trim()
+                .parse::<u64>()
+                .unwrap_or_else(|_| panic!("invalid BENCH_SEEDS state {seed:?}"));
+            let name: &'static str = Box::leak(format!("{name}-seed{state}").into_boxed_str());
+            (name, state)
+        })
+        .collect()
+}
+
+/// Loads `<name>.json` from the directory that `BENCH_CORPUS` names, if it has one, so that real data
+/// can be benchmarked in place of the generated data set.
+///
+/// Panics if the directory can't be read, or if the file can't be loaded or `len` finds no elements in
+/// it to benchmark.
+fn corpus<T: DeserializeOwned>(name: &str, len: impl FnOnce(&T) -> usize) -> Option<T> {
+    let dir = std::env::var_os("BENCH_CORPUS")?;
+    let dir = Path::new(&dir);
+    if let Err(e) = std::fs::read_dir(dir) {
+        panic!("failed to read BENCH_CORPUS {}: {e}", dir.display());
+    }
+
+    let path = dir.join(format!("{name}.json"));
+    let file = match File::open(&path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return None,
+        Err(e) => panic!("failed to open {}: {e}", path.display()),
     };
+    let data = serde_json::from_reader(BufReader::new(file))
+        .unwrap_or_else(|e| panic!("failed to load {}: {e}", path.display()));
+    if len(&data) == 0 {
+        panic!("{} has no elements to benchmark", path.display());
+    }
+    Some(data)
+}
+
+fn bench_log(c: &mut Criterion) {
+    const LOGS: usize = 10_000;
+
+    if let Some(data) = corpus("log", |data: &Logs| data.logs.len()) {
+        bench_scale("log-corpus", data.logs.len());
+        bench_log_with(c, "log-corpus", data);
+        return;
+    }
+
+    for (name, len) in scales("log", LOGS) {
+        for (name, state) in seeds(name) {
+            // nothing up our sleeves, stream is the second 10 digits of pi
+            const STREAM: u64 = 5897932384;
+
+            let mut rng = Lcg64Xsh32::new(state, STREAM);
+
+            let data = Logs {
+                logs: generate_vec::<_, Log>(&mut rng, len..len + 1),
+            };
+            bench_scale(name, len);
+            bench_seed(name, state);
+            bench_log_with(c, name, data);
+        }
+    }
+}
+
+fn bench_log_with(c: &mut Criterion, name: &'static str, data: Logs) {
+    let indices = random_indices(data.logs.len());
+
+    #[cfg(feature = "apache-avro")]
+    bench_avro::bench(name, c, &data);
 
     #[cfg(feature = "bilrost")]
-    bench_bilrost::bench(BENCH, c, &data);
+    bench_bilrost::bench(name, c, &data);
 
     #[cfg(feature = "bincode1")]
-    bench_bincode1::bench(BENCH, c, &data);
+    {
+        bench_bincode1::bench(name, c, &data);
+        bench_bincode1::bench_borrowed(name, c, &data);
+    }
 
     #[cfg(feature = "bincode")]
-    bench_bincode::bench(BENCH, c, &data);
+    {
+        bench_bincode::bench(name, c, &data);
+        bench_bincode::bench_serde(name, c, &data);
+        bench_bincode::bench_read_random(name, c, &data, &indices);
+    }
 
     #[cfg(feature = "bitcode")]
-    bench_bitcode::bench(BENCH, c, &data);
+    {
+        bench_bitcode::bench(name, c, &data);
+        bench_bitcode::bench_serde(name, c, &data);
+        bench_bitcode::bench_read_random(name, c, &data, &indices);
+    }
 
     #[cfg(feature = "borsh")]
-    bench_borsh::bench(BENCH, c, &data);
+    {
+        bench_borsh::bench(name, c, &data);
+        bench_borsh::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "bson")]
+    {
+        bench_bson::bench(name, c, &data);
+        bench_bson::bench_borrowed(name, c, &data);
+        bench_bson::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "serde-brief")]
-    bench_serde_brief::bench(BENCH, c, &data);
+    {
+        bench_serde_brief::bench(name, c, &data);
+        bench_serde_brief::bench_borrowed(name, c, &data);
+        bench_serde_brief::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "capnp")]
-    bench_capnp::bench(BENCH, c, &data, |bytes| {
-        let message_reader =
-            capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
-        let data = message_reader
-            .get_root::<rust_serialization_benchmark::datasets::log::cp::logs::Reader>()
-            .unwrap();
-        for log in data.get_logs().unwrap().iter() {
-            black_box(log.get_address().unwrap());
-            black_box(log.get_code());
-            black_box(log.get_size());
-        }
-    });
+    {
+        use rust_serialization_benchmark::datasets::log::cp;
+
+        bench_capnp::bench(
+            name,
+            c,
+            &data,
+            |bytes, options| {
+                let message_reader =
+                    capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+                let data = message_reader.get_root::<cp::logs::Reader>()?;
+                for log in data.get_logs()?.iter() {
+                    black_box(log.get_address()?);
+                    black_box(log.get_code());
+                    black_box(log.get_size());
+                }
+                Ok(())
+            },
+            |root| {
+                let data = root.get_as::<cp::logs::Builder>()?;
+                let mut logs = data.get_logs()?;
+                for i in 0..logs.len() {
+                    let mut log = logs.reborrow().get(i);
+                    let mut address = log.reborrow().get_address()?;
+                    address.set_x0(0);
+                    address.set_x1(0);
+                    address.set_x2(0);
+                    address.set_x3(0);
+                    log.set_code(200);
+                    log.set_size(0);
+                }
+                Ok(())
+            },
+        );
+        bench_capnp::bench_packed(name, c, &data);
+        bench_capnp::bench_read_random(name, c, &data, &indices, |bytes, indices, options| {
+            let message_reader = capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+            let logs = message_reader.get_root::<cp::logs::Reader>()?.get_logs()?;
+            for &i in indices {
+                let log = logs.get(i as u32);
+                black_box(log.get_address()?);
+                black_box(log.get_code());
+                black_box(log.get_size());
+            }
+            Ok(())
+        });
+        bench_capnp::bench_project(name, c, &data, |bytes, options| {
+            let message_reader = capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+            let data = message_reader.get_root::<cp::logs::Reader>()?;
+            for log in data.get_logs()?.iter() {
+                black_box(log.get_code());
+            }
+            Ok(())
+        });
+    }
 
     #[cfg(feature = "cbor4ii")]
-    bench_cbor4ii::bench(BENCH, c, &data);
+    {
+        bench_cbor4ii::bench(name, c, &data);
+        bench_cbor4ii::bench_borrowed(name, c, &data);
+        bench_cbor4ii::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "ciborium")]
-    bench_ciborium::bench(BENCH, c, &data);
+    bench_ciborium::bench(name, c, &data);
 
     #[cfg(feature = "databuf")]
-    bench_databuf::bench(BENCH, c, &data);
+    bench_databuf::bench(name, c, &data);
 
     #[cfg(feature = "dlhn")]
-    bench_dlhn::bench(BENCH, c, &data);
+    bench_dlhn::bench(name, c, &data);
 
     #[cfg(feature = "flatbuffers")]
     bench_flatbuffers::bench(
-        BENCH,
+        name,
         c,
         &data,
         |bytes| unsafe {
@@ -139,39 +374,204 @@ fn bench_log(c: &mut Criterion) {
                 black_box(log.size_());
             }
         },
-        |bytes| {
-            let data =
-                flatbuffers::root::<rust_serialization_benchmark::datasets::log::fb::Logs>(bytes)
-                    .unwrap();
+        |bytes, options| {
+            let data = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::log::fb::Logs,
+            >(options, bytes)
+            .unwrap();
             for log in data.logs().iter() {
                 black_box(log.address());
                 black_box(log.code());
                 black_box(log.size_());
             }
         },
+        |bytes| {
+            use rust_serialization_benchmark::datasets::log::fb;
+
+            let mut data = bench_flatbuffers::TableMut::root(bytes);
+            let mut logs = data.vector_mut(fb::Logs::VT_LOGS).unwrap();
+            for i in 0..logs.len() {
+                let mut log = logs.table_mut(i);
+                let address = unsafe { log.struct_mut::<fb::Address>(fb::Log::VT_ADDRESS) };
+                if let Some(address) = address {
+                    address.set_x0(0);
+                    address.set_x1(0);
+                    address.set_x2(0);
+                    address.set_x3(0);
+                }
+                log.mutate(fb::Log::VT_CODE, 200u16);
+                log.mutate(fb::Log::VT_SIZE_, 0u64);
+            }
+        },
+    );
+
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench_read_random(
+        name,
+        c,
+        &data,
+        &indices,
+        |bytes, indices| unsafe {
+            let logs = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::log::fb::Logs,
+            >(bytes)
+            .logs();
+            for &i in indices {
+                let log = logs.get(i);
+                black_box(log.address());
+                black_box(log.code());
+                black_box(log.size_());
+            }
+        },
+        |bytes, indices, options| {
+            let logs = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::log::fb::Logs,
+            >(options, bytes)
+            .unwrap()
+            .logs();
+            for &i in indices {
+                let log = logs.get(i);
+                black_box(log.address());
+                black_box(log.code());
+                black_box(log.size_());
+            }
+        },
+    );
+
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench_project(
+        name,
+        c,
+        &data,
+        |bytes| unsafe {
+            let data = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::log::fb::Logs,
+            >(bytes);
+            for log in data.logs().iter() {
+                black_box(log.code());
+            }
+        },
+        |bytes, options| {
+            let data = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::log::fb::Logs,
+            >(options, bytes)
+            .unwrap();
+            for log in data.logs().iter() {
+                black_box(log.code());
+            }
+        },
     );
 
+    #[cfg(feature = "flexbuffers")]
+    bench_flexbuffers::bench(name, c, &data);
+
+    #[cfg(feature = "ion-rs")]
+    bench_ion_rs::bench(name, c, &data);
+
     #[cfg(feature = "msgpacker")]
-    bench_msgpacker::bench(BENCH, c, &data);
+    bench_msgpacker::bench(name, c, &data);
+
+    #[cfg(feature = "musli")]
+    {
+        bench_musli::bench_storage(name, c, &data);
+        bench_musli::bench_wire(name, c, &data);
+        bench_musli::bench_descriptive(name, c, &data);
+    }
+
+    #[cfg(feature = "musli-zerocopy")]
+    bench_musli_zerocopy::bench(
+        name,
+        c,
+        &data,
+        |buf| {
+            use rust_serialization_benchmark::datasets::log::zc;
+
+            let data = buf.load_at::<zc::Logs>(0).unwrap();
+            for log in buf.load(data.logs).unwrap().iter() {
+                black_box(&log.address);
+                black_box(log.code);
+                black_box(log.size);
+            }
+        },
+        |buf| {
+            use rust_serialization_benchmark::datasets::log::zc;
+
+            let logs = buf.load_at::<zc::Logs>(0).unwrap().logs;
+            for log in buf.load_mut(logs).unwrap().iter_mut() {
+                log.address = zc::Address {
+                    x0: 0,
+                    x1: 0,
+                    x2: 0,
+                    x3: 0,
+                };
+                log.code = 200;
+                log.size = 0;
+            }
+        },
+    );
+
+    #[cfg(feature = "musli-zerocopy")]
+    bench_musli_zerocopy::bench_read_random(name, c, &data, &indices, |buf, indices| {
+        use rust_serialization_benchmark::datasets::log::zc;
+
+        let data = buf.load_at::<zc::Logs>(0).unwrap();
+        let logs = buf.load(data.logs).unwrap();
+        for &i in indices {
+            let log = &logs[i];
+            black_box(&log.address);
+            black_box(log.code);
+            black_box(log.size);
+        }
+    });
 
     #[cfg(feature = "nachricht-serde")]
-    bench_nachricht_serde::bench(BENCH, c, &data);
+    {
+        bench_nachricht_serde::bench(name, c, &data);
+        bench_nachricht_serde::bench_borrowed(name, c, &data);
+        bench_nachricht_serde::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "scale")]
-    bench_parity_scale_codec::bench(BENCH, c, &data);
+    bench_parity_scale_codec::bench(name, c, &data);
 
     #[cfg(feature = "postcard")]
-    bench_postcard::bench(BENCH, c, &data);
+    {
+        bench_postcard::bench(name, c, &data);
+        bench_postcard::bench_borrowed(name, c, &data);
+        bench_postcard::bench_read_random(name, c, &data, &indices);
+    }
 
     #[cfg(feature = "pot")]
-    bench_pot::bench(BENCH, c, &data);
+    {
+        bench_pot::bench(name, c, &data);
+        bench_pot::bench_borrowed(name, c, &data);
+    }
 
     #[cfg(feature = "prost")]
-    bench_prost::bench(BENCH, c, &data);
+    {
+        bench_prost::bench(name, c, &data);
+        bench_prost::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "protobuf")]
+    bench_protobuf::bench(name, c, &data);
+
+    #[cfg(feature = "quick-protobuf")]
+    bench_quick_protobuf::bench(name, c, &data);
+
+    #[cfg(feature = "quick-xml")]
+    bench_quick_xml::bench(name, c, &data);
+
+    #[cfg(feature = "rasn")]
+    {
+        bench_rasn::bench_ber(name, c, &data);
+        bench_rasn::bench_der(name, c, &data);
+        bench_rasn::bench_uper(name, c, &data, data.logs.len());
+    }
 
     #[cfg(feature = "rkyv")]
     bench_rkyv::bench(
-        BENCH,
+        name,
         c,
         &data,
         |logs| {
@@ -213,98 +613,280 @@ fn bench_log(c: &mut Criterion) {
         },
     );
 
+    #[cfg(feature = "rkyv")]
+    bench_rkyv::bench_read_random(name, c, &data, &indices, |logs, indices| {
+        for &i in indices {
+            let log = &logs.logs[i];
+            black_box(&log.address);
+            black_box(log.code);
+            black_box(log.size);
+        }
+    });
+
+    #[cfg(feature = "rkyv")]
+    bench_rkyv::bench_project(name, c, &data, |logs| {
+        for log in logs.logs.iter() {
+            black_box(log.code);
+        }
+    });
+
     #[cfg(feature = "rmp-serde")]
-    bench_rmp_serde::bench(BENCH, c, &data);
+    {
+        bench_rmp_serde::bench(name, c, &data);
+        bench_rmp_serde::bench_named(name, c, &data);
+        bench_rmp_serde::bench_borrowed(name, c, &data);
+        bench_rmp_serde::bench_project(name, c, &data);
+        bench_rmp_serde::bench_read_random(name, c, &data, &indices);
+    }
 
     #[cfg(feature = "ron")]
-    bench_ron::bench(BENCH, c, &data);
+    {
+        bench_ron::bench(name, c, &data);
+        bench_ron::bench_borrowed(name, c, &data);
+        bench_ron::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "savefile")]
-    bench_savefile::bench(BENCH, c, &data);
+    bench_savefile::bench(name, c, &data);
 
     #[cfg(feature = "serde_bare")]
-    bench_serde_bare::bench(BENCH, c, &data);
+    bench_serde_bare::bench(name, c, &data);
 
     #[cfg(feature = "serde_cbor")]
-    bench_serde_cbor::bench(BENCH, c, &data);
+    {
+        bench_serde_cbor::bench(name, c, &data);
+        bench_serde_cbor::bench_borrowed(name, c, &data);
+        bench_serde_cbor::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "serde_json")]
-    bench_serde_json::bench(BENCH, c, &data);
+    {
+        bench_serde_json::bench(name, c, &data);
+        bench_serde_json::bench_borrowed(name, c, &data);
+        bench_serde_json::bench_project(name, c, &data);
+        bench_serde_json::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    bench_serde_yaml::bench(name, c, &data);
 
     #[cfg(feature = "simd-json")]
-    bench_simd_json::bench(BENCH, c, &data);
+    {
+        bench_simd_json::bench(name, c, &data);
+        bench_simd_json::bench_borrowed(name, c, &data);
+    }
+
+    #[cfg(feature = "sonic-rs")]
+    {
+        bench_sonic_rs::bench(name, c, &data);
+        bench_sonic_rs::bench_borrowed(name, c, &data);
+        bench_sonic_rs::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "speedy")]
-    bench_speedy::bench(BENCH, c, &data);
+    {
+        bench_speedy::bench(name, c, &data);
+        bench_speedy::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "thrift")]
+    bench_thrift::bench(name, c, &data);
+
+    #[cfg(feature = "toml")]
+    bench_toml::bench(name, c, &data);
 
     #[cfg(feature = "nanoserde")]
-    bench_nanoserde::bench(BENCH, c, &data);
+    bench_nanoserde::bench(name, c, &data);
 
     #[cfg(feature = "wiring")]
-    bench_wiring::bench(BENCH, c, &data);
+    bench_wiring::bench(name, c, &data);
 }
 
 fn bench_mesh(c: &mut Criterion) {
-    use rust_serialization_benchmark::datasets::mesh::{Mesh, Triangle};
+    const TRIANGLES: usize = 125_000;
 
-    const BENCH: &'static str = "mesh";
+    if let Some(data) = corpus("mesh", |data: &Mesh| data.triangles.len()) {
+        bench_scale("mesh-corpus", data.triangles.len());
+        bench_mesh_with(c, "mesh-corpus", data);
+        return;
+    }
 
-    // nothing up our sleeves, state and stream are first 20 digits of pi
-    const STATE: u64 = 3141592653;
-    const STREAM: u64 = 5897932384;
+    for (name, len) in scales("mesh", TRIANGLES) {
+        for (name, state) in seeds(name) {
+            // nothing up our sleeves, stream is the second 10 digits of pi
+            const STREAM: u64 = 5897932384;
 
-    let mut rng = Lcg64Xsh32::new(STATE, STREAM);
+            let mut rng = Lcg64Xsh32::new(state, STREAM);
 
-    const TRIANGLES: usize = 125_000;
-    let data = Mesh {
-        triangles: generate_vec::<_, Triangle>(&mut rng, TRIANGLES..TRIANGLES + 1),
-    };
+            let data = Mesh {
+                triangles: generate_vec::<_, Triangle>(&mut rng, len..len + 1),
+            };
+            bench_scale(name, len);
+            bench_seed(name, state);
+            bench_mesh_with(c, name, data);
+        }
+    }
+}
+
+fn bench_mesh_with(c: &mut Criterion, name: &'static str, data: Mesh) {
+    let indices = random_indices(data.triangles.len());
+
+    #[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+    let pod_triangles = data
+        .triangles
+        .iter()
+        .map(|&triangle| triangle.into())
+        .collect::<Vec<rust_serialization_benchmark::datasets::mesh::pod::Triangle>>();
+
+    #[cfg(feature = "apache-avro")]
+    bench_avro::bench(name, c, &data);
+
+    #[cfg(feature = "bebop")]
+    bench_bebop::bench(name, c, &data);
 
     #[cfg(feature = "bilrost")]
-    bench_bilrost::bench(BENCH, c, &data);
+    bench_bilrost::bench(name, c, &data);
 
     #[cfg(feature = "bincode1")]
-    bench_bincode1::bench(BENCH, c, &data);
+    bench_bincode1::bench(name, c, &data);
 
     #[cfg(feature = "bincode")]
-    bench_bincode::bench(BENCH, c, &data);
+    {
+        bench_bincode::bench(name, c, &data);
+        bench_bincode::bench_serde(name, c, &data);
+        bench_bincode::bench_read_random(name, c, &data, &indices);
+    }
 
     #[cfg(feature = "bitcode")]
-    bench_bitcode::bench(BENCH, c, &data);
+    {
+        bench_bitcode::bench(name, c, &data);
+        bench_bitcode::bench_serde(name, c, &data);
+        bench_bitcode::bench_read_random(name, c, &data, &indices);
+    }
 
     #[cfg(feature = "borsh")]
-    bench_borsh::bench(BENCH, c, &data);
+    {
+        bench_borsh::bench(name, c, &data);
+        bench_borsh::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "bson")]
+    {
+        bench_bson::bench(name, c, &data);
+        bench_bson::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    bench_bytemuck::bench_slice(
+        name,
+        c,
+        &pod_triangles,
+        |triangles| {
+            for triangle in triangles.iter() {
+                black_box(&triangle.normal);
+            }
+        },
+        |triangles| {
+            for triangle in triangles.iter_mut() {
+                triangle.normal = rust_serialization_benchmark::datasets::mesh::pod::Vector3 {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                };
+            }
+        },
+    );
+
+    #[cfg(feature = "bytemuck")]
+    bench_bytemuck::bench_slice_read_random(
+        name,
+        c,
+        &pod_triangles,
+        &indices,
+        |triangles, indices| {
+            for &i in indices {
+                black_box(&triangles[i].normal);
+            }
+        },
+    );
 
     #[cfg(feature = "serde-brief")]
-    bench_serde_brief::bench(BENCH, c, &data);
+    {
+        bench_serde_brief::bench(name, c, &data);
+        bench_serde_brief::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "capnp")]
-    bench_capnp::bench(BENCH, c, &data, |bytes| {
-        let message_reader =
-            capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
-        let data = message_reader
-            .get_root::<rust_serialization_benchmark::datasets::mesh::cp::mesh::Reader>()
-            .unwrap();
-        for triangle in data.get_triangles().unwrap().iter() {
-            black_box(triangle.get_normal().unwrap());
-        }
-    });
+    {
+        use rust_serialization_benchmark::datasets::mesh::cp;
+
+        bench_capnp::bench(
+            name,
+            c,
+            &data,
+            |bytes, options| {
+                let message_reader =
+                    capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+                let data = message_reader.get_root::<cp::mesh::Reader>()?;
+                for triangle in data.get_triangles()?.iter() {
+                    black_box(triangle.get_normal()?);
+                }
+                Ok(())
+            },
+            |root| {
+                let data = root.get_as::<cp::mesh::Builder>()?;
+                let mut triangles = data.get_triangles()?;
+                for i in 0..triangles.len() {
+                    let mut normal = triangles.reborrow().get(i).get_normal()?;
+                    normal.set_x(0.0);
+                    normal.set_y(0.0);
+                    normal.set_z(0.0);
+                }
+                Ok(())
+            },
+        );
+        bench_capnp::bench_packed(name, c, &data);
+        bench_capnp::bench_read_random(name, c, &data, &indices, |bytes, indices, options| {
+            let message_reader = capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+            let triangles = message_reader
+                .get_root::<cp::mesh::Reader>()?
+                .get_triangles()?;
+            for &i in indices {
+                black_box(triangles.get(i as u32).get_normal()?);
+            }
+            Ok(())
+        });
+        bench_capnp::bench_project(name, c, &data, |bytes, options| {
+            let message_reader = capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+            let data = message_reader.get_root::<cp::mesh::Reader>()?;
+            for triangle in data.get_triangles()?.iter() {
+                black_box(triangle.get_normal()?);
+            }
+            Ok(())
+        });
+    }
 
     #[cfg(feature = "cbor4ii")]
-    bench_cbor4ii::bench(BENCH, c, &data);
+    {
+        bench_cbor4ii::bench(name, c, &data);
+        bench_cbor4ii::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "ciborium")]
-    bench_ciborium::bench(BENCH, c, &data);
+    bench_ciborium::bench(name, c, &data);
 
     #[cfg(feature = "databuf")]
-    bench_databuf::bench(BENCH, c, &data);
+    bench_databuf::bench(name, c, &data);
 
     #[cfg(feature = "dlhn")]
-    bench_dlhn::bench(BENCH, c, &data);
+    bench_dlhn::bench(name, c, &data);
+
+    #[cfg(feature = "ssz")]
+    bench_ethereum_ssz::bench(name, c, &data);
 
     #[cfg(feature = "flatbuffers")]
     bench_flatbuffers::bench(
-        BENCH,
+        name,
         c,
         &data,
         |bytes| unsafe {
@@ -315,37 +897,167 @@ fn bench_mesh(c: &mut Criterion) {
                 black_box(triangle.normal());
             }
         },
+        |bytes, options| {
+            let data = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::mesh::fb::Mesh,
+            >(options, bytes)
+            .unwrap();
+            for triangle in data.triangles().iter() {
+                black_box(triangle.normal());
+            }
+        },
         |bytes| {
-            let data =
-                flatbuffers::root::<rust_serialization_benchmark::datasets::mesh::fb::Mesh>(bytes)
-                    .unwrap();
+            use rust_serialization_benchmark::datasets::mesh::fb;
+
+            let mut data = bench_flatbuffers::TableMut::root(bytes);
+            let mut triangles = data.vector_mut(fb::Mesh::VT_TRIANGLES).unwrap();
+            for triangle in unsafe { triangles.structs_mut::<fb::Triangle>() } {
+                triangle.set_normal(&fb::Vector3::new(0.0, 0.0, 0.0));
+            }
+        },
+    );
+
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench_read_random(
+        name,
+        c,
+        &data,
+        &indices,
+        |bytes, indices| unsafe {
+            let triangles = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::mesh::fb::Mesh,
+            >(bytes)
+            .triangles();
+            for &i in indices {
+                black_box(triangles.get(i).normal());
+            }
+        },
+        |bytes, indices, options| {
+            let triangles = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::mesh::fb::Mesh,
+            >(options, bytes)
+            .unwrap()
+            .triangles();
+            for &i in indices {
+                black_box(triangles.get(i).normal());
+            }
+        },
+    );
+
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench_project(
+        name,
+        c,
+        &data,
+        |bytes| unsafe {
+            let data = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::mesh::fb::Mesh,
+            >(bytes);
+            for triangle in data.triangles().iter() {
+                black_box(triangle.normal());
+            }
+        },
+        |bytes, options| {
+            let data = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::mesh::fb::Mesh,
+            >(options, bytes)
+            .unwrap();
             for triangle in data.triangles().iter() {
                 black_box(triangle.normal());
             }
         },
     );
 
+    #[cfg(feature = "flexbuffers")]
+    bench_flexbuffers::bench(name, c, &data);
+
+    #[cfg(feature = "ion-rs")]
+    bench_ion_rs::bench(name, c, &data);
+
     #[cfg(feature = "msgpacker")]
-    bench_msgpacker::bench(BENCH, c, &data);
+    bench_msgpacker::bench(name, c, &data);
+
+    #[cfg(feature = "musli")]
+    {
+        bench_musli::bench_storage(name, c, &data);
+        bench_musli::bench_wire(name, c, &data);
+        bench_musli::bench_descriptive(name, c, &data);
+    }
+
+    #[cfg(feature = "musli-zerocopy")]
+    bench_musli_zerocopy::bench(
+        name,
+        c,
+        &data,
+        |buf| {
+            use rust_serialization_benchmark::datasets::mesh::zc;
+
+            let data = buf.load_at::<zc::Mesh>(0).unwrap();
+            for triangle in buf.load(data.triangles).unwrap().iter() {
+                black_box(&triangle.normal);
+            }
+        },
+        |buf| {
+            use rust_serialization_benchmark::datasets::mesh::zc;
+
+            let triangles = buf.load_at::<zc::Mesh>(0).unwrap().triangles;
+            for triangle in buf.load_mut(triangles).unwrap().iter_mut() {
+                triangle.normal = zc::Vector3 {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                };
+            }
+        },
+    );
+
+    #[cfg(feature = "musli-zerocopy")]
+    bench_musli_zerocopy::bench_read_random(name, c, &data, &indices, |buf, indices| {
+        use rust_serialization_benchmark::datasets::mesh::zc;
+
+        let data = buf.load_at::<zc::Mesh>(0).unwrap();
+        let triangles = buf.load(data.triangles).unwrap();
+        for &i in indices {
+            black_box(&triangles[i].normal);
+        }
+    });
 
     #[cfg(feature = "nachricht-serde")]
-    bench_nachricht_serde::bench(BENCH, c, &data);
+    {
+        bench_nachricht_serde::bench(name, c, &data);
+        bench_nachricht_serde::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "scale")]
-    bench_parity_scale_codec::bench(BENCH, c, &data);
+    bench_parity_scale_codec::bench(name, c, &data);
 
     #[cfg(feature = "postcard")]
-    bench_postcard::bench(BENCH, c, &data);
+    {
+        bench_postcard::bench(name, c, &data);
+        bench_postcard::bench_read_random(name, c, &data, &indices);
+    }
 
     #[cfg(feature = "pot")]
-    bench_pot::bench(BENCH, c, &data);
+    bench_pot::bench(name, c, &data);
 
     #[cfg(feature = "prost")]
-    bench_prost::bench(BENCH, c, &data);
+    {
+        bench_prost::bench(name, c, &data);
+        bench_prost::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "protobuf")]
+    bench_protobuf::bench(name, c, &data);
+
+    #[cfg(feature = "quick-protobuf")]
+    bench_quick_protobuf::bench(name, c, &data);
+
+    #[cfg(feature = "quick-xml")]
+    bench_quick_xml::bench(name, c, &data);
 
     #[cfg(feature = "rkyv")]
     bench_rkyv::bench(
-        BENCH,
+        name,
         c,
         &data,
         |mesh| {
@@ -380,361 +1092,1229 @@ fn bench_mesh(c: &mut Criterion) {
         },
     );
 
+    #[cfg(feature = "rkyv")]
+    bench_rkyv::bench_read_random(name, c, &data, &indices, |mesh, indices| {
+        for &i in indices {
+            black_box(&mesh.triangles[i].normal);
+        }
+    });
+
+    #[cfg(feature = "rkyv")]
+    bench_rkyv::bench_project(name, c, &data, |triangles| {
+        for triangle in triangles.triangles.iter() {
+            black_box(&triangle.normal);
+        }
+    });
+
+    #[cfg(feature = "rmp-serde")]
+    {
+        bench_rmp_serde::bench(name, c, &data);
+        bench_rmp_serde::bench_named(name, c, &data);
+        bench_rmp_serde::bench_project(name, c, &data);
+        bench_rmp_serde::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "ron")]
+    {
+        bench_ron::bench(name, c, &data);
+        bench_ron::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "savefile")]
+    bench_savefile::bench(name, c, &data);
+
+    #[cfg(feature = "serde_bare")]
+    bench_serde_bare::bench(name, c, &data);
+
+    #[cfg(feature = "serde_cbor")]
+    {
+        bench_serde_cbor::bench(name, c, &data);
+        bench_serde_cbor::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "serde_json")]
+    {
+        bench_serde_json::bench(name, c, &data);
+        bench_serde_json::bench_project(name, c, &data);
+        bench_serde_json::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    bench_serde_yaml::bench(name, c, &data);
+
+    #[cfg(feature = "simd-json")]
+    bench_simd_json::bench(name, c, &data);
+
+    #[cfg(feature = "sonic-rs")]
+    {
+        bench_sonic_rs::bench(name, c, &data);
+        bench_sonic_rs::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "speedy")]
+    {
+        bench_speedy::bench(name, c, &data);
+        bench_speedy::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "thrift")]
+    bench_thrift::bench(name, c, &data);
+
+    #[cfg(feature = "toml")]
+    bench_toml::bench(name, c, &data);
+
+    #[cfg(feature = "nanoserde")]
+    bench_nanoserde::bench(name, c, &data);
+
+    #[cfg(feature = "wiring")]
+    bench_wiring::bench(name, c, &data);
+
+    #[cfg(feature = "zerocopy")]
+    bench_zerocopy::bench_slice(
+        name,
+        c,
+        &pod_triangles,
+        |triangles| {
+            for triangle in triangles.iter() {
+                black_box(&triangle.normal);
+            }
+        },
+        |triangles| {
+            for triangle in triangles.iter_mut() {
+                triangle.normal = rust_serialization_benchmark::datasets::mesh::pod::Vector3 {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                };
+            }
+        },
+    );
+
+    #[cfg(feature = "zerocopy")]
+    bench_zerocopy::bench_slice_read_random(
+        name,
+        c,
+        &pod_triangles,
+        &indices,
+        |triangles, indices| {
+            for &i in indices {
+                black_box(&triangles[i].normal);
+            }
+        },
+    );
+}
+
+fn bench_minecraft_savedata(c: &mut Criterion) {
+    const PLAYERS: usize = 500;
+
+    if let Some(data) = corpus("minecraft_savedata", |data: &Players| data.players.len()) {
+        bench_scale("minecraft_savedata-corpus", data.players.len());
+        bench_minecraft_savedata_with(c, "minecraft_savedata-corpus", data);
+        return;
+    }
+
+    for (name, len) in scales("minecraft_savedata", PLAYERS) {
+        for (name, state) in seeds(name) {
+            // nothing up our sleeves, stream is the second 10 digits of pi
+            const STREAM: u64 = 5897932384;
+
+            let mut rng = Lcg64Xsh32::new(state, STREAM);
+
+            let data = Players {
+                players: generate_vec::<_, Player>(&mut rng, len..len + 1),
+            };
+            bench_scale(name, len);
+            bench_seed(name, state);
+            bench_minecraft_savedata_with(c, name, data);
+        }
+    }
+}
+
+fn bench_minecraft_savedata_with(c: &mut Criterion, name: &'static str, data: Players) {
+    let indices = random_indices(data.players.len());
+
+    #[cfg(feature = "apache-avro")]
+    bench_avro::bench(name, c, &data);
+
+    #[cfg(feature = "bilrost")]
+    bench_bilrost::bench(name, c, &data);
+
+    #[cfg(feature = "bincode1")]
+    {
+        bench_bincode1::bench(name, c, &data);
+        bench_bincode1::bench_borrowed(name, c, &data);
+    }
+
+    #[cfg(feature = "bincode")]
+    {
+        bench_bincode::bench(name, c, &data);
+        bench_bincode::bench_serde(name, c, &data);
+        bench_bincode::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "bitcode")]
+    {
+        bench_bitcode::bench(name, c, &data);
+        bench_bitcode::bench_serde(name, c, &data);
+        bench_bitcode::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "borsh")]
+    {
+        bench_borsh::bench(name, c, &data);
+        bench_borsh::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "bson")]
+    {
+        bench_bson::bench(name, c, &data);
+        bench_bson::bench_borrowed(name, c, &data);
+        bench_bson::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "serde-brief")]
+    {
+        bench_serde_brief::bench(name, c, &data);
+        bench_serde_brief::bench_borrowed(name, c, &data);
+        bench_serde_brief::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "capnp")]
+    {
+        use rust_serialization_benchmark::datasets::minecraft_savedata::cp;
+
+        bench_capnp::bench(
+            name,
+            c,
+            &data,
+            |bytes, options| {
+                let message_reader =
+                    capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+                let data = message_reader.get_root::<cp::players::Reader>()?;
+                for player in data.get_players()?.iter() {
+                    black_box(player.get_game_type()?);
+                }
+                Ok(())
+            },
+            |root| {
+                let data = root.get_as::<cp::players::Builder>()?;
+                let mut players = data.get_players()?;
+                for i in 0..players.len() {
+                    let mut player = players.reborrow().get(i);
+                    player.set_game_type(cp::GameType::Survival);
+                    let mut spawn = player.get_spawn();
+                    spawn.set_x(0);
+                    spawn.set_y(0);
+                    spawn.set_z(0);
+                }
+                Ok(())
+            },
+        );
+        bench_capnp::bench_packed(name, c, &data);
+        bench_capnp::bench_read_random(name, c, &data, &indices, |bytes, indices, options| {
+            let message_reader = capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+            let players = message_reader
+                .get_root::<cp::players::Reader>()?
+                .get_players()?;
+            for &i in indices {
+                black_box(players.get(i as u32).get_game_type()?);
+            }
+            Ok(())
+        });
+        bench_capnp::bench_project(name, c, &data, |bytes, options| {
+            let message_reader = capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+            let data = message_reader.get_root::<cp::players::Reader>()?;
+            for player in data.get_players()?.iter() {
+                black_box(player.get_game_type()?);
+            }
+            Ok(())
+        });
+    }
+
+    #[cfg(feature = "cbor4ii")]
+    {
+        bench_cbor4ii::bench(name, c, &data);
+        bench_cbor4ii::bench_borrowed(name, c, &data);
+        bench_cbor4ii::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "ciborium")]
+    bench_ciborium::bench(name, c, &data);
+
+    #[cfg(feature = "databuf")]
+    bench_databuf::bench(name, c, &data);
+
+    #[cfg(feature = "dlhn")]
+    bench_dlhn::bench(name, c, &data);
+
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench(
+        name,
+        c,
+        &data,
+        |bytes| unsafe {
+            let data = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::minecraft_savedata::fb::Players,
+            >(bytes);
+            for player in data.players().iter() {
+                black_box(player.game_type());
+            }
+        },
+        |bytes, options| {
+            let data = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::minecraft_savedata::fb::Players,
+            >(options, bytes)
+            .unwrap();
+            for player in data.players().iter() {
+                black_box(player.game_type());
+            }
+        },
+        |bytes| {
+            use rust_serialization_benchmark::datasets::minecraft_savedata::fb;
+
+            let mut data = bench_flatbuffers::TableMut::root(bytes);
+            let mut players = data.vector_mut(fb::Players::VT_PLAYERS).unwrap();
+            for i in 0..players.len() {
+                let mut player = players.table_mut(i);
+                player.mutate(fb::Player::VT_GAME_TYPE, fb::GameType::Survival);
+                player.mutate(fb::Player::VT_SPAWN_X, 0i64);
+                player.mutate(fb::Player::VT_SPAWN_Y, 0i64);
+                player.mutate(fb::Player::VT_SPAWN_Z, 0i64);
+            }
+        },
+    );
+
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench_read_random(
+        name,
+        c,
+        &data,
+        &indices,
+        |bytes, indices| unsafe {
+            let players = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::minecraft_savedata::fb::Players,
+            >(bytes)
+            .players();
+            for &i in indices {
+                black_box(players.get(i).game_type());
+            }
+        },
+        |bytes, indices, options| {
+            let players = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::minecraft_savedata::fb::Players,
+            >(options, bytes)
+            .unwrap()
+            .players();
+            for &i in indices {
+                black_box(players.get(i).game_type());
+            }
+        },
+    );
+
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench_project(
+        name,
+        c,
+        &data,
+        |bytes| unsafe {
+            let data = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::minecraft_savedata::fb::Players,
+            >(bytes);
+            for player in data.players().iter() {
+                black_box(player.game_type());
+            }
+        },
+        |bytes, options| {
+            let data = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::minecraft_savedata::fb::Players,
+            >(options, bytes)
+            .unwrap();
+            for player in data.players().iter() {
+                black_box(player.game_type());
+            }
+        },
+    );
+
+    #[cfg(feature = "flexbuffers")]
+    bench_flexbuffers::bench(name, c, &data);
+
+    #[cfg(feature = "ion-rs")]
+    bench_ion_rs::bench(name, c, &data);
+
+    #[cfg(feature = "msgpacker")]
+    bench_msgpacker::bench(name, c, &data);
+
+    #[cfg(feature = "musli")]
+    {
+        bench_musli::bench_storage(name, c, &data);
+        bench_musli::bench_wire(name, c, &data);
+        bench_musli::bench_descriptive(name, c, &data);
+    }
+
+    #[cfg(feature = "nachricht-serde")]
+    {
+        bench_nachricht_serde::bench(name, c, &data);
+        bench_nachricht_serde::bench_borrowed(name, c, &data);
+        bench_nachricht_serde::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "scale")]
+    bench_parity_scale_codec::bench(name, c, &data);
+
+    #[cfg(feature = "postcard")]
+    {
+        bench_postcard::bench(name, c, &data);
+        bench_postcard::bench_borrowed(name, c, &data);
+        bench_postcard::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "pot")]
+    {
+        bench_pot::bench(name, c, &data);
+        bench_pot::bench_borrowed(name, c, &data);
+    }
+
+    #[cfg(feature = "prost")]
+    {
+        bench_prost::bench(name, c, &data);
+        bench_prost::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "protobuf")]
+    bench_protobuf::bench(name, c, &data);
+
+    #[cfg(feature = "quick-protobuf")]
+    rust_serialization_benchmark::bench_skipped(
+        name,
+        "quick-protobuf",
+        "pb-rs doesn't support proto3 optional",
+    );
+
+    #[cfg(feature = "quick-xml")]
+    bench_quick_xml::bench(name, c, &data);
+
+    #[cfg(feature = "rasn")]
+    {
+        bench_rasn::bench_ber(name, c, &data);
+        bench_rasn::bench_der(name, c, &data);
+        bench_rasn::bench_uper(name, c, &data, data.players.len());
+    }
+
+    #[cfg(feature = "rkyv")]
+    bench_rkyv::bench(
+        name,
+        c,
+        &data,
+        |players| {
+            for player in players.players.iter() {
+                black_box(&player.game_type);
+            }
+        },
+        |players| {
+            use rkyv::{munge::munge, vec::ArchivedVec};
+            use rust_serialization_benchmark::datasets::minecraft_savedata::{
+                ArchivedPlayer, ArchivedPlayers,
+            };
+
+            use rust_serialization_benchmark::datasets::minecraft_savedata::ArchivedGameType;
+
+            munge!(let ArchivedPlayers { players } = players);
+            let mut players = ArchivedVec::as_slice_seal(players);
+
+            for i in 0..players.len() {
+                munge! {
+                    let ArchivedPlayer {
+                        mut game_type,
+                        mut spawn_x,
+                        mut spawn_y,
+                        mut spawn_z,
+                        ..
+                    } = players.as_mut().index(i);
+                }
+                *game_type = ArchivedGameType::Survival;
+                *spawn_x = 0.into();
+                *spawn_y = 0.into();
+                *spawn_z = 0.into();
+            }
+        },
+    );
+
+    #[cfg(feature = "rkyv")]
+    bench_rkyv::bench_read_random(name, c, &data, &indices, |players, indices| {
+        for &i in indices {
+            black_box(&players.players[i].game_type);
+        }
+    });
+
+    #[cfg(feature = "rkyv")]
+    bench_rkyv::bench_project(name, c, &data, |players| {
+        for player in players.players.iter() {
+            black_box(&player.game_type);
+        }
+    });
+
     #[cfg(feature = "rmp-serde")]
-    bench_rmp_serde::bench(BENCH, c, &data);
+    {
+        bench_rmp_serde::bench(name, c, &data);
+        bench_rmp_serde::bench_named(name, c, &data);
+        bench_rmp_serde::bench_borrowed(name, c, &data);
+        bench_rmp_serde::bench_project(name, c, &data);
+        bench_rmp_serde::bench_read_random(name, c, &data, &indices);
+    }
 
     #[cfg(feature = "ron")]
-    bench_ron::bench(BENCH, c, &data);
+    {
+        bench_ron::bench(name, c, &data);
+        bench_ron::bench_borrowed(name, c, &data);
+        bench_ron::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "savefile")]
-    bench_savefile::bench(BENCH, c, &data);
+    bench_savefile::bench(name, c, &data);
 
     #[cfg(feature = "serde_bare")]
-    bench_serde_bare::bench(BENCH, c, &data);
+    bench_serde_bare::bench(name, c, &data);
 
     #[cfg(feature = "serde_cbor")]
-    bench_serde_cbor::bench(BENCH, c, &data);
+    {
+        bench_serde_cbor::bench(name, c, &data);
+        bench_serde_cbor::bench_borrowed(name, c, &data);
+        bench_serde_cbor::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "serde_json")]
-    bench_serde_json::bench(BENCH, c, &data);
+    {
+        bench_serde_json::bench(name, c, &data);
+        bench_serde_json::bench_borrowed(name, c, &data);
+        bench_serde_json::bench_project(name, c, &data);
+        bench_serde_json::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    bench_serde_yaml::bench(name, c, &data);
 
     #[cfg(feature = "simd-json")]
-    bench_simd_json::bench(BENCH, c, &data);
+    {
+        bench_simd_json::bench(name, c, &data);
+        bench_simd_json::bench_borrowed(name, c, &data);
+    }
+
+    #[cfg(feature = "sonic-rs")]
+    {
+        bench_sonic_rs::bench(name, c, &data);
+        bench_sonic_rs::bench_borrowed(name, c, &data);
+        bench_sonic_rs::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "speedy")]
-    bench_speedy::bench(BENCH, c, &data);
+    {
+        bench_speedy::bench(name, c, &data);
+        bench_speedy::bench_read_random(name, c, &data, &indices);
+    }
+
+    #[cfg(feature = "thrift")]
+    bench_thrift::bench(name, c, &data);
+
+    #[cfg(feature = "toml")]
+    bench_toml::bench(name, c, &data);
 
     #[cfg(feature = "nanoserde")]
-    bench_nanoserde::bench(BENCH, c, &data);
+    bench_nanoserde::bench(name, c, &data);
 
     #[cfg(feature = "wiring")]
-    bench_wiring::bench(BENCH, c, &data);
+    bench_wiring::bench(name, c, &data);
 }
 
-fn bench_minecraft_savedata(c: &mut Criterion) {
-    use rust_serialization_benchmark::datasets::minecraft_savedata::{Player, Players};
+fn bench_mk48(c: &mut Criterion) {
+    const UPDATES: usize = 1000;
+
+    if let Some(data) = corpus("mk48", |data: &Updates| data.updates.len()) {
+        bench_scale("mk48-corpus", data.updates.len());
+        bench_mk48_with(c, "mk48-corpus", data);
+        return;
+    }
+
+    for (name, len) in scales("mk48", UPDATES) {
+        for (name, state) in seeds(name) {
+            // nothing up our sleeves, stream is the second 10 digits of pi
+            const STREAM: u64 = 5897932384;
 
-    const BENCH: &'static str = "minecraft_savedata";
+            let mut rng = Lcg64Xsh32::new(state, STREAM);
 
-    // nothing up our sleeves, state and stream are first 20 digits of pi
-    const STATE: u64 = 3141592653;
-    const STREAM: u64 = 5897932384;
+            let data = Updates {
+                updates: generate_vec(&mut rng, len..len + 1),
+            };
+            bench_scale(name, len);
+            bench_seed(name, state);
+            bench_mk48_with(c, name, data);
+        }
+    }
+}
 
-    let mut rng = Lcg64Xsh32::new(STATE, STREAM);
+fn bench_mk48_with(c: &mut Criterion, name: &'static str, data: Updates) {
+    #[cfg(feature = "apache-avro")]
+    bench_avro::bench(name, c, &data);
 
-    const PLAYERS: usize = 500;
-    let data = Players {
-        players: generate_vec::<_, Player>(&mut rng, PLAYERS..PLAYERS + 1),
-    };
+    #[cfg(feature = "bebop")]
+    bench_bebop::bench(name, c, &data);
 
     #[cfg(feature = "bilrost")]
-    bench_bilrost::bench(BENCH, c, &data);
+    bench_bilrost::bench(name, c, &data);
 
     #[cfg(feature = "bincode1")]
-    bench_bincode1::bench(BENCH, c, &data);
+    bench_bincode1::bench(name, c, &data);
 
     #[cfg(feature = "bincode")]
-    bench_bincode::bench(BENCH, c, &data);
+    {
+        bench_bincode::bench(name, c, &data);
+        bench_bincode::bench_serde(name, c, &data);
+    }
 
     #[cfg(feature = "bitcode")]
-    bench_bitcode::bench(BENCH, c, &data);
+    {
+        bench_bitcode::bench(name, c, &data);
+        bench_bitcode::bench_serde(name, c, &data);
+    }
 
     #[cfg(feature = "borsh")]
-    bench_borsh::bench(BENCH, c, &data);
+    bench_borsh::bench(name, c, &data);
+
+    #[cfg(feature = "bson")]
+    {
+        bench_bson::bench(name, c, &data);
+        bench_bson::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "serde-brief")]
-    bench_serde_brief::bench(BENCH, c, &data);
+    {
+        bench_serde_brief::bench(name, c, &data);
+        bench_serde_brief::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "capnp")]
-    bench_capnp::bench(BENCH, c, &data, |bytes| {
-        let message_reader =
-            capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
-        let data = message_reader
-      .get_root::<rust_serialization_benchmark::datasets::minecraft_savedata::cp::players::Reader>()
-      .unwrap();
-        for player in data.get_players().unwrap().iter() {
-            black_box(player.get_game_type().unwrap());
-        }
-    });
+    {
+        use rust_serialization_benchmark::datasets::mk48::cp;
+
+        bench_capnp::bench(
+            name,
+            c,
+            &data,
+            |bytes, options| {
+                let message_reader =
+                    capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+                let data = message_reader.get_root::<cp::updates::Reader>()?;
+                for update in data.get_updates()?.iter() {
+                    black_box(update.get_score());
+                }
+                Ok(())
+            },
+            |root| {
+                let data = root.get_as::<cp::updates::Builder>()?;
+                let mut updates = data.get_updates()?;
+                for i in 0..updates.len() {
+                    let mut update = updates.reborrow().get(i);
+                    let score = update.reborrow().get_score();
+                    update.set_score(score * 2);
+                }
+                Ok(())
+            },
+        );
+        bench_capnp::bench_packed(name, c, &data);
+        bench_capnp::bench_project(name, c, &data, |bytes, options| {
+            let message_reader = capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+            let data = message_reader.get_root::<cp::updates::Reader>()?;
+            for update in data.get_updates()?.iter() {
+                black_box(update.get_score());
+            }
+            Ok(())
+        });
+    }
 
     #[cfg(feature = "cbor4ii")]
-    bench_cbor4ii::bench(BENCH, c, &data);
+    {
+        bench_cbor4ii::bench(name, c, &data);
+        bench_cbor4ii::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "ciborium")]
-    bench_ciborium::bench(BENCH, c, &data);
+    bench_ciborium::bench(name, c, &data);
 
     #[cfg(feature = "databuf")]
-    bench_databuf::bench(BENCH, c, &data);
+    bench_databuf::bench(name, c, &data);
 
     #[cfg(feature = "dlhn")]
-    bench_dlhn::bench(BENCH, c, &data);
+    bench_dlhn::bench(name, c, &data);
+
+    #[cfg(feature = "ssz")]
+    bench_ethereum_ssz::bench(name, c, &data);
 
     #[cfg(feature = "flatbuffers")]
     bench_flatbuffers::bench(
-        BENCH,
+        name,
         c,
         &data,
         |bytes| unsafe {
             let data = flatbuffers::root_unchecked::<
-                rust_serialization_benchmark::datasets::minecraft_savedata::fb::Players,
+                rust_serialization_benchmark::datasets::mk48::fb::Updates,
             >(bytes);
-            for player in data.players().iter() {
-                black_box(player.game_type());
+            for update in data.updates().iter() {
+                black_box(update.score());
+            }
+        },
+        |bytes, options| {
+            let data = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::mk48::fb::Updates,
+            >(options, bytes)
+            .unwrap();
+            for update in data.updates().iter() {
+                black_box(update.score());
             }
         },
         |bytes| {
-            let data = flatbuffers::root::<
-                rust_serialization_benchmark::datasets::minecraft_savedata::fb::Players,
-            >(bytes)
+            use rust_serialization_benchmark::datasets::mk48::fb;
+
+            let mut data = bench_flatbuffers::TableMut::root(bytes);
+            let mut updates = data.vector_mut(fb::Updates::VT_UPDATES).unwrap();
+            for i in 0..updates.len() {
+                let mut update = updates.table_mut(i);
+                if let Some(score) = update.get::<u32>(fb::Update::VT_SCORE) {
+                    update.mutate(fb::Update::VT_SCORE, score * 2);
+                }
+            }
+        },
+    );
+
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench_project(
+        name,
+        c,
+        &data,
+        |bytes| unsafe {
+            let data = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::mk48::fb::Updates,
+            >(bytes);
+            for update in data.updates().iter() {
+                black_box(update.score());
+            }
+        },
+        |bytes, options| {
+            let data = flatbuffers::root_with_opts::<
+                rust_serialization_benchmark::datasets::mk48::fb::Updates,
+            >(options, bytes)
             .unwrap();
-            for player in data.players().iter() {
-                black_box(player.game_type());
+            for update in data.updates().iter() {
+                black_box(update.score());
             }
         },
     );
 
+    #[cfg(feature = "flexbuffers")]
+    bench_flexbuffers::bench(name, c, &data);
+
+    #[cfg(feature = "ion-rs")]
+    bench_ion_rs::bench(name, c, &data);
+
     #[cfg(feature = "msgpacker")]
-    bench_msgpacker::bench(BENCH, c, &data);
+    bench_msgpacker::bench(name, c, &data);
+
+    #[cfg(feature = "musli")]
+    {
+        bench_musli::bench_storage(name, c, &data);
+        bench_musli::bench_wire(name, c, &data);
+        bench_musli::bench_descriptive(name, c, &data);
+    }
 
     #[cfg(feature = "nachricht-serde")]
-    bench_nachricht_serde::bench(BENCH, c, &data);
+    {
+        bench_nachricht_serde::bench(name, c, &data);
+        bench_nachricht_serde::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "scale")]
-    bench_parity_scale_codec::bench(BENCH, c, &data);
+    bench_parity_scale_codec::bench(name, c, &data);
 
     #[cfg(feature = "postcard")]
-    bench_postcard::bench(BENCH, c, &data);
+    bench_postcard::bench(name, c, &data);
 
     #[cfg(feature = "pot")]
-    bench_pot::bench(BENCH, c, &data);
+    bench_pot::bench(name, c, &data);
 
     #[cfg(feature = "prost")]
-    bench_prost::bench(BENCH, c, &data);
+    {
+        bench_prost::bench(name, c, &data);
+        bench_prost::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "protobuf")]
+    bench_protobuf::bench(name, c, &data);
+
+    #[cfg(feature = "quick-protobuf")]
+    rust_serialization_benchmark::bench_skipped(
+        name,
+        "quick-protobuf",
+        "pb-rs doesn't support proto3 optional",
+    );
+
+    #[cfg(feature = "quick-xml")]
+    bench_quick_xml::bench(name, c, &data);
 
     #[cfg(feature = "rkyv")]
     bench_rkyv::bench(
-        BENCH,
+        name,
         c,
         &data,
-        |players| {
-            for player in players.players.iter() {
-                black_box(&player.game_type);
+        |updates| {
+            for update in updates.updates.iter() {
+                black_box(update.score);
             }
         },
-        |players| {
+        |updates| {
             use rkyv::{munge::munge, vec::ArchivedVec};
-            use rust_serialization_benchmark::datasets::minecraft_savedata::{
-                ArchivedPlayer, ArchivedPlayers,
-            };
-
-            use rust_serialization_benchmark::datasets::minecraft_savedata::ArchivedGameType;
+            use rust_serialization_benchmark::datasets::mk48::{ArchivedUpdate, ArchivedUpdates};
 
-            munge!(let ArchivedPlayers { players } = players);
-            let mut players = ArchivedVec::as_slice_seal(players);
+            munge!(let ArchivedUpdates { updates } = updates);
+            let mut updates = ArchivedVec::as_slice_seal(updates);
 
-            for i in 0..players.len() {
-                munge! {
-                    let ArchivedPlayer {
-                        mut game_type,
-                        mut spawn_x,
-                        mut spawn_y,
-                        mut spawn_z,
-                        ..
-                    } = players.as_mut().index(i);
-                }
-                *game_type = ArchivedGameType::Survival;
-                *spawn_x = 0.into();
-                *spawn_y = 0.into();
-                *spawn_z = 0.into();
+            for i in 0..updates.len() {
+                munge!(let ArchivedUpdate { mut score, .. } = updates.as_mut().index(i));
+                *score *= 2;
             }
         },
     );
 
+    #[cfg(feature = "rkyv")]
+    bench_rkyv::bench_project(name, c, &data, |updates| {
+        for update in updates.updates.iter() {
+            black_box(update.score);
+        }
+    });
+
     #[cfg(feature = "rmp-serde")]
-    bench_rmp_serde::bench(BENCH, c, &data);
+    {
+        bench_rmp_serde::bench(name, c, &data);
+        bench_rmp_serde::bench_named(name, c, &data);
+        bench_rmp_serde::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "ron")]
-    bench_ron::bench(BENCH, c, &data);
+    {
+        bench_ron::bench(name, c, &data);
+        bench_ron::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "savefile")]
-    bench_savefile::bench(BENCH, c, &data);
+    bench_savefile::bench(name, c, &data);
 
     #[cfg(feature = "serde_bare")]
-    bench_serde_bare::bench(BENCH, c, &data);
+    bench_serde_bare::bench(name, c, &data);
 
     #[cfg(feature = "serde_cbor")]
-    bench_serde_cbor::bench(BENCH, c, &data);
+    {
+        bench_serde_cbor::bench(name, c, &data);
+        bench_serde_cbor::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "serde_json")]
-    bench_serde_json::bench(BENCH, c, &data);
+    {
+        bench_serde_json::bench(name, c, &data);
+        bench_serde_json::bench_project(name, c, &data);
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    bench_serde_yaml::bench(name, c, &data);
 
     #[cfg(feature = "simd-json")]
-    bench_simd_json::bench(BENCH, c, &data);
+    bench_simd_json::bench(name, c, &data);
+
+    #[cfg(feature = "sonic-rs")]
+    {
+        bench_sonic_rs::bench(name, c, &data);
+        bench_sonic_rs::bench_project(name, c, &data);
+    }
 
     #[cfg(feature = "speedy")]
-    bench_speedy::bench(BENCH, c, &data);
+    bench_speedy::bench(name, c, &data);
+
+    #[cfg(feature = "thrift")]
+    bench_thrift::bench(name, c, &data);
+
+    #[cfg(feature = "toml")]
+    bench_toml::bench(name, c, &data);
 
     #[cfg(feature = "nanoserde")]
-    bench_nanoserde::bench(BENCH, c, &data);
+    bench_nanoserde::bench(name, c, &data);
 
     #[cfg(feature = "wiring")]
-    bench_wiring::bench(BENCH, c, &data);
+    bench_wiring::bench(name, c, &data);
 }
 
-fn bench_mk48(c: &mut Criterion) {
-    use rust_serialization_benchmark::datasets::mk48::Updates;
+fn bench_tree(c: &mut Criterion) {
+    const TREES: usize = 500;
 
-    const BENCH: &'static str = "mk48";
+    if let Some(data) = corpus("tree", |data: &Trees| data.trees.len()) {
+        bench_scale("tree-corpus", data.trees.len());
+        bench_tree_with(c, "tree-corpus", data);
+        return;
+    }
 
-    // nothing up our sleeves, state and stream are first 20 digits of pi
-    const STATE: u64 = 3141592653;
-    const STREAM: u64 = 5897932384;
+    for (name, len) in scales("tree", TREES) {
+        for (name, state) in seeds(name) {
+            // nothing up our sleeves, stream is the second 10 digits of pi
+            const STREAM: u64 = 5897932384;
 
-    let mut rng = Lcg64Xsh32::new(STATE, STREAM);
+            let mut rng = Lcg64Xsh32::new(state, STREAM);
 
-    const UPDATES: usize = 1000;
-    let data = Updates {
-        updates: generate_vec(&mut rng, UPDATES..UPDATES + 1),
-    };
+            let data = Trees {
+                trees: generate_vec(&mut rng, len..len + 1),
+            };
+            bench_scale(name, len);
+            bench_seed(name, state);
+            bench_tree_with(c, name, data);
+        }
+    }
+}
+
+fn bench_tree_with(c: &mut Criterion, name: &'static str, data: Trees) {
+    #[cfg(feature = "apache-avro")]
+    rust_serialization_benchmark::bench_skipped(
+        name,
+        "apache-avro",
+        "its serde deserializer can't read an enum out of a union, which is the only way an avro \
+         schema can describe a mix of unit and newtype variants",
+    );
 
     #[cfg(feature = "bilrost")]
-    bench_bilrost::bench(BENCH, c, &data);
+    rust_serialization_benchmark::bench_skipped(
+        name,
+        "bilrost",
+        "enums with fields can only be encoded as a oneof inside a message",
+    );
 
     #[cfg(feature = "bincode1")]
-    bench_bincode1::bench(BENCH, c, &data);
+    bench_bincode1::bench(name, c, &data);
 
     #[cfg(feature = "bincode")]
-    bench_bincode::bench(BENCH, c, &data);
+    {
+        bench_bincode::bench(name, c, &data);
+        bench_bincode::bench_serde(name, c, &data);
+    }
 
+    // bitcode's derive doesn't support recursive types, so only its serde implementation is benched.
     #[cfg(feature = "bitcode")]
-    bench_bitcode::bench(BENCH, c, &data);
+    bench_bitcode::bench_serde(name, c, &data);
 
     #[cfg(feature = "borsh")]
-    bench_borsh::bench(BENCH, c, &data);
+    bench_borsh::bench(name, c, &data);
+
+    #[cfg(feature = "bson")]
+    bench_bson::bench(name, c, &data);
 
     #[cfg(feature = "serde-brief")]
-    bench_serde_brief::bench(BENCH, c, &data);
+    bench_serde_brief::bench(name, c, &data);
 
     #[cfg(feature = "capnp")]
-    bench_capnp::bench(BENCH, c, &data, |bytes| {
-        let message_reader =
-            capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
-        let data = message_reader
-            .get_root::<rust_serialization_benchmark::datasets::mk48::cp::updates::Reader>()
-            .unwrap();
-        for update in data.get_updates().unwrap().iter() {
-            black_box(update.get_score());
+    {
+        use rust_serialization_benchmark::datasets::tree::cp;
+
+        fn count(node: cp::node::Reader<'_>) -> capnp::Result<usize> {
+            Ok(match node.which()? {
+                cp::node::Which::Array(nodes) => {
+                    let mut total = 1;
+                    for node in nodes?.iter() {
+                        total += count(node)?;
+                    }
+                    total
+                }
+                cp::node::Which::Object(members) => {
+                    let mut total = 1;
+                    for member in members?.iter() {
+                        total += count(member.get_value()?)?;
+                    }
+                    total
+                }
+                _ => 1,
+            })
         }
-    });
+
+        fn negate(mut node: cp::node::Builder<'_>) -> capnp::Result<()> {
+            match node.reborrow().which()? {
+                cp::node::Which::Number(number) => node.set_number(-number),
+                cp::node::Which::Array(nodes) => {
+                    let mut nodes = nodes?;
+                    for i in 0..nodes.len() {
+                        negate(nodes.reborrow().get(i))?;
+                    }
+                }
+                cp::node::Which::Object(members) => {
+                    let mut members = members?;
+                    for i in 0..members.len() {
+                        negate(members.reborrow().get(i).get_value()?)?;
+                    }
+                }
+                _ => (),
+            }
+            Ok(())
+        }
+
+        bench_capnp::bench(
+            name,
+            c,
+            &data,
+            |bytes, options| {
+                let message_reader =
+                    capnp::serialize::read_message_from_flat_slice(bytes, options)?;
+                let data = message_reader.get_root::<cp::trees::Reader>()?;
+                for tree in data.get_trees()?.iter() {
+                    black_box(count(tree)?);
+                }
+                Ok(())
+            },
+            |root| {
+                let data = root.get_as::<cp::trees::Builder>()?;
+                let mut trees = data.get_trees()?;
+                for i in 0..trees.len() {
+                    negate(trees.reborrow().get(i))?;
+                }
+                Ok(())
+            },
+        );
+        bench_capnp::bench_packed(name, c, &data);
+    }
 
     #[cfg(feature = "cbor4ii")]
-    bench_cbor4ii::bench(BENCH, c, &data);
+    bench_cbor4ii::bench(name, c, &data);
 
     #[cfg(feature = "ciborium")]
-    bench_ciborium::bench(BENCH, c, &data);
+    bench_ciborium::bench(name, c, &data);
 
     #[cfg(feature = "databuf")]
-    bench_databuf::bench(BENCH, c, &data);
+    bench_databuf::bench(name, c, &data);
 
     #[cfg(feature = "dlhn")]
-    bench_dlhn::bench(BENCH, c, &data);
+    bench_dlhn::bench(name, c, &data);
 
     #[cfg(feature = "flatbuffers")]
-    bench_flatbuffers::bench(
-        BENCH,
-        c,
-        &data,
-        |bytes| unsafe {
-            let data = flatbuffers::root_unchecked::<
-                rust_serialization_benchmark::datasets::mk48::fb::Updates,
-            >(bytes);
-            for update in data.updates().iter() {
-                black_box(update.score());
+    {
+        use rust_serialization_benchmark::datasets::tree::fb;
+
+        fn count(node: fb::Node<'_>) -> usize {
+            match node.value_type() {
+                fb::Value::ArrayValue => {
+                    let nodes = node.value_as_array_value().unwrap().nodes();
+                    1 + nodes.iter().map(count).sum::<usize>()
+                }
+                fb::Value::ObjectValue => {
+                    let members = node.value_as_object_value().unwrap().members();
+                    1 + members
+                        .iter()
+                        .map(|member| count(member.value()))
+                        .sum::<usize>()
+                }
+                _ => 1,
             }
-        },
-        |bytes| {
-            let data =
-                flatbuffers::root::<rust_serialization_benchmark::datasets::mk48::fb::Updates>(
-                    bytes,
-                )
-                .unwrap();
-            for update in data.updates().iter() {
-                black_box(update.score());
+        }
+
+        fn negate(mut node: bench_flatbuffers::TableMut<'_>) {
+            let Some(value_type) = node.get::<fb::Value>(fb::Node::VT_VALUE_TYPE) else {
+                return;
+            };
+            let mut value = node.table_mut(fb::Node::VT_VALUE).unwrap();
+            match value_type {
+                fb::Value::NumberValue => {
+                    if let Some(number) = value.get::<f64>(fb::NumberValue::VT_VALUE) {
+                        value.mutate(fb::NumberValue::VT_VALUE, -number);
+                    }
+                }
+                fb::Value::ArrayValue => {
+                    let mut nodes = value.vector_mut(fb::ArrayValue::VT_NODES).unwrap();
+                    for i in 0..nodes.len() {
+                        negate(nodes.table_mut(i));
+                    }
+                }
+                fb::Value::ObjectValue => {
+                    let mut members = value.vector_mut(fb::ObjectValue::VT_MEMBERS).unwrap();
+                    for i in 0..members.len() {
+                        negate(
+                            members
+                                .table_mut(i)
+                                .table_mut(fb::Member::VT_VALUE)
+                                .unwrap(),
+                        );
+                    }
+                }
+                _ => (),
             }
-        },
-    );
+        }
+
+        bench_flatbuffers::bench(
+            name,
+            c,
+            &data,
+            |bytes| unsafe {
+                let data = flatbuffers::root_unchecked::<fb::Trees>(bytes);
+                for tree in data.trees().iter() {
+                    black_box(count(tree));
+                }
+            },
+            |bytes, options| {
+                let data = flatbuffers::root_with_opts::<fb::Trees>(options, bytes).unwrap();
+                for tree in data.trees().iter() {
+                    black_box(count(tree));
+                }
+            },
+            |bytes| {
+                let mut data = bench_flatbuffers::TableMut::root(bytes);
+                let mut trees = data.vector_mut(fb::Trees::VT_TREES).unwrap();
+                for i in 0..trees.len() {
+                    negate(trees.table_mut(i));
+                }
+            },
+        );
+    }
+
+    #[cfg(feature = "flexbuffers")]
+    bench_flexbuffers::bench(name, c, &data);
+
+    #[cfg(feature = "ion-rs")]
+    bench_ion_rs::bench(name, c, &data);
 
     #[cfg(feature = "msgpacker")]
-    bench_msgpacker::bench(BENCH, c, &data);
+    bench_msgpacker::bench(name, c, &data);
+
+    #[cfg(feature = "musli")]
+    {
+        bench_musli::bench_storage(name, c, &data);
+        bench_musli::bench_wire(name, c, &data);
+        bench_musli::bench_descriptive(name, c, &data);
+    }
 
     #[cfg(feature = "nachricht-serde")]
-    bench_nachricht_serde::bench(BENCH, c, &data);
+    bench_nachricht_serde::bench(name, c, &data);
 
     #[cfg(feature = "scale")]
-    bench_parity_scale_codec::bench(BENCH, c, &data);
+    bench_parity_scale_codec::bench(name, c, &data);
 
     #[cfg(feature = "postcard")]
-    bench_postcard::bench(BENCH, c, &data);
+    bench_postcard::bench(name, c, &data);
 
     #[cfg(feature = "pot")]
-    bench_pot::bench(BENCH, c, &data);
+    bench_pot::bench(name, c, &data);
 
     #[cfg(feature = "prost")]
-    bench_prost::bench(BENCH, c, &data);
+    bench_prost::bench(name, c, &data);
+
+    #[cfg(feature = "protobuf")]
+    bench_protobuf::bench(name, c, &data);
+
+    #[cfg(feature = "quick-protobuf")]
+    bench_quick_protobuf::bench(name, c, &data);
+
+    #[cfg(feature = "quick-xml")]
+    bench_quick_xml::bench(name, c, &data);
 
     #[cfg(feature = "rkyv")]
     bench_rkyv::bench(
-        BENCH,
+        name,
         c,
         &data,
-        |updates| {
-            for update in updates.updates.iter() {
-                black_box(update.score);
+        |trees| {
+            use rust_serialization_benchmark::datasets::tree::ArchivedNode;
+
+            fn count(node: &ArchivedNode) -> usize {
+                match node {
+                    ArchivedNode::Array(nodes) => 1 + nodes.iter().map(count).sum::<usize>(),
+                    ArchivedNode::Object(members) => {
+                        1 + members.iter().map(|m| count(&m.value)).sum::<usize>()
+                    }
+                    _ => 1,
+                }
+            }
+
+            for tree in trees.trees.iter() {
+                black_box(count(tree));
             }
         },
-        |updates| {
-            use rkyv::{munge::munge, vec::ArchivedVec};
-            use rust_serialization_benchmark::datasets::mk48::{ArchivedUpdate, ArchivedUpdates};
+        |trees| {
+            use rkyv::{munge::munge, seal::Seal, vec::ArchivedVec};
+            use rust_serialization_benchmark::datasets::tree::{
+                ArchivedMember, ArchivedNode, ArchivedTrees,
+            };
 
-            munge!(let ArchivedUpdates { updates } = updates);
-            let mut updates = ArchivedVec::as_slice_seal(updates);
+            fn negate(node: Seal<'_, ArchivedNode>) {
+                // Only numbers are written, and the children are sealed again before recursing, so
+                // the archive stays valid.
+                match unsafe { node.unseal_unchecked() } {
+                    ArchivedNode::Number(number) => *number = (-number.to_native()).into(),
+                    ArchivedNode::Array(nodes) => {
+                        let mut nodes = ArchivedVec::as_slice_seal(Seal::new(nodes));
+                        for i in 0..nodes.len() {
+                            negate(nodes.as_mut().index(i));
+                        }
+                    }
+                    ArchivedNode::Object(members) => {
+                        let mut members = ArchivedVec::as_slice_seal(Seal::new(members));
+                        for i in 0..members.len() {
+                            munge!(let ArchivedMember { value, .. } = members.as_mut().index(i));
+                            negate(value);
+                        }
+                    }
+                    _ => (),
+                }
+            }
 
-            for i in 0..updates.len() {
-                munge!(let ArchivedUpdate { mut score, .. } = updates.as_mut().index(i));
-                *score *= 2;
+            munge!(let ArchivedTrees { trees } = trees);
+            let mut trees = ArchivedVec::as_slice_seal(trees);
+
+            for i in 0..trees.len() {
+                negate(trees.as_mut().index(i));
             }
         },
     );
 
     #[cfg(feature = "rmp-serde")]
-    bench_rmp_serde::bench(BENCH, c, &data);
+    {
+        bench_rmp_serde::bench(name, c, &data);
+        bench_rmp_serde::bench_named(name, c, &data);
+    }
 
     #[cfg(feature = "ron")]
-    bench_ron::bench(BENCH, c, &data);
+    bench_ron::bench(name, c, &data);
 
     #[cfg(feature = "savefile")]
-    bench_savefile::bench(BENCH, c, &data);
+    bench_savefile::bench(name, c, &data);
 
     #[cfg(feature = "serde_bare")]
-    bench_serde_bare::bench(BENCH, c, &data);
+    bench_serde_bare::bench(name, c, &data);
 
     #[cfg(feature = "serde_cbor")]
-    bench_serde_cbor::bench(BENCH, c, &data);
+    bench_serde_cbor::bench(name, c, &data);
 
     #[cfg(feature = "serde_json")]
-    bench_serde_json::bench(BENCH, c, &data);
+    bench_serde_json::bench(name, c, &data);
+
+    #[cfg(feature = "serde_yaml")]
+    bench_serde_yaml::bench(name, c, &data);
 
     #[cfg(feature = "simd-json")]
-    bench_simd_json::bench(BENCH, c, &data);
+    bench_simd_json::bench(name, c, &data);
+
+    #[cfg(feature = "sonic-rs")]
+    bench_sonic_rs::bench(name, c, &data);
 
     #[cfg(feature = "speedy")]
-    bench_speedy::bench(BENCH, c, &data);
+    bench_speedy::bench(name, c, &data);
+
+    #[cfg(feature = "thrift")]
+    bench_thrift::bench(name, c, &data);
+
+    #[cfg(feature = "toml")]
+    bench_toml::bench(name, c, &data);
 
     #[cfg(feature = "nanoserde")]
-    bench_nanoserde::bench(BENCH, c, &data);
+    bench_nanoserde::bench(name, c, &data);
 
     #[cfg(feature = "wiring")]
-    bench_wiring::bench(BENCH, c, &data);
+    bench_wiring::bench(name, c, &data);
 }
 
 fn bench_page(c: &mut Criterion) {
@@ -753,7 +2333,23 @@ fn bench_page(c: &mut Criterion) {
     for i in 0..data.data.len() {
         data.data[i] = i as u8;
     }
+    #[cfg(feature = "bitcode")]
     bench_bitcode::bench(BENCH, c, &data);
+    #[cfg(feature = "bytemuck")]
+    bench_bytemuck::bench(
+        BENCH,
+        c,
+        &data,
+        |page| {
+            black_box(page.header.page_id);
+            black_box(page.header.previous_id);
+            black_box(page.header.next_id);
+            black_box(page.header.page_type);
+            black_box(page.header.space_id);
+            black_box(page.data);
+        },
+        |_update| {},
+    );
     bench_rkyv::bench(
         BENCH,
         c,
@@ -768,6 +2364,7 @@ fn bench_page(c: &mut Criterion) {
         },
         |_update| unsafe {},
     );
+    #[cfg(feature = "transmute")]
     unsafe {
         bench_transmute::bench(
             BENCH,
@@ -784,6 +2381,21 @@ fn bench_page(c: &mut Criterion) {
             |_update| {},
         );
     }
+    #[cfg(feature = "zerocopy")]
+    bench_zerocopy::bench(
+        BENCH,
+        c,
+        &data,
+        |page| {
+            black_box(page.header.page_id);
+            black_box(page.header.previous_id);
+            black_box(page.header.next_id);
+            black_box(page.header.page_type);
+            black_box(page.header.space_id);
+            black_box(page.data);
+        },
+        |_update| {},
+    );
 }
 #[cfg(feature = "pprof")]
 mod profiling {
@@ -830,10 +2442,17 @@ mod profiling {
 }
 
 pub fn criterion_benchmark(c: &mut Criterion) {
-    // bench_log(c);
-    // bench_mesh(c);
-    // bench_minecraft_savedata(c);
-    // bench_mk48(c);
+    // The adversarial messages are the same for every data set
+    #[cfg(feature = "capnp")]
+    bench_capnp::bench_adversarial();
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench_adversarial();
+
+    bench_log(c);
+    bench_mesh(c);
+    bench_minecraft_savedata(c);
+    bench_mk48(c);
+    bench_tree(c);
     bench_page(c);
 }
 
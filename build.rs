@@ -45,14 +45,45 @@ fn prost_compile_dataset(name: &'static str) -> std::io::Result<()> {
     )
 }
 
+#[cfg(feature = "regenerate-protobuf")]
+fn protobuf_compile_dataset(name: &'static str) -> std::io::Result<()> {
+    // rust-protobuf always names its output after the input file, which would collide with the
+    // thrift output, so generate into OUT_DIR and copy the file into place.
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join("protobuf");
+    std::fs::create_dir_all(&out_dir)?;
+    protobuf_codegen::Codegen::new()
+        .pure()
+        .include("src")
+        .input(format!("src/datasets/{name}/{name}.proto"))
+        .out_dir(&out_dir)
+        .run_from_script();
+    std::fs::copy(
+        out_dir.join(format!("{name}.rs")),
+        format!("src/datasets/{name}/{name}_protobuf.rs"),
+    )?;
+    Ok(())
+}
+
+#[cfg(feature = "regenerate-quick-protobuf")]
+fn quick_protobuf_compile_dataset(name: &'static str) -> pb_rs::errors::Result<()> {
+    let input = PathBuf::from(format!("src/datasets/{name}/{name}.proto"));
+    let output = PathBuf::from(format!("src/datasets/{name}/{name}_quick_protobuf.rs"));
+    let config = pb_rs::ConfigBuilder::new(&[input], Some(&output), None, &["src".into()])?
+        .single_module(true)
+        .build();
+    pb_rs::types::FileDescriptor::run(&config)
+}
+
 fn main() {
     #[cfg(any(
         feature = "regenerate-capnp",
         feature = "regenerate-flatbuffers",
-        feature = "regenerate-prost"
+        feature = "regenerate-prost",
+        feature = "regenerate-protobuf",
+        feature = "regenerate-quick-protobuf"
     ))]
     {
-        const DATASETS: &[&str] = &["log", "mesh", "minecraft_savedata", "mk48"];
+        const DATASETS: &[&str] = &["log", "mesh", "minecraft_savedata", "mk48", "tree"];
         for &name in DATASETS.iter() {
             #[cfg(feature = "regenerate-capnp")]
             capnpc_compile_dataset(name).unwrap();
@@ -60,6 +91,13 @@ fn main() {
             flatc_compile_dataset(name).unwrap();
             #[cfg(feature = "regenerate-prost")]
             prost_compile_dataset(name).unwrap();
+            #[cfg(feature = "regenerate-protobuf")]
+            protobuf_compile_dataset(name).unwrap();
+            // pb-rs doesn't support proto3 optional fields, which the other datasets use
+            #[cfg(feature = "regenerate-quick-protobuf")]
+            if name == "log" || name == "mesh" || name == "tree" {
+                quick_protobuf_compile_dataset(name).unwrap();
+            }
         }
     }
 }
@@ -0,0 +1,230 @@
+//! `#[derive(Generate)]` for the benchmark data set types.
+//!
+//! Fields are generated in declaration order, each with one call to `Generate::generate` unless its
+//! `#[generate(...)]` attribute says otherwise:
+//!
+//! * `range = a..b` draws the value with `Rng::gen_range`.
+//! * `choice = LIST` picks an element of the const array or slice `LIST` and converts it with `Into`.
+//! * `probability = p` makes an `Option` field `Some` with probability `p` instead of one half.
+//! * `len = a..b` generates a `Vec` field with `Rng::gen_range(a..b)` elements, each following the
+//!   `range` or `choice` if there is one.
+//!
+//! Values can also be written as string literals, like `range = "0..100"`. The data set types need
+//! this because savefile's derive rejects attributes with values that aren't literals.
+//!
+//! Enums must only have unit variants, which are picked uniformly.
+//!
+//! The generated code names `crate::Generate`, so it only works inside `rust_serialization_benchmark`.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, ExprLit, Fields,
+    GenericArgument, Lit, PathArguments, Type,
+};
+
+#[proc_macro_derive(Generate, attributes(generate))]
+pub fn derive_generate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match derive(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn derive(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let body = match &input.data {
+        Data::Struct(data) => generate_fields(quote!(Self), &data.fields)?,
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    if !matches!(variant.fields, Fields::Unit) {
+                        return Err(Error::new(
+                            variant.span(),
+                            "Generate can only be derived for enums with unit variants",
+                        ));
+                    }
+                    let i = proc_macro2::Literal::usize_unsuffixed(i);
+                    let ident = &variant.ident;
+                    Ok(quote!(#i => Self::#ident,))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let len = proc_macro2::Literal::usize_unsuffixed(arms.len());
+            quote! {
+                match ::rand::Rng::gen_range(rng, 0..#len) {
+                    #(#arms)*
+                    _ => unreachable!(),
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                input.span(),
+                "Generate can't be derived for unions",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics crate::Generate for #ident #ty_generics #where_clause {
+            fn generate<R: ::rand::Rng>(rng: &mut R) -> Self {
+                #body
+            }
+        }
+    })
+}
+
+fn generate_fields(path: TokenStream, fields: &Fields) -> Result<TokenStream, Error> {
+    match fields {
+        Fields::Named(fields) => {
+            let fields = fields
+                .named
+                .iter()
+                .map(|field| {
+                    let ident = field.ident.as_ref().unwrap();
+                    let value = generate_field(field)?;
+                    Ok(quote!(#ident: #value,))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            Ok(quote!(#path { #(#fields)* }))
+        }
+        Fields::Unnamed(fields) => {
+            let fields = fields
+                .unnamed
+                .iter()
+                .map(generate_field)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(quote!(#path(#(#fields),*)))
+        }
+        Fields::Unit => Ok(path),
+    }
+}
+
+#[derive(Default)]
+struct Attributes {
+    range: Option<Expr>,
+    choice: Option<Expr>,
+    probability: Option<Expr>,
+    len: Option<Expr>,
+}
+
+impl Attributes {
+    fn parse(field: &syn::Field) -> Result<Self, Error> {
+        let mut attributes = Self::default();
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("generate")) {
+            attr.parse_nested_meta(|meta| {
+                let slot = if meta.path.is_ident("range") {
+                    &mut attributes.range
+                } else if meta.path.is_ident("choice") {
+                    &mut attributes.choice
+                } else if meta.path.is_ident("probability") {
+                    &mut attributes.probability
+                } else if meta.path.is_ident("len") {
+                    &mut attributes.len
+                } else {
+                    return Err(meta.error("expected `range`, `choice`, `probability` or `len`"));
+                };
+                *slot = Some(match meta.value()?.parse()? {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(s), ..
+                    }) => s.parse()?,
+                    expr => expr,
+                });
+                Ok(())
+            })?;
+        }
+        if attributes.range.is_some() && attributes.choice.is_some() {
+            return Err(Error::new(
+                field.span(),
+                "`range` and `choice` can't be used together",
+            ));
+        }
+        Ok(attributes)
+    }
+}
+
+fn generate_field(field: &syn::Field) -> Result<TokenStream, Error> {
+    let attributes = Attributes::parse(field)?;
+    let span = field.ty.span();
+
+    if let Some(len) = &attributes.len {
+        let Some(element) = wrapped_type(&field.ty, "Vec") else {
+            return Err(Error::new(span, "`len` can only be used on `Vec` fields"));
+        };
+        let element = generate_value(&attributes, element);
+        // Draws the length first, like `generate_vec`
+        return Ok(quote_spanned! {span=>
+            {
+                let len: usize = ::rand::Rng::gen_range(rng, #len);
+                (0..len).map(|_| #element).collect()
+            }
+        });
+    }
+
+    if let Some(inner) = wrapped_type(&field.ty, "Option") {
+        if attributes.range.is_some()
+            || attributes.choice.is_some()
+            || attributes.probability.is_some()
+        {
+            let probability = attributes
+                .probability
+                .as_ref()
+                .map_or_else(|| quote!(0.5), |p| quote!(#p));
+            let value = generate_value(&attributes, inner);
+            return Ok(quote_spanned! {span=>
+                if ::rand::Rng::gen_bool(rng, #probability) {
+                    Some(#value)
+                } else {
+                    None
+                }
+            });
+        }
+    } else if attributes.probability.is_some() {
+        return Err(Error::new(
+            span,
+            "`probability` can only be used on `Option` fields",
+        ));
+    }
+
+    if wrapped_type(&field.ty, "Vec").is_some() {
+        return Err(Error::new(span, "`Vec` fields need a `len` attribute"));
+    }
+
+    Ok(generate_value(&attributes, &field.ty))
+}
+
+/// Generates one value of `ty`, which is the element type of the field for `Option` and `Vec`.
+fn generate_value(attributes: &Attributes, ty: &Type) -> TokenStream {
+    if let Some(range) = &attributes.range {
+        quote!(::rand::Rng::gen_range(rng, #range))
+    } else if let Some(choice) = &attributes.choice {
+        quote!(::core::convert::Into::into(
+            #choice[::rand::Rng::gen_range(rng, 0..#choice.len())]
+        ))
+    } else {
+        quote!(<#ty as crate::Generate>::generate(rng))
+    }
+}
+
+/// Returns `T` if `ty` is spelled `wrapper<T>`.
+fn wrapped_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
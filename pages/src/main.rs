@@ -3,7 +3,7 @@ use crate::compression::Compression;
 use crate::event::event_target;
 use crate::mode::Mode;
 use row::Row;
-use schema::Results;
+use schema::{base_dataset_name, Results};
 use std::str::FromStr;
 use stylist::css;
 use web_sys::{Event, HtmlInputElement, HtmlSelectElement};
@@ -73,11 +73,16 @@ fn benchmark() -> Html {
         }
     }));
     let dataset = dataset_state.value;
-    let (message_name, messages_per_benchmark) = match dataset.as_str() {
-        "log" => ("logs", 10_000),
+    // Older results don't record how many elements each data set was generated with
+    let scale = results.datasets.get(&dataset).unwrap().scale;
+    let scale = |default: u32| scale.map_or(default, |scale| scale as u32);
+    let (message_name, messages_per_benchmark) = match base_dataset_name(&dataset) {
+        "log" => ("logs", scale(10_000)),
+        // Every triangle is part of the one mesh, however many there are
         "mesh" => ("meshes", 1),
-        "minecraft_savedata" => ("saves", 500),
-        "mk48" => ("updates", 1_000),
+        "minecraft_savedata" => ("saves", scale(500)),
+        "mk48" => ("updates", scale(1_000)),
+        "tree" => ("trees", scale(500)),
         _ => ("messages", 1),
     };
 
@@ -93,6 +98,7 @@ fn benchmark() -> Html {
         .unwrap()
         .features
         .iter()
+        .filter(|(_, feature)| feature.skipped.is_none())
         .map(TryFrom::try_from)
         .collect();
     let rows = match rows {
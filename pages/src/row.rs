@@ -35,7 +35,7 @@ fn unwrap_seconds(bench: &Bench) -> Result<Option<f64>> {
 impl TryFrom<(&String, &Feature)> for Row {
     type Error = Error;
 
-    fn try_from((feature, Feature { benches }): (&String, &Feature)) -> Result<Self> {
+    fn try_from((feature, Feature { benches, .. }): (&String, &Feature)) -> Result<Self> {
         let col = |key: &'static str| -> Result<&Bench> { benches.get(key).ok_or(key) };
 
         let serialize = unwrap_seconds(col("serialize")?)?.ok_or("no serialize primary")? as f32;
@@ -0,0 +1,94 @@
+use apache_avro::{
+    from_avro_datum, from_value, to_avro_datum, to_value, types::Value, Reader, Writer,
+};
+use criterion::{black_box, Criterion};
+use serde::{de::DeserializeOwned, Serialize};
+
+pub trait Schema {
+    /// The `.avsc` schema describing the serde representation of the type.
+    const SCHEMA: &'static str;
+}
+
+// The serde serializer leaves union branches unselected, which the encoder can't handle for
+// heterogeneous tuples like `Player::root_vehicle`. Resolving against the schema selects them.
+fn to_resolved_value<T: Serialize>(schema: &apache_avro::Schema, data: &T) -> Value {
+    to_value(data).unwrap().resolve(schema).unwrap()
+}
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Schema + Serialize + DeserializeOwned + PartialEq,
+{
+    let schema = apache_avro::Schema::parse_str(T::SCHEMA).unwrap();
+
+    let mut group = c.benchmark_group(format!("{}/apache-avro", name));
+
+    let mut serialize = || {
+        let value = to_resolved_value(&schema, black_box(data));
+        black_box(to_avro_datum(&schema, value).unwrap());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "apache-avro", "serialize", serialize);
+
+    let mut serialize_container = || {
+        let mut writer = Writer::new(&schema, Vec::new());
+        writer
+            .append(to_resolved_value(&schema, black_box(data)))
+            .unwrap();
+        black_box(writer.into_inner().unwrap());
+    };
+    group.bench_function("serialize (container file)", |b| {
+        b.iter(&mut serialize_container)
+    });
+    crate::bench_latency(
+        name,
+        "apache-avro",
+        "serialize (container file)",
+        serialize_container,
+    );
+
+    let datum = to_avro_datum(&schema, to_resolved_value(&schema, data)).unwrap();
+    let container = {
+        let mut writer = Writer::new(&schema, Vec::new());
+        writer.append(to_resolved_value(&schema, data)).unwrap();
+        writer.into_inner().unwrap()
+    };
+
+    let mut deserialize = || {
+        let value = from_avro_datum(&schema, &mut black_box(datum.as_slice()), None).unwrap();
+        black_box(from_value::<T>(&value).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "apache-avro", "deserialize", deserialize);
+
+    let mut deserialize_container = || {
+        let mut reader = Reader::with_schema(&schema, black_box(container.as_slice())).unwrap();
+        let value = reader.next().unwrap().unwrap();
+        black_box(from_value::<T>(&value).unwrap());
+    };
+    group.bench_function("deserialize (container file)", |b| {
+        b.iter(&mut deserialize_container)
+    });
+    crate::bench_latency(
+        name,
+        "apache-avro",
+        "deserialize (container file)",
+        deserialize_container,
+    );
+
+    crate::bench_size(name, "apache-avro", datum.as_slice());
+    // The container file wraps the same datum in a header that holds the schema, and a block with
+    // its length and a sync marker
+    crate::bench_size_variant(name, "apache-avro", "container file", container.as_slice());
+
+    let value = from_avro_datum(&schema, &mut datum.as_slice(), None).unwrap();
+    assert!(from_value::<T>(&value).unwrap() == *data);
+    let value = Reader::with_schema(&schema, container.as_slice())
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert!(from_value::<T>(&value).unwrap() == *data);
+
+    group.finish();
+}
@@ -0,0 +1,47 @@
+use std::io::Write;
+
+use bebop::{Record, SeResult};
+use criterion::{black_box, Criterion};
+
+pub trait Serialize: Sized {
+    type Message<'raw>: Record<'raw> + Into<Self>;
+
+    /// Builds the message for `self` and writes it to `dest`.
+    fn serialize_bebop<W: Write>(&self, dest: &mut W) -> SeResult<usize>;
+}
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + PartialEq,
+{
+    const BUFFER_LEN: usize = 10_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/bebop", name));
+
+    let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
+
+    let mut serialize = || {
+        black_box(&mut serialize_buffer).clear();
+        black_box(data)
+            .serialize_bebop(&mut serialize_buffer)
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "bebop", "serialize", serialize);
+
+    let mut deserialize_buffer = Vec::new();
+    data.serialize_bebop(&mut deserialize_buffer).unwrap();
+
+    let mut deserialize = || {
+        black_box(T::Message::deserialize(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bebop", "deserialize", deserialize);
+
+    crate::bench_size(name, "bebop", deserialize_buffer.as_slice());
+
+    assert!(T::Message::deserialize(&deserialize_buffer).unwrap().into() == *data);
+
+    group.finish();
+}
@@ -14,21 +14,21 @@ where
     let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
     let mut prepend_buffer = ReverseBuffer::with_capacity(BUFFER_LEN);
 
-    group.bench_function("serialize (encode)", |b| {
-        b.iter(|| {
-            black_box(&mut serialize_buffer).clear();
-            data.encode(&mut serialize_buffer).unwrap();
-            black_box(());
-        })
-    });
-
-    group.bench_function("serialize (prepend)", |b| {
-        b.iter(|| {
-            black_box(&mut prepend_buffer).clear();
-            data.prepend(&mut prepend_buffer);
-            black_box(());
-        })
-    });
+    let mut encode = || {
+        black_box(&mut serialize_buffer).clear();
+        data.encode(&mut serialize_buffer).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize (encode)", |b| b.iter(&mut encode));
+    crate::bench_latency(name, "bilrost", "serialize (encode)", encode);
+
+    let mut prepend = || {
+        black_box(&mut prepend_buffer).clear();
+        data.prepend(&mut prepend_buffer);
+        black_box(());
+    };
+    group.bench_function("serialize (prepend)", |b| b.iter(&mut prepend));
+    crate::bench_latency(name, "bilrost", "serialize (prepend)", prepend);
 
     let mut deserialize_buffer = Vec::new();
     data.encode(&mut deserialize_buffer).unwrap();
@@ -38,11 +38,11 @@ where
     // prepended encoding path emits precisely the same bytes as the forward-encoded one.
     assert_eq!(prepended_data, deserialize_buffer);
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(T::decode(black_box(&deserialize_buffer).as_slice()).unwrap());
-        })
-    });
+    let mut deserialize = || {
+        black_box(T::decode(black_box(&deserialize_buffer).as_slice()).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bilrost", "deserialize", deserialize);
 
     crate::bench_size(name, "bilrost", deserialize_buffer.as_slice());
 
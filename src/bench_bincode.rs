@@ -1,4 +1,7 @@
 use criterion::{black_box, Criterion};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::ReadRandom;
 
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
@@ -7,28 +10,29 @@ where
     const BUFFER_LEN: usize = 10_000_000;
     let mut group = c.benchmark_group(format!("{}/bincode", name));
 
-    let mut buffer = Box::new([0u8; BUFFER_LEN]);
     let conf = bincode::config::standard();
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            let size = bincode::encode_into_slice(black_box(&data), black_box(&mut *buffer), conf)
-                .unwrap();
-            black_box(&buffer[..size]);
-        })
-    });
+    let len = bincode::encode_to_vec(data, conf).unwrap().len();
+    let mut buffer = vec![0u8; BUFFER_LEN.max(len)].into_boxed_slice();
+    let mut serialize = || {
+        let size =
+            bincode::encode_into_slice(black_box(&data), black_box(&mut *buffer), conf).unwrap();
+        black_box(&buffer[..size]);
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "bincode", "serialize", serialize);
 
-    let size = bincode::encode_into_slice(data, &mut *buffer, conf).unwrap();
+    let size = bincode::encode_into_slice(data, &mut buffer, conf).unwrap();
     let buffer = &buffer[..size];
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(
-                bincode::decode_from_slice::<T, _>(black_box(buffer), conf)
-                    .unwrap()
-                    .0,
-            );
-        })
-    });
+    let mut deserialize = || {
+        black_box(
+            bincode::decode_from_slice::<T, _>(black_box(buffer), conf)
+                .unwrap()
+                .0,
+        );
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bincode", "deserialize", deserialize);
 
     crate::bench_size(name, "bincode", buffer);
 
@@ -41,3 +45,68 @@ where
 
     group.finish();
 }
+
+pub fn bench_serde<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq,
+{
+    const BUFFER_LEN: usize = 10_000_000;
+    let mut group = c.benchmark_group(format!("{}/bincode", name));
+
+    let conf = bincode::config::standard();
+    let len = bincode::serde::encode_to_vec(data, conf).unwrap().len();
+    let mut buffer = vec![0u8; BUFFER_LEN.max(len)].into_boxed_slice();
+    let mut serialize = || {
+        let size =
+            bincode::serde::encode_into_slice(black_box(&data), black_box(&mut *buffer), conf)
+                .unwrap();
+        black_box(&buffer[..size]);
+    };
+    group.bench_function("serialize (serde)", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "bincode", "serialize (serde)", serialize);
+
+    let size = bincode::serde::encode_into_slice(data, &mut buffer, conf).unwrap();
+    let buffer = &buffer[..size];
+
+    let mut deserialize = || {
+        black_box(
+            bincode::serde::decode_from_slice::<T, _>(black_box(buffer), conf)
+                .unwrap()
+                .0,
+        );
+    };
+    group.bench_function("deserialize (serde)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bincode", "deserialize (serde)", deserialize);
+
+    assert!(
+        bincode::serde::decode_from_slice::<T, _>(buffer, conf)
+            .unwrap()
+            .0
+            == *data
+    );
+
+    group.finish();
+}
+
+/// Benchmarks deserializing the whole buffer and then reading the elements at `indices`, which is
+/// what the zero-copy `read_random` benchmarks avoid.
+pub fn bench_read_random<T>(name: &'static str, c: &mut Criterion, data: &T, indices: &[usize])
+where
+    T: bincode::Encode + bincode::Decode + ReadRandom,
+{
+    let mut group = c.benchmark_group(format!("{}/bincode", name));
+
+    let conf = bincode::config::standard();
+    let buffer = bincode::encode_to_vec(data, conf).unwrap();
+
+    group.bench_function("read_random", |b| {
+        b.iter(|| {
+            bincode::decode_from_slice::<T, _>(black_box(&buffer), conf)
+                .unwrap()
+                .0
+                .read_random(black_box(indices));
+        })
+    });
+
+    group.finish();
+}
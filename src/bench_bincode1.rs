@@ -1,6 +1,9 @@
+use bincode1::Options;
 use criterion::{black_box, Criterion};
 use serde::{Deserialize, Serialize};
 
+use crate::Borrowable;
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: Serialize + for<'de> Deserialize<'de> + PartialEq,
@@ -9,27 +12,75 @@ where
 
     let mut group = c.benchmark_group(format!("{}/bincode1", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            bincode1::serialize_into(black_box(serialize_buffer.as_mut_slice()), black_box(&data))
-                .unwrap();
-            black_box(());
-        })
-    });
-
     let mut deserialize_buffer = Vec::new();
     bincode1::serialize_into(&mut deserialize_buffer, &data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(bincode1::deserialize::<'_, T>(black_box(&deserialize_buffer)).unwrap());
-        })
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        bincode1::serialize_into(black_box(serialize_buffer.as_mut_slice()), black_box(&data))
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "bincode1", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(bincode1::deserialize::<'_, T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bincode1", "deserialize", deserialize);
+
+    let mut value = bincode1::deserialize::<T>(&deserialize_buffer).unwrap();
+    let mut deserialize_in_place = || {
+        let mut deserializer = bincode1::Deserializer::from_slice(
+            black_box(&deserialize_buffer),
+            bincode1::DefaultOptions::new()
+                .with_fixint_encoding()
+                .allow_trailing_bytes(),
+        );
+        T::deserialize_in_place(&mut deserializer, black_box(&mut value)).unwrap();
+    };
+    group.bench_function("deserialize (in place)", |b| {
+        b.iter(&mut deserialize_in_place)
     });
+    crate::bench_latency(
+        name,
+        "bincode1",
+        "deserialize (in place)",
+        deserialize_in_place,
+    );
 
     crate::bench_size(name, "bincode1", deserialize_buffer.as_slice());
 
     assert!(bincode1::deserialize::<'_, T>(black_box(&deserialize_buffer)).unwrap() == *data);
+    assert!(value == *data);
+
+    group.finish();
+}
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/bincode1", name));
+
+    let mut deserialize_buffer = Vec::new();
+    bincode1::serialize_into(&mut deserialize_buffer, &data).unwrap();
+
+    let mut deserialize = || {
+        black_box(
+            bincode1::deserialize::<'_, T::Borrowed<'_>>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bincode1", "deserialize (borrowed)", deserialize);
+
+    assert!(
+        bincode1::deserialize::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
 
     group.finish();
 }
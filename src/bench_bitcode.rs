@@ -1,5 +1,8 @@
 use bitcode::{DecodeOwned, Encode};
 use criterion::{black_box, Criterion};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::ReadRandom;
 
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
@@ -8,19 +11,19 @@ where
     let mut group = c.benchmark_group(format!("{}/bitcode", name));
     let mut buffer = bitcode::Buffer::new();
 
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            black_box(buffer.encode(black_box(data)));
-        })
-    });
+    let mut serialize = || {
+        black_box(buffer.encode(black_box(data)));
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "bitcode", "serialize", serialize);
 
     let encoded = buffer.encode(data).to_vec();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(buffer.decode::<T>(black_box(&encoded)).unwrap());
-        })
-    });
+    let mut deserialize = || {
+        black_box(buffer.decode::<T>(black_box(&encoded)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bitcode", "deserialize", deserialize);
 
     crate::bench_size(name, "bitcode", &encoded);
 
@@ -28,3 +31,53 @@ where
 
     group.finish();
 }
+
+pub fn bench_serde<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/bitcode", name));
+
+    let mut serialize = || {
+        black_box(bitcode::serialize(black_box(data)).unwrap());
+    };
+    group.bench_function("serialize (serde)", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "bitcode", "serialize (serde)", serialize);
+
+    let encoded = bitcode::serialize(data).unwrap();
+
+    let mut deserialize = || {
+        black_box(bitcode::deserialize::<T>(black_box(&encoded)).unwrap());
+    };
+    group.bench_function("deserialize (serde)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bitcode", "deserialize (serde)", deserialize);
+
+    crate::bench_size_variant(name, "bitcode", "serde", &encoded);
+
+    assert!(bitcode::deserialize::<T>(&encoded).unwrap() == *data);
+
+    group.finish();
+}
+
+/// Benchmarks deserializing the whole buffer and then reading the elements at `indices`, which is
+/// what the zero-copy `read_random` benchmarks avoid.
+pub fn bench_read_random<T>(name: &'static str, c: &mut Criterion, data: &T, indices: &[usize])
+where
+    T: Encode + DecodeOwned + ReadRandom,
+{
+    let mut group = c.benchmark_group(format!("{}/bitcode", name));
+    let mut buffer = bitcode::Buffer::new();
+
+    let encoded = buffer.encode(data).to_vec();
+
+    group.bench_function("read_random", |b| {
+        b.iter(|| {
+            buffer
+                .decode::<T>(black_box(&encoded))
+                .unwrap()
+                .read_random(black_box(indices));
+        })
+    });
+
+    group.finish();
+}
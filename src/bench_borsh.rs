@@ -1,6 +1,8 @@
 use borsh::{to_vec, BorshDeserialize, BorshSerialize};
 use criterion::{black_box, Criterion};
 
+use crate::ReadRandom;
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: BorshSerialize + BorshDeserialize + PartialEq,
@@ -9,22 +11,22 @@ where
 
     let mut group = c.benchmark_group(format!("{}/borsh", name));
 
-    let mut serialize_buffer = vec![0u8; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            data.serialize(black_box(&mut serialize_buffer.as_mut_slice()))
-                .unwrap();
-            black_box(());
-        })
-    });
-
     let deserialize_buffer = to_vec(data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(T::deserialize(&mut deserialize_buffer.as_slice()).unwrap());
-        })
-    });
+    let mut serialize_buffer = vec![0u8; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        data.serialize(black_box(&mut serialize_buffer.as_mut_slice()))
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "borsh", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(T::deserialize(&mut deserialize_buffer.as_slice()).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "borsh", "deserialize", deserialize);
 
     crate::bench_size(name, "borsh", deserialize_buffer.as_slice());
 
@@ -32,3 +34,24 @@ where
 
     group.finish();
 }
+
+/// Benchmarks deserializing the whole buffer and then reading the elements at `indices`, which is
+/// what the zero-copy `read_random` benchmarks avoid.
+pub fn bench_read_random<T>(name: &'static str, c: &mut Criterion, data: &T, indices: &[usize])
+where
+    T: BorshSerialize + BorshDeserialize + ReadRandom,
+{
+    let mut group = c.benchmark_group(format!("{}/borsh", name));
+
+    let deserialize_buffer = to_vec(data).unwrap();
+
+    group.bench_function("read_random", |b| {
+        b.iter(|| {
+            T::deserialize(&mut black_box(deserialize_buffer.as_slice()))
+                .unwrap()
+                .read_random(black_box(indices));
+        })
+    });
+
+    group.finish();
+}
@@ -0,0 +1,89 @@
+use criterion::{black_box, Criterion};
+use serde::{Deserialize, Serialize};
+
+use crate::{Borrowable, Projectable};
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq,
+{
+    const BUFFER_LEN: usize = 10_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/bson", name));
+
+    let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
+    let mut serialize = || {
+        black_box(&mut serialize_buffer).clear();
+        bson::serialize_to_buffer(black_box(data), &mut serialize_buffer).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "bson", "serialize", serialize);
+
+    let deserialize_buffer = bson::serialize_to_vec(data).unwrap();
+
+    let mut deserialize = || {
+        black_box(bson::deserialize_from_slice::<T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bson", "deserialize", deserialize);
+
+    crate::bench_size(name, "bson", deserialize_buffer.as_slice());
+
+    assert!(bson::deserialize_from_slice::<T>(&deserialize_buffer).unwrap() == *data);
+
+    group.finish();
+}
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/bson", name));
+
+    let deserialize_buffer = bson::serialize_to_vec(data).unwrap();
+
+    let mut deserialize = || {
+        black_box(
+            bson::deserialize_from_slice::<T::Borrowed<'_>>(black_box(&deserialize_buffer))
+                .unwrap(),
+        );
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bson", "deserialize (borrowed)", deserialize);
+
+    assert!(
+        bson::deserialize_from_slice::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+
+    group.finish();
+}
+
+pub fn bench_project<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Projectable,
+{
+    let mut group = c.benchmark_group(format!("{}/bson", name));
+
+    let deserialize_buffer = bson::serialize_to_vec(data).unwrap();
+
+    let mut project = || {
+        black_box(
+            bson::deserialize_from_slice::<T::Projection>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("project", |b| b.iter(&mut project));
+    crate::bench_latency(name, "bson", "project", project);
+
+    assert!(
+        bson::deserialize_from_slice::<T::Projection>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == data.project()
+    );
+
+    group.finish();
+}
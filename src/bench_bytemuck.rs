@@ -0,0 +1,144 @@
+use bytemuck::Pod;
+use criterion::{black_box, Criterion};
+
+/// Benchmarks casting a single plain-old-data value to and from bytes.
+pub fn bench<T, R, U>(name: &'static str, c: &mut Criterion, data: &T, read: R, update: U)
+where
+    T: Pod + PartialEq,
+    R: Fn(&T),
+    U: Fn(&mut T),
+{
+    let mut group = c.benchmark_group(format!("{}/bytemuck", name));
+
+    // Backed by a `T` so that the bytes are always aligned for it.
+    let mut buffer = T::zeroed();
+
+    let mut serialize = || {
+        bytemuck::bytes_of_mut(black_box(&mut buffer))
+            .copy_from_slice(bytemuck::bytes_of(black_box(data)));
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "bytemuck", "serialize", serialize);
+
+    buffer = *data;
+    let bytes = bytemuck::bytes_of(&buffer);
+
+    group.bench_function("access (validated upfront with error)", |b| {
+        b.iter(|| black_box(bytemuck::try_from_bytes::<T>(black_box(bytes)).unwrap()))
+    });
+
+    group.bench_function("read (validated upfront with error)", |b| {
+        b.iter(|| {
+            read(bytemuck::try_from_bytes(black_box(bytes)).unwrap());
+            black_box(());
+        })
+    });
+
+    let mut deserialize = || {
+        let value: T = *bytemuck::try_from_bytes(black_box(bytes)).unwrap();
+        black_box(value);
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bytemuck", "deserialize", deserialize);
+
+    let mut update_buffer = buffer;
+    let update_bytes = bytemuck::bytes_of_mut(&mut update_buffer);
+    group.bench_function("update (validated upfront with error)", |b| {
+        b.iter(|| {
+            update(bytemuck::try_from_bytes_mut(black_box(&mut *update_bytes)).unwrap());
+            black_box(());
+        })
+    });
+
+    crate::bench_size(name, "bytemuck", bytes);
+
+    assert!(bytemuck::from_bytes::<T>(bytes) == data);
+
+    group.finish();
+}
+
+/// Benchmarks casting a slice of plain-old-data values to and from bytes.
+pub fn bench_slice<T, R, U>(name: &'static str, c: &mut Criterion, data: &[T], read: R, update: U)
+where
+    T: Pod + PartialEq,
+    R: Fn(&[T]),
+    U: Fn(&mut [T]),
+{
+    let mut group = c.benchmark_group(format!("{}/bytemuck", name));
+
+    // Backed by `T`s so that the bytes are always aligned for them.
+    let mut buffer = vec![T::zeroed(); data.len()];
+
+    let mut serialize = || {
+        bytemuck::cast_slice_mut::<T, u8>(black_box(&mut buffer))
+            .copy_from_slice(bytemuck::cast_slice(black_box(data)));
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "bytemuck", "serialize", serialize);
+
+    buffer.copy_from_slice(data);
+    let bytes: &[u8] = bytemuck::cast_slice(&buffer);
+
+    group.bench_function("access (validated upfront with error)", |b| {
+        b.iter(|| black_box(bytemuck::try_cast_slice::<u8, T>(black_box(bytes)).unwrap()))
+    });
+
+    group.bench_function("read (validated upfront with error)", |b| {
+        b.iter(|| {
+            read(bytemuck::try_cast_slice(black_box(bytes)).unwrap());
+            black_box(());
+        })
+    });
+
+    let mut deserialize = || {
+        let value: Vec<T> = bytemuck::try_cast_slice(black_box(bytes)).unwrap().to_vec();
+        black_box(value);
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "bytemuck", "deserialize", deserialize);
+
+    let mut update_buffer = buffer.clone();
+    let update_bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut update_buffer);
+    group.bench_function("update (validated upfront with error)", |b| {
+        b.iter(|| {
+            update(bytemuck::try_cast_slice_mut(black_box(&mut *update_bytes)).unwrap());
+            black_box(());
+        })
+    });
+
+    crate::bench_size(name, "bytemuck", bytes);
+
+    assert!(bytemuck::cast_slice::<u8, T>(bytes) == data);
+
+    group.finish();
+}
+
+/// Benchmarks reading the elements at `indices` out of a large slice of values.
+pub fn bench_slice_read_random<T, R>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &[T],
+    indices: &[usize],
+    read: R,
+) where
+    T: Pod,
+    R: Fn(&[T], &[usize]),
+{
+    let mut group = c.benchmark_group(format!("{}/bytemuck", name));
+
+    let bytes = bytemuck::cast_slice::<T, u8>(data);
+
+    group.bench_function("read_random (validated upfront with error)", |b| {
+        b.iter(|| {
+            read(
+                bytemuck::try_cast_slice(black_box(bytes)).unwrap(),
+                black_box(indices),
+            );
+            black_box(());
+        })
+    });
+
+    group.finish();
+}
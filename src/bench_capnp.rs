@@ -1,4 +1,13 @@
-use capnp::{message::ScratchSpaceHeapAllocator, serialize::read_message_from_flat_slice};
+use core::marker::PhantomData;
+
+use capnp::{
+    message::{ReaderOptions, ScratchSpaceHeapAllocator},
+    private::{
+        arena::{BuilderArena, ReaderArena},
+        layout::PointerBuilder,
+    },
+    serialize::read_message_from_flat_slice,
+};
 use criterion::{black_box, Criterion};
 
 pub trait Serialize<'a> {
@@ -8,10 +17,11 @@ pub trait Serialize<'a> {
     fn serialize_capnp(&self, builder: &mut Self::Builder);
 }
 
-pub fn bench<T, R>(name: &'static str, c: &mut Criterion, data: &T, read: R)
+pub fn bench<T, R, U>(name: &'static str, c: &mut Criterion, data: &T, read: R, update: U)
 where
     T: for<'a> Serialize<'a>,
-    R: Fn(&mut &[u8]),
+    R: Fn(&mut &[u8], ReaderOptions) -> capnp::Result<()>,
+    U: Fn(capnp::any_pointer::Builder<'_>) -> capnp::Result<()>,
 {
     const BUFFER_LEN: usize = 1_000_000;
 
@@ -22,29 +32,27 @@ where
     let mut scratch_words = capnp::Word::allocate_zeroed_vec(BUFFER_LEN);
     let mut allocator =
         ScratchSpaceHeapAllocator::new(capnp::Word::words_to_bytes_mut(&mut scratch_words[..]));
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            black_box(&mut serialize_buffer).clear();
-            let mut builder = capnp::message::Builder::new(&mut allocator);
-            data.serialize_capnp(&mut builder.init_root::<T::Builder>());
-            capnp::serialize::write_message(&mut serialize_buffer, &builder).unwrap();
-            black_box(());
-        })
-    });
+    let mut serialize = || {
+        black_box(&mut serialize_buffer).clear();
+        let mut builder = capnp::message::Builder::new(&mut allocator);
+        data.serialize_capnp(&mut builder.init_root::<T::Builder>());
+        capnp::serialize::write_message(&mut serialize_buffer, &builder).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "capnp", "serialize", serialize);
 
     let mut deserialize_buffer = Vec::new();
     let mut builder = capnp::message::Builder::new(&mut allocator);
     data.serialize_capnp(&mut builder.init_root::<T::Builder>());
     capnp::serialize::write_message(&mut deserialize_buffer, &builder).unwrap();
+    let options = reader_options(&deserialize_buffer);
 
     group.bench_function("access (validated on-demand with error)", |b| {
         b.iter(|| {
             black_box(&mut deserialize_buffer);
-            let message_reader = read_message_from_flat_slice(
-                &mut deserialize_buffer.as_slice(),
-                Default::default(),
-            )
-            .unwrap();
+            let message_reader =
+                read_message_from_flat_slice(&mut deserialize_buffer.as_slice(), options).unwrap();
             let reader = message_reader.get_root::<T::Reader>().unwrap();
             black_box(reader);
         })
@@ -52,12 +60,361 @@ where
 
     group.bench_function("read (validated on-demand with error)", |b| {
         b.iter(|| {
-            read(black_box(&mut deserialize_buffer.as_slice()));
+            read(black_box(&mut deserialize_buffer.as_slice()), options).unwrap();
+            black_box(());
+        })
+    });
+
+    // The smallest limits that still accept reading the data set.
+    let accepts =
+        |options: ReaderOptions| read(&mut deserialize_buffer.as_slice(), options).is_ok();
+    let tight_options = ReaderOptions {
+        traversal_limit_in_words: Some(crate::smallest_limit(
+            options.traversal_limit_in_words.unwrap(),
+            |limit| {
+                accepts(ReaderOptions {
+                    traversal_limit_in_words: Some(limit),
+                    ..options
+                })
+            },
+        )),
+        nesting_limit: crate::smallest_limit(options.nesting_limit as usize, |limit| {
+            accepts(ReaderOptions {
+                nesting_limit: limit as i32,
+                ..options
+            })
+        }) as i32,
+    };
+    group.bench_function("read (validated on-demand with tight limits)", |b| {
+        b.iter(|| {
+            read(black_box(&mut deserialize_buffer.as_slice()), tight_options).unwrap();
             black_box(());
         })
     });
 
+    // Points between the tight limits and the ones above, to show whether the limits' headroom
+    // changes the cost of reading.
+    for factor in [2, 4, 16] {
+        let options = ReaderOptions {
+            traversal_limit_in_words: tight_options
+                .traversal_limit_in_words
+                .zip(options.traversal_limit_in_words)
+                .map(|(tight, limit)| (tight * factor).min(limit)),
+            nesting_limit: (tight_options.nesting_limit * factor as i32).min(options.nesting_limit),
+        };
+        group.bench_function(
+            format!("read (validated on-demand with {factor}x tight limits)"),
+            |b| {
+                b.iter(|| {
+                    read(black_box(&mut deserialize_buffer.as_slice()), options).unwrap();
+                    black_box(());
+                })
+            },
+        );
+    }
+
+    let unlimited_options = *ReaderOptions::new().traversal_limit_in_words(None);
+    group.bench_function("read (validated on-demand without traversal limit)", |b| {
+        b.iter(|| {
+            read(
+                black_box(&mut deserialize_buffer.as_slice()),
+                unlimited_options,
+            )
+            .unwrap();
+            black_box(());
+        })
+    });
+
+    // Builders trust the pointers they follow, so this has no validated counterpart.
+    let mut update_buffer = capnp::Word::allocate_zeroed_vec(deserialize_buffer.len() / 8);
+    capnp::Word::words_to_bytes_mut(&mut update_buffer).copy_from_slice(&deserialize_buffer);
+    group.bench_function("update (unvalidated)", |b| {
+        b.iter(|| {
+            let mut arena = InPlaceArena::new(black_box(update_buffer.as_mut_slice()));
+            update(arena.root()).unwrap();
+            black_box(());
+        })
+    });
+
+    read(&mut capnp::Word::words_to_bytes(&update_buffer), options).unwrap();
+
     crate::bench_size(name, "capnp", deserialize_buffer.as_slice());
 
     group.finish();
 }
+
+/// Records whether reading the adversarial deep and wide messages fails with the default reader
+/// options and with the traversal limit turned off. Neither depends on the data set, so this only
+/// runs once.
+pub fn bench_adversarial() {
+    for (limits, options) in [
+        ("default limits", ReaderOptions::default()),
+        (
+            "no traversal limit",
+            *ReaderOptions::new().traversal_limit_in_words(None),
+        ),
+    ] {
+        let deep = read_total_size(&deep_message(), options);
+        crate::bench_adversarial("capnp", "deep message", limits, deep);
+        let wide = read_void_list(&wide_message(), options);
+        crate::bench_adversarial("capnp", "wide message", limits, wide);
+    }
+}
+
+/// Benchmarks reading the elements at `indices` out of a large message, which only touches the parts
+/// of the message that hold them.
+pub fn bench_read_random<T, R>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &T,
+    indices: &[usize],
+    read: R,
+) where
+    T: for<'a> Serialize<'a>,
+    R: Fn(&mut &[u8], &[usize], ReaderOptions) -> capnp::Result<()>,
+{
+    let mut group = c.benchmark_group(format!("{}/capnp", name));
+
+    let mut buffer = Vec::new();
+    let mut builder = capnp::message::Builder::new_default();
+    data.serialize_capnp(&mut builder.init_root::<T::Builder>());
+    capnp::serialize::write_message(&mut buffer, &builder).unwrap();
+    let options = reader_options(&buffer);
+
+    group.bench_function("read_random (validated on-demand with error)", |b| {
+        b.iter(|| {
+            read(
+                black_box(&mut buffer.as_slice()),
+                black_box(indices),
+                options,
+            )
+            .unwrap();
+            black_box(());
+        })
+    });
+
+    group.finish();
+}
+
+/// Benchmarks reading only the fields that `project` looks at out of a message.
+pub fn bench_project<T, P>(name: &'static str, c: &mut Criterion, data: &T, project: P)
+where
+    T: for<'a> Serialize<'a>,
+    P: Fn(&mut &[u8], ReaderOptions) -> capnp::Result<()>,
+{
+    let mut group = c.benchmark_group(format!("{}/capnp", name));
+
+    let mut buffer = Vec::new();
+    let mut builder = capnp::message::Builder::new_default();
+    data.serialize_capnp(&mut builder.init_root::<T::Builder>());
+    capnp::serialize::write_message(&mut buffer, &builder).unwrap();
+    let options = reader_options(&buffer);
+
+    group.bench_function("project (validated on-demand with error)", |b| {
+        b.iter(|| {
+            project(black_box(&mut buffer.as_slice()), options).unwrap();
+            black_box(());
+        })
+    });
+
+    group.finish();
+}
+
+/// An arena over the segments of an existing message, which opens builders on it so its fields can
+/// be set in place.
+///
+/// capnp doesn't expose a way to build on top of an existing message, so this goes through its
+/// semver-exempt `private` module. The segments are already full, so anything that would need to
+/// allocate (like setting text) panics instead.
+struct InPlaceArena<'a> {
+    segments: Vec<(*mut u8, u32)>,
+    _message: PhantomData<&'a mut [capnp::Word]>,
+}
+
+impl<'a> InPlaceArena<'a> {
+    /// Splits a message framed by `capnp::serialize::write_message` into its segments.
+    fn new(message: &'a mut [capnp::Word]) -> Self {
+        let bytes = capnp::Word::words_to_bytes_mut(message);
+        let table = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+
+        let segment_count = table(0) as usize + 1;
+        let mut start = (segment_count / 2 + 1) * 8;
+        let mut segments = Vec::with_capacity(segment_count);
+        for i in 1..=segment_count {
+            let len = table(i);
+            segments.push((start, len));
+            start += len as usize * 8;
+        }
+        assert!(start <= bytes.len());
+
+        let base = bytes.as_mut_ptr();
+        let segments = segments
+            .into_iter()
+            .map(|(start, len)| (unsafe { base.add(start) }, len))
+            .collect();
+
+        Self {
+            segments,
+            _message: PhantomData,
+        }
+    }
+
+    fn root(&mut self) -> capnp::any_pointer::Builder<'_> {
+        let root = self.segments[0].0;
+        capnp::any_pointer::Builder::new(PointerBuilder::get_root(self, 0, root))
+    }
+}
+
+impl ReaderArena for InPlaceArena<'_> {
+    fn get_segment(&self, id: u32) -> capnp::Result<(*const u8, u32)> {
+        let (start, len) = self.segments[id as usize];
+        Ok((start, len))
+    }
+
+    unsafe fn check_offset(
+        &self,
+        _segment_id: u32,
+        start: *const u8,
+        offset_in_words: i32,
+    ) -> capnp::Result<*const u8> {
+        Ok(unsafe { start.offset(offset_in_words as isize * 8) })
+    }
+
+    fn contains_interval(&self, _id: u32, _start: *const u8, _size: usize) -> capnp::Result<()> {
+        Ok(())
+    }
+
+    fn amplified_read(&self, _virtual_amount: u64) -> capnp::Result<()> {
+        Ok(())
+    }
+
+    fn nesting_limit(&self) -> i32 {
+        i32::MAX
+    }
+}
+
+impl BuilderArena for InPlaceArena<'_> {
+    fn allocate(&mut self, _segment_id: u32, _amount: u32) -> Option<u32> {
+        None
+    }
+
+    fn allocate_anywhere(&mut self, _amount: u32) -> (u32, u32) {
+        panic!("can't allocate in a message that's being updated in place")
+    }
+
+    fn get_segment_mut(&mut self, id: u32) -> (*mut u8, u32) {
+        self.segments[id as usize]
+    }
+
+    fn as_reader(&self) -> &dyn ReaderArena {
+        self
+    }
+}
+
+/// Benchmarks the packed framing, which compresses away zero bytes and has to be unpacked before
+/// the message can be accessed.
+pub fn bench_packed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: for<'a> Serialize<'a>,
+{
+    const BUFFER_LEN: usize = 1_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/capnp-packed", name));
+
+    let mut serialize_buffer = Vec::new();
+
+    let mut scratch_words = capnp::Word::allocate_zeroed_vec(BUFFER_LEN);
+    let mut allocator =
+        ScratchSpaceHeapAllocator::new(capnp::Word::words_to_bytes_mut(&mut scratch_words[..]));
+    let mut serialize = || {
+        black_box(&mut serialize_buffer).clear();
+        let mut builder = capnp::message::Builder::new(&mut allocator);
+        data.serialize_capnp(&mut builder.init_root::<T::Builder>());
+        capnp::serialize_packed::write_message(&mut serialize_buffer, &builder).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "capnp-packed", "serialize", serialize);
+
+    let mut deserialize_buffer = Vec::new();
+    let mut builder = capnp::message::Builder::new(&mut allocator);
+    data.serialize_capnp(&mut builder.init_root::<T::Builder>());
+    capnp::serialize_packed::write_message(&mut deserialize_buffer, &builder).unwrap();
+    let options = reader_options(&capnp::serialize::write_message_to_words(&builder));
+
+    group.bench_function("access (validated on-demand with error)", |b| {
+        b.iter(|| {
+            let message_reader = capnp::serialize_packed::read_message(
+                black_box(deserialize_buffer.as_slice()),
+                options,
+            )
+            .unwrap();
+            let reader = message_reader.get_root::<T::Reader>().unwrap();
+            black_box(reader);
+        })
+    });
+
+    crate::bench_size(name, "capnp-packed", deserialize_buffer.as_slice());
+
+    group.finish();
+}
+
+/// The default reader options, with the traversal limit raised to the size of `message` when that's
+/// larger, so that data sets scaled past the default can still be read in one pass.
+fn reader_options(message: &[u8]) -> ReaderOptions {
+    let default = ReaderOptions::default();
+    ReaderOptions {
+        traversal_limit_in_words: default
+            .traversal_limit_in_words
+            .map(|limit| limit.max(message.len() / 8)),
+        ..default
+    }
+}
+
+/// Reads the message in `bytes` and walks every pointer in it.
+fn read_total_size(mut bytes: &[u8], options: ReaderOptions) -> capnp::Result<()> {
+    let message_reader = read_message_from_flat_slice(&mut bytes, options)?;
+    message_reader
+        .get_root::<capnp::any_pointer::Reader>()?
+        .target_size()?;
+    Ok(())
+}
+
+/// Reads the message in `bytes` as a list of voids.
+fn read_void_list(mut bytes: &[u8], options: ReaderOptions) -> capnp::Result<()> {
+    let message_reader = read_message_from_flat_slice(&mut bytes, options)?;
+    message_reader.get_root::<capnp::primitive_list::Reader<()>>()?;
+    Ok(())
+}
+
+/// Frames a single segment of raw words as a message.
+fn single_segment_message(words: &[u64]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8 + words.len() * 8);
+    bytes.extend_from_slice(&0u32.to_le_bytes());
+    bytes.extend_from_slice(&(words.len() as u32).to_le_bytes());
+    for word in words {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    bytes
+}
+
+/// A chain of structs that each hold a single pointer to the next one, nested far deeper than any
+/// of the data sets.
+fn deep_message() -> Vec<u8> {
+    const DEPTH: usize = 1_000;
+    // A struct pointer to the very next word, with no data section and one pointer.
+    const NEXT: u64 = 1 << 48;
+
+    let mut words = vec![NEXT; DEPTH];
+    words.push(0);
+    single_segment_message(&words)
+}
+
+/// A list that claims to hold hundreds of millions of voids while only taking up a single word.
+fn wide_message() -> Vec<u8> {
+    const LEN: u64 = (1 << 29) - 1;
+    // A list pointer with void elements.
+    const LIST: u64 = 1;
+
+    single_segment_message(&[LIST | LEN << 35])
+}
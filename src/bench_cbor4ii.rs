@@ -1,6 +1,8 @@
 use criterion::{black_box, Criterion};
 use serde::{Deserialize, Serialize};
 
+use crate::{Borrowable, Projectable};
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: Serialize + for<'de> Deserialize<'de> + PartialEq,
@@ -10,22 +12,22 @@ where
     let mut group = c.benchmark_group(format!("{}/cbor4ii", name));
 
     let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            serialize_buffer.clear();
-            cbor4ii::serde::to_writer(black_box(&mut serialize_buffer), black_box(&data)).unwrap();
-            black_box(());
-        })
-    });
+    let mut serialize = || {
+        serialize_buffer.clear();
+        cbor4ii::serde::to_writer(black_box(&mut serialize_buffer), black_box(&data)).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "cbor4ii", "serialize", serialize);
 
     let mut deserialize_buffer = Vec::new();
     cbor4ii::serde::to_writer(&mut deserialize_buffer, &data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(cbor4ii::serde::from_slice::<'_, T>(black_box(&deserialize_buffer)).unwrap());
-        })
-    });
+    let mut deserialize = || {
+        black_box(cbor4ii::serde::from_slice::<'_, T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "cbor4ii", "deserialize", deserialize);
 
     crate::bench_size(name, "cbor4ii", deserialize_buffer.as_slice());
 
@@ -33,3 +35,58 @@ where
 
     group.finish();
 }
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/cbor4ii", name));
+
+    let mut deserialize_buffer = Vec::new();
+    cbor4ii::serde::to_writer(&mut deserialize_buffer, &data).unwrap();
+
+    let mut deserialize = || {
+        black_box(
+            cbor4ii::serde::from_slice::<'_, T::Borrowed<'_>>(black_box(&deserialize_buffer))
+                .unwrap(),
+        );
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "cbor4ii", "deserialize (borrowed)", deserialize);
+
+    assert!(
+        cbor4ii::serde::from_slice::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+
+    group.finish();
+}
+
+pub fn bench_project<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Projectable,
+{
+    let mut group = c.benchmark_group(format!("{}/cbor4ii", name));
+
+    let mut deserialize_buffer = Vec::new();
+    cbor4ii::serde::to_writer(&mut deserialize_buffer, &data).unwrap();
+
+    let mut project = || {
+        black_box(
+            cbor4ii::serde::from_slice::<T::Projection>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("project", |b| b.iter(&mut project));
+    crate::bench_latency(name, "cbor4ii", "project", project);
+
+    assert!(
+        cbor4ii::serde::from_slice::<T::Projection>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == data.project()
+    );
+
+    group.finish();
+}
@@ -9,29 +9,25 @@ where
 
     let mut group = c.benchmark_group(format!("{}/ciborium", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            ciborium::ser::into_writer(
-                black_box(&data),
-                black_box(serialize_buffer.as_mut_slice()),
-            )
-            .unwrap();
-            black_box(());
-        })
-    });
-
     let mut deserialize_buffer = Vec::new();
     ciborium::ser::into_writer(&data, &mut deserialize_buffer).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(
-                ciborium::de::from_reader::<T, _>(black_box(deserialize_buffer.as_slice()))
-                    .unwrap(),
-            );
-        })
-    });
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        ciborium::ser::into_writer(black_box(&data), black_box(serialize_buffer.as_mut_slice()))
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "ciborium", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(
+            ciborium::de::from_reader::<T, _>(black_box(deserialize_buffer.as_slice())).unwrap(),
+        );
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "ciborium", "deserialize", deserialize);
 
     crate::bench_size(name, "ciborium", deserialize_buffer.as_slice());
 
@@ -10,20 +10,20 @@ where
     let mut group = c.benchmark_group(format!("{}/databuf", name));
 
     let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            serialize_buffer.clear();
-            data.encode::<LE>(&mut serialize_buffer).unwrap();
-        })
-    });
+    let mut serialize = || {
+        serialize_buffer.clear();
+        data.encode::<LE>(&mut serialize_buffer).unwrap();
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "databuf", "serialize", serialize);
 
     let deserialize_buffer = data.to_bytes::<LE>();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(T::from_bytes::<LE>(&deserialize_buffer).unwrap());
-        })
-    });
+    let mut deserialize = || {
+        black_box(T::from_bytes::<LE>(&deserialize_buffer).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "databuf", "deserialize", deserialize);
 
     crate::bench_size(name, "databuf", deserialize_buffer.as_slice());
 
@@ -9,33 +9,33 @@ where
 
     let mut group = c.benchmark_group(format!("{}/dlhn", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            black_box(&data)
-                .serialize(&mut dlhn::ser::Serializer::new(&mut black_box(
-                    serialize_buffer.as_mut_slice(),
-                )))
-                .unwrap();
-            black_box(());
-        })
-    });
-
     let mut deserialize_buffer = Vec::new();
     data.serialize(&mut dlhn::ser::Serializer::new(&mut deserialize_buffer))
         .unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(
-                <T>::deserialize(&mut dlhn::de::Deserializer::new(black_box(
-                    &mut deserialize_buffer.as_slice(),
-                )))
-                .unwrap(),
-            );
-        })
-    });
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+
+    let mut serialize = || {
+        black_box(&data)
+            .serialize(&mut dlhn::ser::Serializer::new(&mut black_box(
+                serialize_buffer.as_mut_slice(),
+            )))
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "dlhn", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(
+            <T>::deserialize(&mut dlhn::de::Deserializer::new(black_box(
+                &mut deserialize_buffer.as_slice(),
+            )))
+            .unwrap(),
+        );
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "dlhn", "deserialize", deserialize);
 
     crate::bench_size(name, "dlhn", deserialize_buffer.as_slice());
 
@@ -0,0 +1,48 @@
+use criterion::{black_box, Criterion};
+use ssz::{Decode, Encode};
+
+pub trait Serialize: Sized {
+    type Message: Encode + Decode + Into<Self>;
+
+    fn serialize_ssz(&self) -> Self::Message;
+}
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + PartialEq,
+{
+    const BUFFER_LEN: usize = 10_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/ethereum_ssz", name));
+
+    let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
+
+    let mut serialize = || {
+        black_box(&mut serialize_buffer).clear();
+        black_box(data)
+            .serialize_ssz()
+            .ssz_append(&mut serialize_buffer);
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "ethereum_ssz", "serialize", serialize);
+
+    let deserialize_buffer = data.serialize_ssz().as_ssz_bytes();
+
+    let mut deserialize = || {
+        black_box(T::Message::from_ssz_bytes(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "ethereum_ssz", "deserialize", deserialize);
+
+    crate::bench_size(name, "ethereum_ssz", deserialize_buffer.as_slice());
+
+    assert!(
+        T::Message::from_ssz_bytes(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+
+    group.finish();
+}
@@ -1,5 +1,10 @@
+use core::{mem, slice};
+
 use criterion::{black_box, Criterion};
-use flatbuffers::{FlatBufferBuilder, Follow, Verifiable, WIPOffset};
+use flatbuffers::{
+    EndianScalar, FlatBufferBuilder, Follow, ForwardsUOffset, InvalidFlatbuffer, VOffsetT, Vector,
+    Verifiable, Verifier, VerifierOptions, WIPOffset,
+};
 
 pub trait Serialize<'a> {
     type Target: 'a + Follow<'a> + Verifiable;
@@ -14,7 +19,8 @@ pub fn bench<T>(
     c: &mut Criterion,
     data: &T,
     read_unverified: impl Fn(&[u8]),
-    read_verified: impl Fn(&[u8]),
+    read_verified: impl Fn(&[u8], &VerifierOptions),
+    update: impl Fn(&mut [u8]),
 ) where
     T: for<'a> Serialize<'a>,
 {
@@ -23,19 +29,20 @@ pub fn bench<T>(
     let mut group = c.benchmark_group(format!("{}/flatbuffers", name));
 
     let mut fbb = FlatBufferBuilder::with_capacity(BUFFER_LEN);
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            black_box(&mut fbb).reset();
-            let root = data.serialize_fb(&mut fbb);
-            fbb.finish(root, None);
-            black_box(&mut fbb);
-        })
-    });
+    let mut serialize = || {
+        black_box(&mut fbb).reset();
+        let root = data.serialize_fb(&mut fbb);
+        fbb.finish(root, None);
+        black_box(&mut fbb);
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "flatbuffers", "serialize", serialize);
 
     fbb.reset();
     let root = data.serialize_fb(&mut fbb);
     fbb.finish(root, None);
     let deserialize_buffer = fbb.finished_data();
+    let options = verifier_options(deserialize_buffer);
 
     group.bench_function("access (unvalidated)", |b| {
         b.iter(|| unsafe {
@@ -48,8 +55,24 @@ pub fn bench<T>(
     group.bench_function("access (validated upfront with error)", |b| {
         b.iter(|| {
             black_box(
-                flatbuffers::root::<<T as Serialize<'_>>::Target>(black_box(deserialize_buffer))
-                    .unwrap(),
+                flatbuffers::root_with_opts::<<T as Serialize<'_>>::Target>(
+                    &options,
+                    black_box(deserialize_buffer),
+                )
+                .unwrap(),
+            )
+        })
+    });
+
+    let tight_options = tight_options::<<T as Serialize<'_>>::Target>(&options, deserialize_buffer);
+    group.bench_function("access (validated upfront with tight limits)", |b| {
+        b.iter(|| {
+            black_box(
+                flatbuffers::root_with_opts::<<T as Serialize<'_>>::Target>(
+                    &tight_options,
+                    black_box(deserialize_buffer),
+                )
+                .unwrap(),
             )
         })
     });
@@ -63,12 +86,356 @@ pub fn bench<T>(
 
     group.bench_function("read (validated upfront with error)", |b| {
         b.iter(|| {
-            read_verified(black_box(deserialize_buffer));
+            read_verified(black_box(deserialize_buffer), &options);
+            black_box(())
+        })
+    });
+
+    // Fields left at their default value aren't written, so they'd have nowhere to be updated in
+    // place. The buffer that's updated writes every field instead.
+    let mut update_fbb = FlatBufferBuilder::with_capacity(deserialize_buffer.len());
+    update_fbb.force_defaults(true);
+    let root = data.serialize_fb(&mut update_fbb);
+    update_fbb.finish(root, None);
+    let mut update_buffer = update_fbb.finished_data().to_vec();
+    group.bench_function("update (unvalidated)", |b| {
+        b.iter(|| {
+            update(black_box(update_buffer.as_mut_slice()));
+            black_box(())
+        })
+    });
+
+    group.bench_function("update (validated upfront with error)", |b| {
+        b.iter(|| {
+            flatbuffers::root_with_opts::<<T as Serialize<'_>>::Target>(
+                &options,
+                black_box(&update_buffer),
+            )
+            .unwrap();
+            update(black_box(update_buffer.as_mut_slice()));
             black_box(())
         })
     });
 
+    flatbuffers::root_with_opts::<<T as Serialize<'_>>::Target>(&options, &update_buffer).unwrap();
+
     crate::bench_size(name, "flatbuffers", deserialize_buffer);
 
     group.finish();
 }
+
+/// Benchmarks reading the elements at `indices` out of a large buffer, which only touches the parts
+/// of the buffer that hold them.
+pub fn bench_read_random<T>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &T,
+    indices: &[usize],
+    read_unverified: impl Fn(&[u8], &[usize]),
+    read_verified: impl Fn(&[u8], &[usize], &VerifierOptions),
+) where
+    T: for<'a> Serialize<'a>,
+{
+    let mut group = c.benchmark_group(format!("{}/flatbuffers", name));
+
+    let mut fbb = FlatBufferBuilder::new();
+    let root = data.serialize_fb(&mut fbb);
+    fbb.finish(root, None);
+    let buffer = fbb.finished_data();
+    let options = verifier_options(buffer);
+
+    group.bench_function("read_random (unvalidated)", |b| {
+        b.iter(|| {
+            read_unverified(black_box(buffer), black_box(indices));
+            black_box(())
+        })
+    });
+
+    group.bench_function("read_random (validated upfront with error)", |b| {
+        b.iter(|| {
+            read_verified(black_box(buffer), black_box(indices), &options);
+            black_box(())
+        })
+    });
+
+    group.finish();
+}
+
+/// Benchmarks reading only the fields that the projections look at out of a buffer.
+pub fn bench_project<T>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &T,
+    project_unverified: impl Fn(&[u8]),
+    project_verified: impl Fn(&[u8], &VerifierOptions),
+) where
+    T: for<'a> Serialize<'a>,
+{
+    let mut group = c.benchmark_group(format!("{}/flatbuffers", name));
+
+    let mut fbb = FlatBufferBuilder::new();
+    let root = data.serialize_fb(&mut fbb);
+    fbb.finish(root, None);
+    let buffer = fbb.finished_data();
+    let options = verifier_options(buffer);
+
+    group.bench_function("project (unvalidated)", |b| {
+        b.iter(|| {
+            project_unverified(black_box(buffer));
+            black_box(())
+        })
+    });
+
+    group.bench_function("project (validated upfront with error)", |b| {
+        b.iter(|| {
+            project_verified(black_box(buffer), &options);
+            black_box(())
+        })
+    });
+
+    group.finish();
+}
+
+/// A table in a finished buffer, opened for setting its fields in place.
+///
+/// flatc only generates setters for the fields of structs in Rust, so this stands in for the
+/// `mutate_*` methods it generates in C++. Like those, it can't set scalars that were left out of
+/// the buffer for having their default value. Offsets aren't verified, but are bounds checked.
+pub struct TableMut<'a> {
+    buf: &'a mut [u8],
+    loc: usize,
+}
+
+impl<'a> TableMut<'a> {
+    /// Opens the root table of `buf`.
+    pub fn root(buf: &'a mut [u8]) -> Self {
+        let loc = read_scalar::<u32>(buf, 0) as usize;
+        Self { buf, loc }
+    }
+
+    fn field_loc(&self, slot: VOffsetT) -> Option<usize> {
+        let vtable = self
+            .loc
+            .wrapping_add_signed(-(read_scalar::<i32>(self.buf, self.loc) as isize));
+        if slot >= read_scalar::<VOffsetT>(self.buf, vtable) {
+            return None;
+        }
+        match read_scalar::<VOffsetT>(self.buf, vtable + slot as usize) {
+            0 => None,
+            offset => Some(self.loc + offset as usize),
+        }
+    }
+
+    /// Gets the scalar field in `slot`, or `None` if it was left out for having its default value.
+    pub fn get<T: EndianScalar>(&self, slot: VOffsetT) -> Option<T> {
+        self.field_loc(slot).map(|loc| read_scalar(self.buf, loc))
+    }
+
+    /// Sets the scalar field in `slot`, returning whether it was present in the buffer to be set.
+    pub fn mutate<T: EndianScalar>(&mut self, slot: VOffsetT, value: T) -> bool {
+        let Some(loc) = self.field_loc(slot) else {
+            return false;
+        };
+        let bytes = &mut self.buf[loc..loc + mem::size_of::<T::Scalar>()];
+        // SAFETY: `bytes` is exactly the size of the scalar.
+        unsafe { flatbuffers::emplace_scalar(bytes, value) };
+        true
+    }
+
+    /// Gets the struct field in `slot` to set its fields in place.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be a struct generated by flatc, which has no alignment or invalid bit patterns.
+    pub unsafe fn struct_mut<T>(&mut self, slot: VOffsetT) -> Option<&mut T> {
+        let loc = self.field_loc(slot)?;
+        let bytes = &mut self.buf[loc..loc + mem::size_of::<T>()];
+        Some(unsafe { &mut *bytes.as_mut_ptr().cast::<T>() })
+    }
+
+    /// Gets the table field in `slot`, which may also be the value of a union.
+    pub fn table_mut(&mut self, slot: VOffsetT) -> Option<TableMut<'_>> {
+        let loc = self.field_loc(slot)?;
+        Some(TableMut {
+            loc: loc + read_scalar::<u32>(self.buf, loc) as usize,
+            buf: self.buf,
+        })
+    }
+
+    /// Gets the vector field in `slot`.
+    pub fn vector_mut(&mut self, slot: VOffsetT) -> Option<VectorMut<'_>> {
+        let loc = self.field_loc(slot)?;
+        Some(VectorMut {
+            loc: loc + read_scalar::<u32>(self.buf, loc) as usize,
+            buf: self.buf,
+        })
+    }
+}
+
+/// A vector in a finished buffer, opened for setting its elements in place.
+pub struct VectorMut<'a> {
+    buf: &'a mut [u8],
+    loc: usize,
+}
+
+impl VectorMut<'_> {
+    pub fn len(&self) -> usize {
+        read_scalar::<u32>(self.buf, self.loc) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the table at `index` in a vector of tables.
+    pub fn table_mut(&mut self, index: usize) -> TableMut<'_> {
+        assert!(index < self.len());
+        let loc = self.loc + 4 + index * 4;
+        TableMut {
+            loc: loc + read_scalar::<u32>(self.buf, loc) as usize,
+            buf: self.buf,
+        }
+    }
+
+    /// Gets the elements of a vector of structs.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be a struct generated by flatc, which has no alignment or invalid bit patterns.
+    pub unsafe fn structs_mut<T>(&mut self) -> &mut [T] {
+        let len = self.len();
+        let bytes = &mut self.buf[self.loc + 4..self.loc + 4 + len * mem::size_of::<T>()];
+        unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr().cast::<T>(), len) }
+    }
+}
+
+fn read_scalar<T: EndianScalar>(buf: &[u8], loc: usize) -> T {
+    let bytes = &buf[loc..loc + mem::size_of::<T::Scalar>()];
+    // SAFETY: `bytes` is exactly the size of the scalar.
+    unsafe { flatbuffers::read_scalar(bytes) }
+}
+
+/// Records whether the verifier rejects the adversarial deep and wide messages with the default
+/// options and with its limits lifted. Neither depends on the data set, so this only runs once.
+pub fn bench_adversarial() {
+    let unbounded_options = VerifierOptions {
+        max_depth: usize::MAX,
+        max_tables: usize::MAX,
+        max_apparent_size: usize::MAX,
+        ..Default::default()
+    };
+    for (limits, options) in [
+        ("default limits", VerifierOptions::default()),
+        ("unbounded limits", unbounded_options),
+    ] {
+        let deep = verify::<Node>(&options, &deep_message());
+        crate::bench_adversarial("flatbuffers", "deep message", limits, deep);
+        let wide = verify::<Node>(&options, &wide_message());
+        crate::bench_adversarial("flatbuffers", "wide message", limits, wide);
+    }
+}
+
+fn verify<T: Verifiable>(options: &VerifierOptions, bytes: &[u8]) -> Result<(), InvalidFlatbuffer> {
+    let mut verifier = Verifier::new(options, bytes);
+    <ForwardsUOffset<T>>::run_verifier(&mut verifier, 0)
+}
+
+/// The default verifier options, with the table limit raised to the most tables that `bytes` has
+/// room for when that's larger, so that data sets scaled past the default can still be verified.
+fn verifier_options(bytes: &[u8]) -> VerifierOptions {
+    let default = VerifierOptions::default();
+    VerifierOptions {
+        // Every table starts with a 4-byte offset to its vtable
+        max_tables: default.max_tables.max(bytes.len() / 4),
+        ..default
+    }
+}
+
+/// Finds the smallest verifier limits below `options` that still accept the buffer in `bytes`.
+fn tight_options<T: Verifiable>(options: &VerifierOptions, bytes: &[u8]) -> VerifierOptions {
+    let accepts = |options: VerifierOptions| verify::<T>(&options, bytes).is_ok();
+
+    let tight = VerifierOptions {
+        max_depth: crate::smallest_limit(options.max_depth, |max_depth| {
+            accepts(VerifierOptions {
+                max_depth,
+                ..options.clone()
+            })
+        }),
+        max_tables: crate::smallest_limit(options.max_tables, |max_tables| {
+            accepts(VerifierOptions {
+                max_tables,
+                ..options.clone()
+            })
+        }),
+        max_apparent_size: crate::smallest_limit(options.max_apparent_size, |max_apparent_size| {
+            accepts(VerifierOptions {
+                max_apparent_size,
+                ..options.clone()
+            })
+        }),
+        ..options.clone()
+    };
+    assert!(accepts(tight.clone()));
+    tight
+}
+
+/// A table whose only field is a list of child nodes.
+///
+/// The tree data set nests tables too, but through unions that make its messages awkward to build
+/// by hand, so the adversarial messages are verified against this schema instead:
+///
+/// ```fbs
+/// table Node {
+///   children: [Node];
+/// }
+/// ```
+struct Node;
+
+impl Verifiable for Node {
+    fn run_verifier(v: &mut Verifier, pos: usize) -> Result<(), InvalidFlatbuffer> {
+        v.visit_table(pos)?
+            .visit_field::<ForwardsUOffset<Vector<'_, ForwardsUOffset<Node>>>>(
+                "children", 4, false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+
+fn create_node<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    children: Option<WIPOffset<Vector<'a, ForwardsUOffset<Node>>>>,
+) -> WIPOffset<Node> {
+    let start = fbb.start_table();
+    if let Some(children) = children {
+        fbb.push_slot_always(4, children);
+    }
+    WIPOffset::new(fbb.end_table(start).value())
+}
+
+/// A chain of nodes that each hold a single child, nested far deeper than any of the data sets.
+fn deep_message() -> Vec<u8> {
+    const DEPTH: usize = 1_000;
+
+    let mut fbb = FlatBufferBuilder::new();
+    let mut node = create_node(&mut fbb, None);
+    for _ in 1..DEPTH {
+        let children = fbb.create_vector(&[node]);
+        node = create_node(&mut fbb, Some(children));
+    }
+    fbb.finish(node, None);
+    fbb.finished_data().to_vec()
+}
+
+/// A node with millions of children that all point at the same empty table.
+fn wide_message() -> Vec<u8> {
+    const LEN: usize = 1 << 21;
+
+    let mut fbb = FlatBufferBuilder::new();
+    let leaf = create_node(&mut fbb, None);
+    let children = fbb.create_vector(&vec![leaf; LEN]);
+    let root = create_node(&mut fbb, Some(children));
+    fbb.finish(root, None);
+    fbb.finished_data().to_vec()
+}
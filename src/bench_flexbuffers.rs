@@ -0,0 +1,39 @@
+use criterion::{black_box, Criterion};
+use flexbuffers::FlexbufferSerializer;
+use serde::{Deserialize, Serialize};
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq,
+{
+    let Some(deserialize_buffer) = crate::encode_or_skip(
+        name,
+        "flexbuffers",
+        data,
+        |d| flexbuffers::to_vec(d),
+        |b| flexbuffers::from_slice(b),
+    ) else {
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("{}/flexbuffers", name));
+
+    let mut serializer = FlexbufferSerializer::new();
+    let mut serialize = || {
+        black_box(&mut serializer).reset();
+        black_box(data).serialize(&mut serializer).unwrap();
+        black_box(serializer.view());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "flexbuffers", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(flexbuffers::from_slice::<T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "flexbuffers", "deserialize", deserialize);
+
+    crate::bench_size(name, "flexbuffers", deserialize_buffer.as_slice());
+
+    group.finish();
+}
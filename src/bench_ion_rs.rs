@@ -0,0 +1,43 @@
+use criterion::{black_box, Criterion};
+use serde::{de::DeserializeOwned, Serialize};
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/ion-rs", name));
+
+    let mut serialize = || {
+        black_box(ion_rs::serde::to_binary(black_box(data)).unwrap());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "ion-rs", "serialize", serialize);
+
+    let mut serialize_text = || {
+        black_box(ion_rs::serde::to_string(black_box(data)).unwrap());
+    };
+    group.bench_function("serialize (text)", |b| b.iter(&mut serialize_text));
+    crate::bench_latency(name, "ion-rs", "serialize (text)", serialize_text);
+
+    let binary = ion_rs::serde::to_binary(data).unwrap();
+    let text = ion_rs::serde::to_string(data).unwrap();
+
+    let mut deserialize = || {
+        black_box(ion_rs::serde::from_ion::<T, _>(black_box(binary.as_slice())).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "ion-rs", "deserialize", deserialize);
+
+    let mut deserialize_text = || {
+        black_box(ion_rs::serde::from_ion::<T, _>(black_box(text.as_str())).unwrap());
+    };
+    group.bench_function("deserialize (text)", |b| b.iter(&mut deserialize_text));
+    crate::bench_latency(name, "ion-rs", "deserialize (text)", deserialize_text);
+
+    crate::bench_size(name, "ion-rs", binary.as_slice());
+
+    assert!(ion_rs::serde::from_ion::<T, _>(binary.as_slice()).unwrap() == *data);
+    assert!(ion_rs::serde::from_ion::<T, _>(text.as_str()).unwrap() == *data);
+
+    group.finish();
+}
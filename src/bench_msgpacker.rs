@@ -18,15 +18,22 @@ where
             BatchSize::SmallInput,
         );
     });
+    crate::bench_latency_batched(
+        name,
+        "msgpacker",
+        "serialize",
+        || Vec::with_capacity(BUFFER_LEN),
+        |buf| T::pack(black_box(data), buf),
+    );
 
     let mut deserialize_buffer = Vec::new();
     T::pack(data, &mut deserialize_buffer);
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            T::unpack(black_box(&deserialize_buffer)).unwrap();
-        })
-    });
+    let mut deserialize = || {
+        T::unpack(black_box(&deserialize_buffer)).unwrap();
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "msgpacker", "deserialize", deserialize);
 
     crate::bench_size(name, "msgpacker", &deserialize_buffer);
 
@@ -0,0 +1,45 @@
+use criterion::{black_box, Criterion};
+use musli::{alloc::Global, mode::Binary, Decode, Encode};
+
+macro_rules! bench_encoding {
+    ($bench:ident, $encoding:ident, $lib:literal) => {
+        pub fn $bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+        where
+            T: Encode<Binary> + for<'de> Decode<'de, Binary, Global> + PartialEq,
+        {
+            const BUFFER_LEN: usize = 10_000_000;
+
+            let mut group = c.benchmark_group(format!("{}/{}", name, $lib));
+
+            let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
+
+            let mut serialize = || {
+                black_box(&mut serialize_buffer).clear();
+                musli::$encoding::encode(&mut serialize_buffer, black_box(data)).unwrap();
+                black_box(());
+            };
+            group.bench_function("serialize", |b| b.iter(&mut serialize));
+            crate::bench_latency(name, $lib, "serialize", serialize);
+
+            let deserialize_buffer = musli::$encoding::to_vec(data).unwrap();
+
+            let mut deserialize = || {
+                black_box(
+                    musli::$encoding::from_slice::<T>(black_box(&deserialize_buffer)).unwrap(),
+                );
+            };
+            group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+            crate::bench_latency(name, $lib, "deserialize", deserialize);
+
+            crate::bench_size(name, $lib, deserialize_buffer.as_slice());
+
+            assert!(musli::$encoding::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
+
+            group.finish();
+        }
+    };
+}
+
+bench_encoding!(bench_storage, storage, "musli-storage");
+bench_encoding!(bench_wire, wire, "musli-wire");
+bench_encoding!(bench_descriptive, descriptive, "musli-descriptive");
@@ -0,0 +1,99 @@
+use criterion::{black_box, Criterion};
+use musli_zerocopy::{Buf, Error, OwnedBuf, Ref, ZeroCopy};
+
+pub trait Serialize: Sized {
+    type Target: ZeroCopy;
+
+    /// Stores everything the target refers to in `buf` and returns the target itself.
+    fn serialize_musli_zerocopy(&self, buf: &mut OwnedBuf) -> Result<Self::Target, Error>;
+
+    /// Rebuilds the original value from `target` and everything it refers to in `buf`.
+    fn deserialize_musli_zerocopy(buf: &Buf, target: &Self::Target) -> Result<Self, Error>;
+}
+
+/// Serializes `data` into `buf` with its root at offset zero, where `read` and `update` load it.
+fn serialize<T: Serialize>(data: &T, buf: &mut OwnedBuf) -> Result<Ref<T::Target>, Error> {
+    let root = buf.store_uninit::<T::Target>()?;
+    let value = data.serialize_musli_zerocopy(buf)?;
+    buf.load_uninit_mut(root)?.write(&value);
+    Ok(root.assume_init())
+}
+
+pub fn bench<T, R, U>(name: &'static str, c: &mut Criterion, data: &T, read: R, update: U)
+where
+    T: Serialize + PartialEq,
+    R: Fn(&Buf),
+    U: Fn(&mut Buf),
+{
+    const BUFFER_LEN: usize = 10_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/musli-zerocopy", name));
+
+    let mut buffer = OwnedBuf::with_capacity(BUFFER_LEN).unwrap();
+
+    let mut serialize_data = || {
+        black_box(&mut buffer).clear();
+        black_box(serialize(black_box(data), &mut buffer).unwrap());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize_data));
+    crate::bench_latency(name, "musli-zerocopy", "serialize", serialize_data);
+
+    buffer.clear();
+    let root = serialize(data, &mut buffer).unwrap();
+
+    group.bench_function("access (validated on-demand with error)", |b| {
+        b.iter(|| black_box(black_box(&*buffer).load(root).unwrap()))
+    });
+
+    group.bench_function("read (validated on-demand with error)", |b| {
+        b.iter(|| {
+            read(black_box(&*buffer));
+            black_box(());
+        })
+    });
+
+    let mut update_buffer = buffer.clone();
+    // SAFETY: The update buffer is only accessed through `Buf` from here on, so any padding bytes
+    // written by `update` are never read.
+    let update_buf = unsafe { Buf::new_mut(update_buffer.as_mut_slice()) };
+    group.bench_function("update (validated on-demand with error)", |b| {
+        b.iter(|| {
+            update(black_box(&mut *update_buf));
+            black_box(());
+        })
+    });
+
+    crate::bench_size(name, "musli-zerocopy", buffer.as_slice());
+
+    let target = buffer.load(root).unwrap();
+    assert!(T::deserialize_musli_zerocopy(&buffer, target).unwrap() == *data);
+
+    group.finish();
+}
+
+/// Benchmarks reading the elements at `indices` out of a large buffer, which only touches the parts
+/// of the buffer that hold them.
+pub fn bench_read_random<T, R>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &T,
+    indices: &[usize],
+    read: R,
+) where
+    T: Serialize,
+    R: Fn(&Buf, &[usize]),
+{
+    let mut group = c.benchmark_group(format!("{}/musli-zerocopy", name));
+
+    let mut buffer = OwnedBuf::new();
+    serialize(data, &mut buffer).unwrap();
+
+    group.bench_function("read_random (validated on-demand with error)", |b| {
+        b.iter(|| {
+            read(black_box(&*buffer), black_box(indices));
+            black_box(());
+        })
+    });
+
+    group.finish();
+}
@@ -7,20 +7,20 @@ where
 {
     let mut group = c.benchmark_group(format!("{}/nanoserde", name));
 
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            SerBin::serialize_bin(black_box(data));
-            black_box(());
-        })
-    });
+    let mut serialize = || {
+        SerBin::serialize_bin(black_box(data));
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "nanoserde", "serialize", serialize);
 
     let deserialize_buffer = SerBin::serialize_bin(data);
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(<T as DeBin>::deserialize_bin(black_box(&deserialize_buffer)).unwrap());
-        })
-    });
+    let mut deserialize = || {
+        black_box(<T as DeBin>::deserialize_bin(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "nanoserde", "deserialize", deserialize);
 
     crate::bench_size(name, "nanoserde", deserialize_buffer.as_slice());
 
@@ -9,21 +9,21 @@ where
 
     let mut group = c.benchmark_group(format!("{}/parity-scale-codec", name));
 
-    let mut serialize_buffer = vec![0u8; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            data.encode_to(black_box(&mut serialize_buffer.as_mut_slice()));
-            black_box(());
-        })
-    });
-
     let deserialize_buffer = data.encode();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(T::decode(&mut deserialize_buffer.as_slice()).unwrap());
-        })
-    });
+    let mut serialize_buffer = vec![0u8; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        data.encode_to(black_box(&mut serialize_buffer.as_mut_slice()));
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "parity-scale-codec", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(T::decode(&mut deserialize_buffer.as_slice()).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "parity-scale-codec", "deserialize", deserialize);
 
     crate::bench_size(name, "parity-scale-codec", deserialize_buffer.as_slice());
 
@@ -1,6 +1,8 @@
 use criterion::{black_box, Criterion};
 use serde::{Deserialize, Serialize};
 
+use crate::{Borrowable, ReadRandom};
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: Serialize + for<'de> Deserialize<'de> + PartialEq,
@@ -9,27 +11,90 @@ where
 
     let mut group = c.benchmark_group(format!("{}/postcard", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            black_box(
-                postcard::to_slice(black_box(&data), black_box(serialize_buffer.as_mut_slice()))
-                    .unwrap(),
-            );
-        })
-    });
-
     let deserialize_buffer = postcard::to_allocvec(&data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(postcard::from_bytes::<'_, T>(black_box(&deserialize_buffer)).unwrap());
-        })
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        black_box(
+            postcard::to_slice(black_box(&data), black_box(serialize_buffer.as_mut_slice()))
+                .unwrap(),
+        );
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "postcard", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(postcard::from_bytes::<'_, T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "postcard", "deserialize", deserialize);
+
+    let mut value = postcard::from_bytes::<T>(&deserialize_buffer).unwrap();
+    let mut deserialize_in_place = || {
+        let mut deserializer = postcard::Deserializer::from_bytes(black_box(&deserialize_buffer));
+        T::deserialize_in_place(&mut deserializer, black_box(&mut value)).unwrap();
+    };
+    group.bench_function("deserialize (in place)", |b| {
+        b.iter(&mut deserialize_in_place)
     });
+    crate::bench_latency(
+        name,
+        "postcard",
+        "deserialize (in place)",
+        deserialize_in_place,
+    );
 
     crate::bench_size(name, "postcard", deserialize_buffer.as_slice());
 
     assert!(postcard::from_bytes::<T>(&deserialize_buffer).unwrap() == *data);
+    assert!(value == *data);
+
+    group.finish();
+}
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/postcard", name));
+
+    let deserialize_buffer = postcard::to_allocvec(&data).unwrap();
+
+    let mut deserialize = || {
+        black_box(
+            postcard::from_bytes::<'_, T::Borrowed<'_>>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "postcard", "deserialize (borrowed)", deserialize);
+
+    assert!(
+        postcard::from_bytes::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+
+    group.finish();
+}
+
+/// Benchmarks deserializing the whole buffer and then reading the elements at `indices`, which is
+/// what the zero-copy `read_random` benchmarks avoid.
+pub fn bench_read_random<T>(name: &'static str, c: &mut Criterion, data: &T, indices: &[usize])
+where
+    T: Serialize + for<'de> Deserialize<'de> + ReadRandom,
+{
+    let mut group = c.benchmark_group(format!("{}/postcard", name));
+
+    let deserialize_buffer = postcard::to_allocvec(&data).unwrap();
+
+    group.bench_function("read_random", |b| {
+        b.iter(|| {
+            postcard::from_bytes::<T>(black_box(&deserialize_buffer))
+                .unwrap()
+                .read_random(black_box(indices));
+        })
+    });
 
     group.finish();
 }
@@ -1,6 +1,8 @@
 use criterion::{black_box, Criterion};
 use serde::{Deserialize, Serialize};
 
+use crate::Borrowable;
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: Serialize + for<'de> Deserialize<'de> + PartialEq,
@@ -9,20 +11,20 @@ where
 
     let mut group = c.benchmark_group(format!("{}/pot", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            pot::to_writer(black_box(&data), black_box(serialize_buffer.as_mut_slice())).unwrap();
-        })
-    });
-
     let deserialize_buffer = pot::to_vec(&data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(pot::from_slice::<T>(black_box(&deserialize_buffer)).unwrap());
-        })
-    });
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        pot::to_writer(black_box(&data), black_box(serialize_buffer.as_mut_slice())).unwrap();
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "pot", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(pot::from_slice::<T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "pot", "deserialize", deserialize);
 
     crate::bench_size(name, "pot", deserialize_buffer.as_slice());
 
@@ -30,3 +32,27 @@ where
 
     group.finish();
 }
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/pot", name));
+
+    let deserialize_buffer = pot::to_vec(&data).unwrap();
+
+    let mut deserialize = || {
+        black_box(pot::from_slice::<T::Borrowed<'_>>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "pot", "deserialize (borrowed)", deserialize);
+
+    assert!(
+        pot::from_slice::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+
+    group.finish();
+}
@@ -1,12 +1,19 @@
 use criterion::{black_box, Criterion};
 use prost::Message;
 
+use crate::Projectable;
+
 pub trait Serialize: Sized {
     type Message: Default + Into<Self> + Message;
 
     fn serialize_pb(&self) -> Self::Message;
 }
 
+/// A dataset type with a reduced message that only has the fields its projection keeps.
+pub trait Project: Serialize + Projectable {
+    type Projection: Default + Into<Self::Fields> + Message;
+}
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: Serialize + PartialEq,
@@ -17,35 +24,81 @@ where
 
     let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
 
+    let mut populate_encode = || {
+        black_box(&mut serialize_buffer).clear();
+        data.serialize_pb().encode(&mut serialize_buffer).unwrap();
+        black_box(());
+    };
     group.bench_function("serialize (populate + encode)", |b| {
-        b.iter(|| {
-            black_box(&mut serialize_buffer).clear();
-            data.serialize_pb().encode(&mut serialize_buffer).unwrap();
-            black_box(());
-        })
+        b.iter(&mut populate_encode)
     });
+    crate::bench_latency(
+        name,
+        "prost",
+        "serialize (populate + encode)",
+        populate_encode,
+    );
 
     let message = data.serialize_pb();
-    group.bench_function("serialize (encode)", |b| {
-        b.iter(|| {
-            black_box(&mut serialize_buffer).clear();
-            message.encode(&mut serialize_buffer).unwrap();
-            black_box(());
-        })
-    });
+    let mut encode = || {
+        black_box(&mut serialize_buffer).clear();
+        message.encode(&mut serialize_buffer).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize (encode)", |b| b.iter(&mut encode));
+    crate::bench_latency(name, "prost", "serialize (encode)", encode);
 
     let mut deserialize_buffer = Vec::new();
     data.serialize_pb().encode(&mut deserialize_buffer).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(<T::Message>::decode(black_box(&deserialize_buffer).as_slice()).unwrap());
-        })
-    });
+    let mut deserialize = || {
+        black_box(<T::Message>::decode(black_box(&deserialize_buffer).as_slice()).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "prost", "deserialize", deserialize);
+
+    let mut merged = <T::Message>::decode(deserialize_buffer.as_slice()).unwrap();
+    let mut merge = || {
+        merged.clear();
+        merged
+            .merge(black_box(&deserialize_buffer).as_slice())
+            .unwrap();
+        black_box(&merged);
+    };
+    group.bench_function("deserialize (merge)", |b| b.iter(&mut merge));
+    crate::bench_latency(name, "prost", "deserialize (merge)", merge);
 
     crate::bench_size(name, "prost", deserialize_buffer.as_slice());
 
     assert!(<T::Message>::decode(&*deserialize_buffer).unwrap().into() == *data);
+    assert!(merged.into() == *data);
+
+    group.finish();
+}
+
+pub fn bench_project<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Project,
+{
+    let mut group = c.benchmark_group(format!("{}/prost", name));
+
+    let mut deserialize_buffer = Vec::new();
+    data.serialize_pb().encode(&mut deserialize_buffer).unwrap();
+
+    let mut project = || {
+        black_box(
+            <T as Project>::Projection::decode(black_box(&deserialize_buffer).as_slice()).unwrap(),
+        );
+    };
+    group.bench_function("project", |b| b.iter(&mut project));
+    crate::bench_latency(name, "prost", "project", project);
+
+    assert!(
+        <T as Project>::Projection::decode(&*deserialize_buffer)
+            .unwrap()
+            .into()
+            == data.project()
+    );
 
     group.finish();
 }
@@ -0,0 +1,76 @@
+use criterion::{black_box, Criterion};
+use protobuf::Message;
+
+pub trait Serialize: Sized {
+    type Message: Into<Self> + Message;
+
+    fn serialize_protobuf(&self) -> Self::Message;
+}
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + PartialEq,
+{
+    const BUFFER_LEN: usize = 10_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/protobuf", name));
+
+    let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
+
+    let mut populate_encode = || {
+        black_box(&mut serialize_buffer).clear();
+        data.serialize_protobuf()
+            .write_to_vec(&mut serialize_buffer)
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize (populate + encode)", |b| {
+        b.iter(&mut populate_encode)
+    });
+    crate::bench_latency(
+        name,
+        "protobuf",
+        "serialize (populate + encode)",
+        populate_encode,
+    );
+
+    let message = data.serialize_protobuf();
+    let mut encode = || {
+        black_box(&mut serialize_buffer).clear();
+        message.write_to_vec(&mut serialize_buffer).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize (encode)", |b| b.iter(&mut encode));
+    crate::bench_latency(name, "protobuf", "serialize (encode)", encode);
+
+    let deserialize_buffer = data.serialize_protobuf().write_to_bytes().unwrap();
+
+    let mut deserialize = || {
+        black_box(<T::Message>::parse_from_bytes(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "protobuf", "deserialize", deserialize);
+
+    let mut merged = <T::Message>::parse_from_bytes(&deserialize_buffer).unwrap();
+    let mut merge = || {
+        merged.clear();
+        merged
+            .merge_from_bytes(black_box(&deserialize_buffer))
+            .unwrap();
+        black_box(&merged);
+    };
+    group.bench_function("deserialize (merge)", |b| b.iter(&mut merge));
+    crate::bench_latency(name, "protobuf", "deserialize (merge)", merge);
+
+    crate::bench_size(name, "protobuf", deserialize_buffer.as_slice());
+
+    assert!(
+        <T::Message>::parse_from_bytes(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+    assert!(merged.into() == *data);
+
+    group.finish();
+}
@@ -0,0 +1,70 @@
+use criterion::{black_box, Criterion};
+use quick_protobuf::{BytesReader, MessageRead, MessageWrite, Writer};
+
+pub trait Serialize: Sized {
+    /// The generated message type. Its strings and bytes are `Cow`s, which borrow from the data
+    /// when serializing and from the buffer when deserializing.
+    type Message<'a>: MessageRead<'a> + MessageWrite + Into<Self>
+    where
+        Self: 'a;
+
+    fn serialize_qpb(&self) -> Self::Message<'_>;
+}
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + PartialEq,
+{
+    const BUFFER_LEN: usize = 10_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/quick-protobuf", name));
+
+    let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
+
+    let mut populate_encode = || {
+        black_box(&mut serialize_buffer).clear();
+        let mut writer = Writer::new(&mut serialize_buffer);
+        data.serialize_qpb().write_message(&mut writer).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize (populate + encode)", |b| {
+        b.iter(&mut populate_encode)
+    });
+    crate::bench_latency(
+        name,
+        "quick-protobuf",
+        "serialize (populate + encode)",
+        populate_encode,
+    );
+
+    let message = data.serialize_qpb();
+    let mut encode = || {
+        black_box(&mut serialize_buffer).clear();
+        let mut writer = Writer::new(&mut serialize_buffer);
+        message.write_message(&mut writer).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize (encode)", |b| b.iter(&mut encode));
+    crate::bench_latency(name, "quick-protobuf", "serialize (encode)", encode);
+
+    let mut deserialize_buffer = Vec::new();
+    message
+        .write_message(&mut Writer::new(&mut deserialize_buffer))
+        .unwrap();
+
+    let mut deserialize = || {
+        let bytes = black_box(deserialize_buffer.as_slice());
+        let mut reader = BytesReader::from_bytes(bytes);
+        black_box(<T::Message<'_>>::from_reader(&mut reader, bytes).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "quick-protobuf", "deserialize", deserialize);
+
+    crate::bench_size(name, "quick-protobuf", deserialize_buffer.as_slice());
+
+    let mut reader = BytesReader::from_bytes(&deserialize_buffer);
+    let message = <T::Message<'_>>::from_reader(&mut reader, &deserialize_buffer).unwrap();
+    assert!(message.into() == *data);
+
+    group.finish();
+}
@@ -0,0 +1,71 @@
+use criterion::{black_box, Criterion};
+use rasn::{Decode, Encode};
+
+pub trait Serialize: Sized {
+    type Message: Encode + Decode + Into<Self>;
+
+    fn serialize_rasn(&self) -> Self::Message;
+}
+
+macro_rules! bench_codec {
+    ($vis:vis $bench:ident, $codec:ident, $lib:literal) => {
+        $vis fn $bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+        where
+            T: Serialize + PartialEq,
+        {
+            let mut group = c.benchmark_group(format!("{}/{}", name, $lib));
+
+            let mut serialize = || {
+                black_box(rasn::$codec::encode(&black_box(data).serialize_rasn()).unwrap());
+            };
+            group.bench_function("serialize", |b| b.iter(&mut serialize));
+            crate::bench_latency(name, $lib, "serialize", serialize);
+
+            let deserialize_buffer = rasn::$codec::encode(&data.serialize_rasn()).unwrap();
+
+            let mut deserialize = || {
+                black_box(
+                    rasn::$codec::decode::<T::Message>(black_box(&deserialize_buffer)).unwrap(),
+                );
+            };
+            group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+            crate::bench_latency(name, $lib, "deserialize", deserialize);
+
+            crate::bench_size(name, $lib, deserialize_buffer.as_slice());
+
+            assert!(
+                rasn::$codec::decode::<T::Message>(&deserialize_buffer)
+                    .unwrap()
+                    .into()
+                    == *data
+            );
+
+            group.finish();
+        }
+    };
+}
+
+bench_codec!(pub bench_ber, ber, "rasn-ber");
+bench_codec!(pub bench_der, der, "rasn-der");
+bench_codec!(bench_uper_codec, uper, "rasn-uper");
+
+/// rasn's UPER decoder can't read back a sequence long enough to be split into a 64K fragment
+/// followed by a 16K one, which it is from this many elements on.
+const UPER_MAX_LEN: usize = 81920;
+
+/// Benchmarks UPER for a data set whose longest sequence has `len` elements.
+pub fn bench_uper<T>(name: &'static str, c: &mut Criterion, data: &T, len: usize)
+where
+    T: Serialize + PartialEq,
+{
+    if len >= UPER_MAX_LEN {
+        crate::bench_skipped(
+            name,
+            "rasn-uper",
+            "its decoder can't read back a sequence split into a 64K fragment followed by a 16K one",
+        );
+        return;
+    }
+
+    bench_uper_codec(name, c, data);
+}
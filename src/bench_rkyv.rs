@@ -1,14 +1,18 @@
-use core::mem::take;
+use core::mem::{take, MaybeUninit};
+use std::panic::{catch_unwind, AssertUnwindSafe};
 
 use criterion::{black_box, Criterion};
 use rkyv::{
     access, access_unchecked, access_unchecked_mut,
-    api::high::{to_bytes_in, HighDeserializer, HighSerializer, HighValidator},
+    api::{
+        high::{to_bytes_in, HighDeserializer, HighSerializer, HighValidator},
+        low::{self, LowSerializer},
+    },
     bytecheck::CheckBytes,
     deserialize, from_bytes,
     rancor::Failure,
     seal::Seal,
-    ser::allocator::ArenaHandle,
+    ser::allocator::{ArenaHandle, SubAllocator},
     util::AlignedVec,
     Archive, Deserialize, Serialize,
 };
@@ -16,31 +20,124 @@ use rkyv::{
 pub type BenchSerializer<'a> = HighSerializer<'a, AlignedVec, ArenaHandle<'a>, Failure>;
 pub type BenchDeserializer = HighDeserializer<Failure>;
 pub type BenchValidator<'a> = HighValidator<'a, Failure>;
+pub type BenchLowSerializer<'a> = LowSerializer<'a, AlignedVec, SubAllocator<'a>, Failure>;
+
+// rkyv's format features change the archived layout for the whole build, so each one is benchmarked
+// by a separate run and reported under its own name.
+#[cfg(any(
+    all(feature = "rkyv-big_endian", feature = "rkyv-unaligned"),
+    all(feature = "rkyv-big_endian", feature = "rkyv-pointer_width_16"),
+    all(feature = "rkyv-big_endian", feature = "rkyv-pointer_width_64"),
+    all(feature = "rkyv-unaligned", feature = "rkyv-pointer_width_16"),
+    all(feature = "rkyv-unaligned", feature = "rkyv-pointer_width_64"),
+    all(feature = "rkyv-pointer_width_16", feature = "rkyv-pointer_width_64"),
+))]
+compile_error!("at most one rkyv format feature can be benchmarked at a time");
+
+#[cfg(not(any(
+    feature = "rkyv-big_endian",
+    feature = "rkyv-unaligned",
+    feature = "rkyv-pointer_width_16",
+    feature = "rkyv-pointer_width_64",
+)))]
+const LIB: &str = "rkyv";
+#[cfg(feature = "rkyv-big_endian")]
+const LIB: &str = "rkyv-big_endian";
+#[cfg(feature = "rkyv-unaligned")]
+const LIB: &str = "rkyv-unaligned";
+#[cfg(feature = "rkyv-pointer_width_16")]
+const LIB: &str = "rkyv-pointer_width_16";
+#[cfg(feature = "rkyv-pointer_width_64")]
+const LIB: &str = "rkyv-pointer_width_64";
+
+const BUFFER_LEN: usize = 10_000_000;
+
+/// Archives `data` into a fresh buffer.
+///
+/// Serializing these data sets can only fail by overflowing a relative pointer, which rkyv reports
+/// with either an error or a panic. Pointers that wrap silently fail validation instead.
+fn encode<T>(data: &T) -> Result<AlignedVec, &'static str>
+where
+    T: for<'a> Serialize<BenchSerializer<'a>>,
+{
+    const OVERFLOW: &str = "relative pointers overflow the pointer width";
+
+    catch_unwind(AssertUnwindSafe(|| {
+        to_bytes_in::<_, Failure>(data, AlignedVec::with_capacity(BUFFER_LEN))
+    }))
+    .map_err(|_| OVERFLOW)?
+    .map_err(|_| OVERFLOW)
+}
+
+/// Allocates scratch space that the low-level serializer can archive `data` in, starting from the
+/// larger of `BUFFER_LEN` and the archived length `len`.
+///
+/// How much scratch space rkyv needs depends on the shape of the data as well as its size, so it's
+/// doubled until serializing succeeds. The high-level serializer has already archived `data`, so
+/// running out of space is the only way this can fail.
+fn low_scratch<T>(data: &T, len: usize) -> Box<[MaybeUninit<u8>]>
+where
+    T: for<'a> Serialize<BenchLowSerializer<'a>>,
+{
+    let mut scratch_len = BUFFER_LEN.max(len);
+    loop {
+        let mut scratch = Box::<[u8]>::new_uninit_slice(scratch_len);
+        let alloc = SubAllocator::new(&mut scratch[..]);
+        let buffer = AlignedVec::with_capacity(len);
+        if low::to_bytes_in_with_alloc::<_, _, Failure>(data, buffer, alloc).is_ok() {
+            return scratch;
+        }
+        scratch_len *= 2;
+    }
+}
 
 pub fn bench<T, R, U>(name: &'static str, c: &mut Criterion, data: &T, read: R, update: U)
 where
-    T: Archive + for<'a> Serialize<BenchSerializer<'a>> + PartialEq,
+    T: Archive
+        + for<'a> Serialize<BenchSerializer<'a>>
+        + for<'a> Serialize<BenchLowSerializer<'a>>
+        + PartialEq,
     T::Archived: for<'a> CheckBytes<BenchValidator<'a>> + Deserialize<T, BenchDeserializer>,
     R: Fn(&T::Archived),
     U: for<'a> Fn(Seal<'a, T::Archived>),
     <T as Archive>::Archived: for<'a> CheckBytes<BenchValidator<'a>>,
 {
-    const BUFFER_LEN: usize = 10_000_000;
+    let Some(mut buffer) = crate::encode_or_skip(name, LIB, data, encode, |bytes| {
+        from_bytes::<T, Failure>(bytes)
+    }) else {
+        return;
+    };
 
-    let mut group = c.benchmark_group(format!("{}/rkyv", name));
+    let mut group = c.benchmark_group(format!("{}/{}", name, LIB));
 
-    let mut buffer = AlignedVec::with_capacity(BUFFER_LEN);
+    let mut serialize = || {
+        buffer.clear();
+        buffer = black_box(to_bytes_in(black_box(data), black_box(take(&mut buffer))).unwrap());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, LIB, "serialize", serialize);
 
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            buffer.clear();
-            buffer = black_box(to_bytes_in(black_box(data), black_box(take(&mut buffer))).unwrap());
-        })
-    });
+    let mut scratch = low_scratch(data, buffer.len());
+    let mut low_buffer = AlignedVec::with_capacity(BUFFER_LEN.max(buffer.len()));
+    let mut serialize_low = || {
+        low_buffer.clear();
+        let alloc = SubAllocator::new(black_box(&mut scratch[..]));
+        low_buffer = black_box(
+            low::to_bytes_in_with_alloc(black_box(data), black_box(take(&mut low_buffer)), alloc)
+                .unwrap(),
+        );
+    };
+    group.bench_function("serialize (low-level)", |b| b.iter(&mut serialize_low));
+    crate::bench_latency(name, LIB, "serialize (low-level)", serialize_low);
 
     buffer.clear();
     buffer = black_box(to_bytes_in(black_box(data), black_box(buffer)).unwrap());
 
+    low_buffer.clear();
+    let alloc = SubAllocator::new(&mut scratch[..]);
+    let low_buffer = low::to_bytes_in_with_alloc(data, low_buffer, alloc).unwrap();
+    assert!(low_buffer.as_slice() == buffer.as_slice());
+
     group.bench_function("access (unvalidated)", |b| {
         b.iter(|| black_box(unsafe { access_unchecked::<T::Archived>(black_box(buffer.as_ref())) }))
     });
@@ -72,23 +169,110 @@ where
         })
     });
 
+    let mut deserialize_unvalidated = || {
+        let value = unsafe { access_unchecked::<T::Archived>(black_box(buffer.as_ref())) };
+        black_box(deserialize(value).unwrap())
+    };
     group.bench_function("deserialize (unvalidated)", |b| {
+        b.iter(&mut deserialize_unvalidated)
+    });
+    crate::bench_latency(
+        name,
+        LIB,
+        "deserialize (unvalidated)",
+        deserialize_unvalidated,
+    );
+
+    let mut deserialize_validated = || {
+        let value = access::<T::Archived, Failure>(black_box(buffer.as_ref())).unwrap();
+        black_box(deserialize(value).unwrap())
+    };
+    group.bench_function("deserialize (validated upfront with error)", |b| {
+        b.iter(&mut deserialize_validated)
+    });
+    crate::bench_latency(
+        name,
+        LIB,
+        "deserialize (validated upfront with error)",
+        deserialize_validated,
+    );
+
+    crate::bench_size(name, LIB, &buffer);
+
+    group.finish();
+}
+
+/// Benchmarks reading the elements at `indices` out of a large archive, which only touches the
+/// parts of the buffer that hold them.
+pub fn bench_read_random<T, R>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &T,
+    indices: &[usize],
+    read: R,
+) where
+    T: Archive + for<'a> Serialize<BenchSerializer<'a>>,
+    T::Archived: for<'a> CheckBytes<BenchValidator<'a>>,
+    R: Fn(&T::Archived, &[usize]),
+{
+    // `bench` has already recorded the data set as skipped if it can't be archived.
+    let Some(buffer) = encode(data)
+        .ok()
+        .filter(|buffer| access::<T::Archived, Failure>(buffer).is_ok())
+    else {
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("{}/{}", name, LIB));
+
+    group.bench_function("read_random (unvalidated)", |b| {
         b.iter(|| {
             let value = unsafe { access_unchecked::<T::Archived>(black_box(buffer.as_ref())) };
-            black_box(deserialize(value).unwrap())
+            read(value, black_box(indices));
         })
     });
 
-    group.bench_function("deserialize (validated upfront with error)", |b| {
+    group.bench_function("read_random (validated upfront with error)", |b| {
         b.iter(|| {
-            let value = access::<T::Archived, Failure>(black_box(buffer.as_ref())).unwrap();
-            black_box(deserialize(value).unwrap())
+            read(
+                access::<T::Archived, Failure>(black_box(buffer.as_ref())).unwrap(),
+                black_box(indices),
+            );
         })
     });
 
-    crate::bench_size(name, "rkyv", &buffer);
+    group.finish();
+}
 
-    assert!(from_bytes::<T, Failure>(buffer.as_ref()).unwrap() == *data);
+/// Benchmarks reading only the fields that `project` looks at out of an archive.
+pub fn bench_project<T, P>(name: &'static str, c: &mut Criterion, data: &T, project: P)
+where
+    T: Archive + for<'a> Serialize<BenchSerializer<'a>>,
+    T::Archived: for<'a> CheckBytes<BenchValidator<'a>>,
+    P: Fn(&T::Archived),
+{
+    // `bench` has already recorded the data set as skipped if it can't be archived.
+    let Some(buffer) = encode(data)
+        .ok()
+        .filter(|buffer| access::<T::Archived, Failure>(buffer).is_ok())
+    else {
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("{}/{}", name, LIB));
+
+    group.bench_function("project (unvalidated)", |b| {
+        b.iter(|| {
+            let value = unsafe { access_unchecked::<T::Archived>(black_box(buffer.as_ref())) };
+            project(value);
+        })
+    });
+
+    group.bench_function("project (validated upfront with error)", |b| {
+        b.iter(|| {
+            project(access::<T::Archived, Failure>(black_box(buffer.as_ref())).unwrap());
+        })
+    });
 
     group.finish();
 }
@@ -1,6 +1,8 @@
 use criterion::{black_box, Criterion};
 use serde::{Deserialize, Serialize};
 
+use crate::{Borrowable, Projectable, ReadRandom};
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: Serialize + for<'de> Deserialize<'de> + PartialEq,
@@ -9,30 +11,158 @@ where
 
     let mut group = c.benchmark_group(format!("{}/rmp-serde", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            rmp_serde::encode::write(
-                &mut black_box(serialize_buffer.as_mut_slice()),
-                black_box(&data),
-            )
-            .unwrap();
-            black_box(());
-        })
-    });
-
     let mut deserialize_buffer = Vec::new();
     rmp_serde::encode::write(&mut deserialize_buffer, &data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(rmp_serde::from_slice::<T>(black_box(&deserialize_buffer)).unwrap());
-        })
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        rmp_serde::encode::write(
+            &mut black_box(serialize_buffer.as_mut_slice()),
+            black_box(&data),
+        )
+        .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "rmp-serde", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(rmp_serde::from_slice::<T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "rmp-serde", "deserialize", deserialize);
+
+    let mut value = rmp_serde::from_slice::<T>(&deserialize_buffer).unwrap();
+    let mut deserialize_in_place = || {
+        let mut deserializer =
+            rmp_serde::Deserializer::from_read_ref(black_box(&deserialize_buffer));
+        T::deserialize_in_place(&mut deserializer, black_box(&mut value)).unwrap();
+    };
+    group.bench_function("deserialize (in place)", |b| {
+        b.iter(&mut deserialize_in_place)
     });
+    crate::bench_latency(
+        name,
+        "rmp-serde",
+        "deserialize (in place)",
+        deserialize_in_place,
+    );
 
     crate::bench_size(name, "rmp-serde", deserialize_buffer.as_slice());
 
     assert!(rmp_serde::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
+    assert!(value == *data);
+
+    group.finish();
+}
+
+/// Benches the struct-as-map encoding from `to_vec_named`, which writes field names so that other
+/// MessagePack implementations can read it.
+pub fn bench_named<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq,
+{
+    const BUFFER_LEN: usize = 10_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/rmp-serde-named", name));
+
+    let deserialize_buffer = rmp_serde::to_vec_named(&data).unwrap();
+
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        rmp_serde::encode::write_named(
+            &mut black_box(serialize_buffer.as_mut_slice()),
+            black_box(&data),
+        )
+        .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "rmp-serde-named", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(rmp_serde::from_slice::<T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "rmp-serde-named", "deserialize", deserialize);
+
+    crate::bench_size(name, "rmp-serde-named", deserialize_buffer.as_slice());
+
+    assert!(rmp_serde::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
+
+    group.finish();
+}
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/rmp-serde", name));
+
+    let mut deserialize_buffer = Vec::new();
+    rmp_serde::encode::write(&mut deserialize_buffer, &data).unwrap();
+
+    let mut deserialize = || {
+        black_box(
+            rmp_serde::from_slice::<T::Borrowed<'_>>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "rmp-serde", "deserialize (borrowed)", deserialize);
+
+    assert!(
+        rmp_serde::from_slice::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+
+    group.finish();
+}
+
+pub fn bench_project<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Projectable,
+{
+    let mut group = c.benchmark_group(format!("{}/rmp-serde", name));
+
+    let mut deserialize_buffer = Vec::new();
+    rmp_serde::encode::write(&mut deserialize_buffer, &data).unwrap();
+
+    let mut project = || {
+        black_box(rmp_serde::from_slice::<T::Projection>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("project", |b| b.iter(&mut project));
+    crate::bench_latency(name, "rmp-serde", "project", project);
+
+    assert!(
+        rmp_serde::from_slice::<T::Projection>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == data.project()
+    );
+
+    group.finish();
+}
+
+/// Benchmarks deserializing the whole buffer and then reading the elements at `indices`, which is
+/// what the zero-copy `read_random` benchmarks avoid.
+pub fn bench_read_random<T>(name: &'static str, c: &mut Criterion, data: &T, indices: &[usize])
+where
+    T: Serialize + for<'de> Deserialize<'de> + ReadRandom,
+{
+    let mut group = c.benchmark_group(format!("{}/rmp-serde", name));
+
+    let mut deserialize_buffer = Vec::new();
+    rmp_serde::encode::write(&mut deserialize_buffer, &data).unwrap();
+
+    group.bench_function("read_random", |b| {
+        b.iter(|| {
+            rmp_serde::from_slice::<T>(black_box(&deserialize_buffer))
+                .unwrap()
+                .read_random(black_box(indices));
+        })
+    });
 
     group.finish();
 }
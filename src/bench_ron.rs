@@ -1,6 +1,8 @@
 use criterion::{black_box, Criterion};
 use serde::{Deserialize, Serialize};
 
+use crate::{Borrowable, Projectable};
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: Serialize + for<'de> Deserialize<'de> + PartialEq,
@@ -9,23 +11,22 @@ where
 
     let mut group = c.benchmark_group(format!("{}/ron", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            ron::ser::to_writer(black_box(serialize_buffer.as_mut_slice()), black_box(&data))
-                .unwrap();
-            black_box(());
-        })
-    });
-
     let mut deserialize_buffer = Vec::new();
     ron::ser::to_writer(&mut deserialize_buffer, &data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(ron::de::from_bytes::<'_, T>(black_box(&deserialize_buffer)).unwrap());
-        })
-    });
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        ron::ser::to_writer(black_box(serialize_buffer.as_mut_slice()), black_box(&data)).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "ron", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(ron::de::from_bytes::<'_, T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "ron", "deserialize", deserialize);
 
     crate::bench_size(name, "ron", deserialize_buffer.as_slice());
 
@@ -33,3 +34,55 @@ where
 
     group.finish();
 }
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/ron", name));
+
+    let mut deserialize_buffer = Vec::new();
+    ron::ser::to_writer(&mut deserialize_buffer, &data).unwrap();
+
+    let mut deserialize = || {
+        black_box(
+            ron::de::from_bytes::<'_, T::Borrowed<'_>>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "ron", "deserialize (borrowed)", deserialize);
+
+    assert!(
+        ron::de::from_bytes::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+
+    group.finish();
+}
+
+pub fn bench_project<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Projectable,
+{
+    let mut group = c.benchmark_group(format!("{}/ron", name));
+
+    let mut deserialize_buffer = Vec::new();
+    ron::ser::to_writer(&mut deserialize_buffer, &data).unwrap();
+
+    let mut project = || {
+        black_box(ron::de::from_bytes::<T::Projection>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("project", |b| b.iter(&mut project));
+    crate::bench_latency(name, "ron", "project", project);
+
+    assert!(
+        ron::de::from_bytes::<T::Projection>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == data.project()
+    );
+
+    group.finish();
+}
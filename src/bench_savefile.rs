@@ -9,23 +9,23 @@ where
     let mut group = c.benchmark_group(format!("{}/savefile", name));
 
     let mut serialize_buffer = Vec::new();
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            serialize_buffer.clear();
-            savefile::save_noschema(black_box(&mut serialize_buffer), 0, black_box(data)).unwrap();
-            black_box(());
-        })
-    });
+    let mut serialize = || {
+        serialize_buffer.clear();
+        savefile::save_noschema(black_box(&mut serialize_buffer), 0, black_box(data)).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "savefile", "serialize", serialize);
 
     let mut deserialize_buffer = Vec::new();
     savefile::save_noschema(&mut deserialize_buffer, 0, data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            let mut reader = Cursor::new(&deserialize_buffer);
-            black_box(savefile::load_noschema::<T>(black_box(&mut reader), 0).unwrap());
-        })
-    });
+    let mut deserialize = || {
+        let mut reader = Cursor::new(&deserialize_buffer);
+        black_box(savefile::load_noschema::<T>(black_box(&mut reader), 0).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "savefile", "deserialize", deserialize);
 
     crate::bench_size(name, "savefile", deserialize_buffer.as_slice());
 
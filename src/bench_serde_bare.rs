@@ -9,23 +9,23 @@ where
 
     let mut group = c.benchmark_group(format!("{}/serde_bare", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            serde_bare::to_writer(black_box(serialize_buffer.as_mut_slice()), black_box(&data))
-                .unwrap();
-            black_box(());
-        })
-    });
-
     let mut deserialize_buffer = Vec::new();
     serde_bare::to_writer(&mut deserialize_buffer, &data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(serde_bare::from_slice::<T>(black_box(&deserialize_buffer)).unwrap());
-        })
-    });
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        serde_bare::to_writer(black_box(serialize_buffer.as_mut_slice()), black_box(&data))
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "serde_bare", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(serde_bare::from_slice::<T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "serde_bare", "deserialize", deserialize);
 
     crate::bench_size(name, "serde_bare", deserialize_buffer.as_slice());
 
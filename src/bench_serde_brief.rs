@@ -1,6 +1,8 @@
 use criterion::{black_box, Criterion};
 use serde::{Deserialize, Serialize};
 
+use crate::{Borrowable, Projectable};
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: Serialize + for<'de> Deserialize<'de> + PartialEq,
@@ -9,23 +11,23 @@ where
 
     let mut group = c.benchmark_group(format!("{}/serde-brief", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            black_box(
-                serde_brief::to_slice(black_box(&data), black_box(serialize_buffer.as_mut_slice()))
-                    .unwrap(),
-            );
-        })
-    });
-
     let deserialize_buffer = serde_brief::to_vec(&data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(serde_brief::from_slice::<'_, T>(black_box(&deserialize_buffer)).unwrap());
-        })
-    });
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        black_box(
+            serde_brief::to_slice(black_box(&data), black_box(serialize_buffer.as_mut_slice()))
+                .unwrap(),
+        );
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "serde-brief", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(serde_brief::from_slice::<'_, T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "serde-brief", "deserialize", deserialize);
 
     crate::bench_size(name, "serde-brief", deserialize_buffer.as_slice());
 
@@ -33,3 +35,55 @@ where
 
     group.finish();
 }
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/serde-brief", name));
+
+    let deserialize_buffer = serde_brief::to_vec(&data).unwrap();
+
+    let mut deserialize = || {
+        black_box(
+            serde_brief::from_slice::<'_, T::Borrowed<'_>>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "serde-brief", "deserialize (borrowed)", deserialize);
+
+    assert!(
+        serde_brief::from_slice::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+
+    group.finish();
+}
+
+pub fn bench_project<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Projectable,
+{
+    let mut group = c.benchmark_group(format!("{}/serde-brief", name));
+
+    let deserialize_buffer = serde_brief::to_vec(&data).unwrap();
+
+    let mut project = || {
+        black_box(
+            serde_brief::from_slice::<T::Projection>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("project", |b| b.iter(&mut project));
+    crate::bench_latency(name, "serde-brief", "project", project);
+
+    assert!(
+        serde_brief::from_slice::<T::Projection>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == data.project()
+    );
+
+    group.finish();
+}
@@ -1,6 +1,8 @@
 use criterion::{black_box, Criterion};
 use serde::{Deserialize, Serialize};
 
+use crate::{Borrowable, Projectable};
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: Serialize + for<'de> Deserialize<'de> + PartialEq,
@@ -9,27 +11,95 @@ where
 
     let mut group = c.benchmark_group(format!("{}/serde_cbor", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            serde_cbor::to_writer(black_box(serialize_buffer.as_mut_slice()), black_box(&data))
-                .unwrap();
-            black_box(());
-        })
-    });
-
     let mut deserialize_buffer = Vec::new();
     serde_cbor::to_writer(&mut deserialize_buffer, &data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(serde_cbor::from_slice::<'_, T>(black_box(&deserialize_buffer)).unwrap());
-        })
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        serde_cbor::to_writer(black_box(serialize_buffer.as_mut_slice()), black_box(&data))
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "serde_cbor", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(serde_cbor::from_slice::<'_, T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "serde_cbor", "deserialize", deserialize);
+
+    let mut value = serde_cbor::from_slice::<T>(&deserialize_buffer).unwrap();
+    let mut deserialize_in_place = || {
+        let mut deserializer = serde_cbor::Deserializer::from_slice(black_box(&deserialize_buffer));
+        T::deserialize_in_place(&mut deserializer, black_box(&mut value)).unwrap();
+    };
+    group.bench_function("deserialize (in place)", |b| {
+        b.iter(&mut deserialize_in_place)
     });
+    crate::bench_latency(
+        name,
+        "serde_cbor",
+        "deserialize (in place)",
+        deserialize_in_place,
+    );
 
     crate::bench_size(name, "serde_cbor", deserialize_buffer.as_slice());
 
     assert!(serde_cbor::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
+    assert!(value == *data);
+
+    group.finish();
+}
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/serde_cbor", name));
+
+    let mut deserialize_buffer = Vec::new();
+    serde_cbor::to_writer(&mut deserialize_buffer, &data).unwrap();
+
+    let mut deserialize = || {
+        black_box(
+            serde_cbor::from_slice::<'_, T::Borrowed<'_>>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "serde_cbor", "deserialize (borrowed)", deserialize);
+
+    assert!(
+        serde_cbor::from_slice::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+
+    group.finish();
+}
+
+pub fn bench_project<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Projectable,
+{
+    let mut group = c.benchmark_group(format!("{}/serde_cbor", name));
+
+    let mut deserialize_buffer = Vec::new();
+    serde_cbor::to_writer(&mut deserialize_buffer, &data).unwrap();
+
+    let mut project = || {
+        black_box(serde_cbor::from_slice::<T::Projection>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("project", |b| b.iter(&mut project));
+    crate::bench_latency(name, "serde_cbor", "project", project);
+
+    assert!(
+        serde_cbor::from_slice::<T::Projection>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == data.project()
+    );
 
     group.finish();
 }
@@ -1,6 +1,8 @@
 use criterion::{black_box, Criterion};
 use serde::{Deserialize, Serialize};
 
+use crate::{Borrowable, Projectable, ReadRandom};
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: Serialize + for<'de> Deserialize<'de> + PartialEq,
@@ -9,27 +11,150 @@ where
 
     let mut group = c.benchmark_group(format!("{}/serde_json", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            serde_json::to_writer(black_box(serialize_buffer.as_mut_slice()), black_box(&data))
-                .unwrap();
-            black_box(());
-        })
-    });
-
     let mut deserialize_buffer = Vec::new();
     serde_json::to_writer(&mut deserialize_buffer, &data).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(serde_json::from_slice::<'_, T>(black_box(&deserialize_buffer)).unwrap());
-        })
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        serde_json::to_writer(black_box(serialize_buffer.as_mut_slice()), black_box(&data))
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "serde_json", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(serde_json::from_slice::<'_, T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "serde_json", "deserialize", deserialize);
+
+    let mut value = serde_json::from_slice::<T>(&deserialize_buffer).unwrap();
+    let mut deserialize_in_place = || {
+        let mut deserializer = serde_json::Deserializer::from_slice(black_box(&deserialize_buffer));
+        T::deserialize_in_place(&mut deserializer, black_box(&mut value)).unwrap();
+    };
+    group.bench_function("deserialize (in place)", |b| {
+        b.iter(&mut deserialize_in_place)
     });
+    crate::bench_latency(
+        name,
+        "serde_json",
+        "deserialize (in place)",
+        deserialize_in_place,
+    );
 
     crate::bench_size(name, "serde_json", deserialize_buffer.as_slice());
 
     assert!(serde_json::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
+    assert!(value == *data);
+
+    group.finish();
+}
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/serde_json", name));
+
+    let mut deserialize_buffer = Vec::new();
+    serde_json::to_writer(&mut deserialize_buffer, &data).unwrap();
+
+    let mut deserialize = || {
+        black_box(
+            serde_json::from_slice::<'_, T::Borrowed<'_>>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "serde_json", "deserialize (borrowed)", deserialize);
+
+    let mut deserialize_value = || {
+        black_box(
+            serde_json::from_slice::<serde_json::Value>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("deserialize (value)", |b| b.iter(&mut deserialize_value));
+    crate::bench_latency(name, "serde_json", "deserialize (value)", deserialize_value);
+
+    #[cfg(feature = "serde_json_borrow")]
+    {
+        let mut deserialize_borrowed_value = || {
+            black_box(
+                serde_json::from_slice::<serde_json_borrow::Value>(black_box(&deserialize_buffer))
+                    .unwrap(),
+            );
+        };
+        group.bench_function("deserialize (borrowed value)", |b| {
+            b.iter(&mut deserialize_borrowed_value)
+        });
+        crate::bench_latency(
+            name,
+            "serde_json",
+            "deserialize (borrowed value)",
+            deserialize_borrowed_value,
+        );
+
+        let value =
+            serde_json::from_slice::<serde_json_borrow::Value>(&deserialize_buffer).unwrap();
+        assert!(serde_json::from_value::<T>(serde_json::Value::from(&value)).unwrap() == *data);
+    }
+
+    assert!(
+        serde_json::from_slice::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+    let value = serde_json::from_slice::<serde_json::Value>(&deserialize_buffer).unwrap();
+    assert!(serde_json::from_value::<T>(value).unwrap() == *data);
+
+    group.finish();
+}
+
+pub fn bench_project<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Projectable,
+{
+    let mut group = c.benchmark_group(format!("{}/serde_json", name));
+
+    let mut deserialize_buffer = Vec::new();
+    serde_json::to_writer(&mut deserialize_buffer, &data).unwrap();
+
+    let mut project = || {
+        black_box(serde_json::from_slice::<T::Projection>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("project", |b| b.iter(&mut project));
+    crate::bench_latency(name, "serde_json", "project", project);
+
+    assert!(
+        serde_json::from_slice::<T::Projection>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == data.project()
+    );
+
+    group.finish();
+}
+
+/// Benchmarks deserializing the whole buffer and then reading the elements at `indices`, which is
+/// what the zero-copy `read_random` benchmarks avoid.
+pub fn bench_read_random<T>(name: &'static str, c: &mut Criterion, data: &T, indices: &[usize])
+where
+    T: Serialize + for<'de> Deserialize<'de> + ReadRandom,
+{
+    let mut group = c.benchmark_group(format!("{}/serde_json", name));
+
+    let mut deserialize_buffer = Vec::new();
+    serde_json::to_writer(&mut deserialize_buffer, &data).unwrap();
+
+    group.bench_function("read_random", |b| {
+        b.iter(|| {
+            serde_json::from_slice::<T>(black_box(&deserialize_buffer))
+                .unwrap()
+                .read_random(black_box(indices));
+        })
+    });
 
     group.finish();
 }
@@ -0,0 +1,38 @@
+use criterion::{black_box, Criterion};
+use serde::{Deserialize, Serialize};
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq,
+{
+    const BUFFER_LEN: usize = 50_000_000;
+
+    let Some(deserialize_buffer) =
+        crate::encode_or_skip(name, "serde_yaml", data, serde_yaml::to_string, |s| {
+            serde_yaml::from_str(s)
+        })
+    else {
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("{}/serde_yaml", name));
+
+    let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
+    let mut serialize = || {
+        black_box(&mut serialize_buffer).clear();
+        serde_yaml::to_writer(&mut serialize_buffer, black_box(&data)).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "serde_yaml", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(serde_yaml::from_str::<T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "serde_yaml", "deserialize", deserialize);
+
+    crate::bench_size(name, "serde_yaml", deserialize_buffer.as_bytes());
+
+    group.finish();
+}
@@ -1,4 +1,5 @@
 use criterion::{black_box, BatchSize, Criterion};
+use serde::de::DeserializeOwned;
 use simd_json::Buffers;
 use simd_json_derive::{Deserialize, Serialize};
 
@@ -10,17 +11,18 @@ where
 
     let mut group = c.benchmark_group(format!("{}/simd-json", name));
 
-    let mut serialize_buffer = vec![0u8; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            black_box(data)
-                .json_write(&mut black_box(serialize_buffer.as_mut_slice()))
-                .unwrap();
-            black_box(());
-        })
-    });
-
     let deserialize_buffer = data.json_vec().unwrap();
+
+    let mut serialize_buffer = vec![0u8; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        black_box(data)
+            .json_write(&mut black_box(serialize_buffer.as_mut_slice()))
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "simd-json", "serialize", serialize);
+
     let mut buffers = Buffers::new(BUFFER_LEN);
 
     group.bench_function("deserialize", |b| {
@@ -35,6 +37,18 @@ where
             BatchSize::SmallInput,
         )
     });
+    crate::bench_latency_batched(
+        name,
+        "simd-json",
+        "deserialize",
+        || deserialize_buffer.clone(),
+        |deserialize_buffer| {
+            black_box(
+                T::from_slice_with_buffers(deserialize_buffer.as_mut_slice(), &mut buffers)
+                    .unwrap(),
+            );
+        },
+    );
 
     crate::bench_size(name, "simd-json", deserialize_buffer.as_slice());
 
@@ -46,3 +60,82 @@ where
 
     group.finish();
 }
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq,
+{
+    const BUFFER_LEN: usize = 50_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/simd-json", name));
+
+    let deserialize_buffer = data.json_vec().unwrap();
+    let mut buffers = Buffers::new(BUFFER_LEN);
+
+    group.bench_function("deserialize (tape)", |b| {
+        b.iter_batched_ref(
+            || deserialize_buffer.clone(),
+            |deserialize_buffer| {
+                black_box(
+                    simd_json::to_tape_with_buffers(
+                        deserialize_buffer.as_mut_slice(),
+                        &mut buffers,
+                    )
+                    .unwrap(),
+                );
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    crate::bench_latency_batched(
+        name,
+        "simd-json",
+        "deserialize (tape)",
+        || deserialize_buffer.clone(),
+        |deserialize_buffer| {
+            black_box(
+                simd_json::to_tape_with_buffers(deserialize_buffer.as_mut_slice(), &mut buffers)
+                    .unwrap(),
+            );
+        },
+    );
+
+    group.bench_function("deserialize (borrowed value)", |b| {
+        b.iter_batched_ref(
+            || deserialize_buffer.clone(),
+            |deserialize_buffer| {
+                black_box(
+                    simd_json::to_borrowed_value_with_buffers(
+                        deserialize_buffer.as_mut_slice(),
+                        &mut buffers,
+                    )
+                    .unwrap(),
+                );
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    crate::bench_latency_batched(
+        name,
+        "simd-json",
+        "deserialize (borrowed value)",
+        || deserialize_buffer.clone(),
+        |deserialize_buffer| {
+            black_box(
+                simd_json::to_borrowed_value_with_buffers(
+                    deserialize_buffer.as_mut_slice(),
+                    &mut buffers,
+                )
+                .unwrap(),
+            );
+        },
+    );
+
+    let mut value_buffer = deserialize_buffer.clone();
+    let value =
+        simd_json::to_borrowed_value_with_buffers(value_buffer.as_mut_slice(), &mut buffers)
+            .unwrap();
+    assert!(simd_json::serde::from_borrowed_value::<T>(value).unwrap() == *data);
+
+    group.finish();
+}
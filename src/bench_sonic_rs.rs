@@ -0,0 +1,94 @@
+use criterion::{black_box, Criterion};
+use serde::{Deserialize, Serialize};
+
+use crate::{Borrowable, Projectable};
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq,
+{
+    const BUFFER_LEN: usize = 50_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/sonic-rs", name));
+
+    let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
+    let mut serialize = || {
+        black_box(&mut serialize_buffer).clear();
+        sonic_rs::to_writer(&mut serialize_buffer, black_box(&data)).unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "sonic-rs", "serialize", serialize);
+
+    let deserialize_buffer = sonic_rs::to_vec(&data).unwrap();
+
+    let mut deserialize = || {
+        black_box(sonic_rs::from_slice::<'_, T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "sonic-rs", "deserialize", deserialize);
+
+    crate::bench_size(name, "sonic-rs", deserialize_buffer.as_slice());
+
+    assert!(sonic_rs::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
+
+    group.finish();
+}
+
+pub fn bench_borrowed<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + Borrowable + PartialEq,
+{
+    let mut group = c.benchmark_group(format!("{}/sonic-rs", name));
+
+    let deserialize_buffer = sonic_rs::to_vec(&data).unwrap();
+
+    let mut deserialize = || {
+        black_box(
+            sonic_rs::from_slice::<'_, T::Borrowed<'_>>(black_box(&deserialize_buffer)).unwrap(),
+        );
+    };
+    group.bench_function("deserialize (borrowed)", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "sonic-rs", "deserialize (borrowed)", deserialize);
+
+    let mut deserialize_value = || {
+        black_box(sonic_rs::from_slice::<sonic_rs::Value>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize (value)", |b| b.iter(&mut deserialize_value));
+    crate::bench_latency(name, "sonic-rs", "deserialize (value)", deserialize_value);
+
+    assert!(
+        sonic_rs::from_slice::<T::Borrowed<'_>>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == *data
+    );
+    let value = sonic_rs::from_slice::<sonic_rs::Value>(&deserialize_buffer).unwrap();
+    assert!(sonic_rs::from_value::<T>(&value).unwrap() == *data);
+
+    group.finish();
+}
+
+pub fn bench_project<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + Projectable,
+{
+    let mut group = c.benchmark_group(format!("{}/sonic-rs", name));
+
+    let deserialize_buffer = sonic_rs::to_vec(&data).unwrap();
+
+    let mut project = || {
+        black_box(sonic_rs::from_slice::<T::Projection>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("project", |b| b.iter(&mut project));
+    crate::bench_latency(name, "sonic-rs", "project", project);
+
+    assert!(
+        sonic_rs::from_slice::<T::Projection>(&deserialize_buffer)
+            .unwrap()
+            .into()
+            == data.project()
+    );
+
+    group.finish();
+}
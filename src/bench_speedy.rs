@@ -1,6 +1,8 @@
 use criterion::{black_box, Criterion};
 use speedy::{Endianness, Readable, Writable};
 
+use crate::ReadRandom;
+
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
     T: for<'a> Readable<'a, Endianness> + Writable<Endianness> + PartialEq,
@@ -14,25 +16,25 @@ where
 
     let mut group = c.benchmark_group(format!("{}/speedy", name));
 
-    let mut serialize_buffer = vec![0; BUFFER_LEN];
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            data.write_to_buffer_with_ctx(CONTEXT, black_box(serialize_buffer.as_mut_slice()))
-                .unwrap();
-            black_box(());
-        })
-    });
-
     let deserialize_buffer = data.write_to_vec_with_ctx(CONTEXT).unwrap();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            black_box(
-                T::read_from_buffer_with_ctx(CONTEXT, black_box(deserialize_buffer.as_slice()))
-                    .unwrap(),
-            );
-        })
-    });
+    let mut serialize_buffer = vec![0; BUFFER_LEN.max(deserialize_buffer.len())];
+    let mut serialize = || {
+        data.write_to_buffer_with_ctx(CONTEXT, black_box(serialize_buffer.as_mut_slice()))
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "speedy", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(
+            T::read_from_buffer_with_ctx(CONTEXT, black_box(deserialize_buffer.as_slice()))
+                .unwrap(),
+        );
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "speedy", "deserialize", deserialize);
 
     crate::bench_size(name, "speedy", deserialize_buffer.as_slice());
 
@@ -40,3 +42,29 @@ where
 
     group.finish();
 }
+
+/// Benchmarks deserializing the whole buffer and then reading the elements at `indices`, which is
+/// what the zero-copy `read_random` benchmarks avoid.
+pub fn bench_read_random<T>(name: &'static str, c: &mut Criterion, data: &T, indices: &[usize])
+where
+    T: for<'a> Readable<'a, Endianness> + Writable<Endianness> + ReadRandom,
+{
+    #[cfg(target_endian = "little")]
+    const CONTEXT: Endianness = Endianness::LittleEndian;
+    #[cfg(target_endian = "big")]
+    const CONTEXT: Endianness = Endianness::BigEndian;
+
+    let mut group = c.benchmark_group(format!("{}/speedy", name));
+
+    let deserialize_buffer = data.write_to_vec_with_ctx(CONTEXT).unwrap();
+
+    group.bench_function("read_random", |b| {
+        b.iter(|| {
+            T::read_from_buffer_with_ctx(CONTEXT, black_box(deserialize_buffer.as_slice()))
+                .unwrap()
+                .read_random(black_box(indices));
+        })
+    });
+
+    group.finish();
+}
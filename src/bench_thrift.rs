@@ -0,0 +1,87 @@
+use criterion::{black_box, Criterion};
+use thrift::protocol::{
+    TBinaryInputProtocol, TBinaryOutputProtocol, TCompactInputProtocol, TCompactOutputProtocol,
+    TSerializable,
+};
+
+pub trait Serialize: Sized {
+    type Message: Into<Self> + TSerializable;
+
+    fn serialize_thrift(&self) -> Self::Message;
+}
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + PartialEq,
+{
+    const BUFFER_LEN: usize = 10_000_000;
+
+    let mut group = c.benchmark_group(format!("{}/thrift", name));
+
+    let mut serialize_buffer = Vec::with_capacity(BUFFER_LEN);
+
+    let mut serialize = || {
+        black_box(&mut serialize_buffer).clear();
+        let mut protocol = TCompactOutputProtocol::new(&mut serialize_buffer);
+        data.serialize_thrift()
+            .write_to_out_protocol(&mut protocol)
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "thrift", "serialize", serialize);
+
+    let mut serialize_binary = || {
+        black_box(&mut serialize_buffer).clear();
+        let mut protocol = TBinaryOutputProtocol::new(&mut serialize_buffer, true);
+        data.serialize_thrift()
+            .write_to_out_protocol(&mut protocol)
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize (binary)", |b| b.iter(&mut serialize_binary));
+    crate::bench_latency(name, "thrift", "serialize (binary)", serialize_binary);
+
+    let mut compact_buffer = Vec::new();
+    data.serialize_thrift()
+        .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut compact_buffer))
+        .unwrap();
+    let mut binary_buffer = Vec::new();
+    data.serialize_thrift()
+        .write_to_out_protocol(&mut TBinaryOutputProtocol::new(&mut binary_buffer, true))
+        .unwrap();
+
+    let mut deserialize = || {
+        let mut protocol = TCompactInputProtocol::new(black_box(compact_buffer.as_slice()));
+        black_box(<T::Message>::read_from_in_protocol(&mut protocol).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "thrift", "deserialize", deserialize);
+
+    let mut deserialize_binary = || {
+        let mut protocol = TBinaryInputProtocol::new(black_box(binary_buffer.as_slice()), true);
+        black_box(<T::Message>::read_from_in_protocol(&mut protocol).unwrap());
+    };
+    group.bench_function("deserialize (binary)", |b| b.iter(&mut deserialize_binary));
+    crate::bench_latency(name, "thrift", "deserialize (binary)", deserialize_binary);
+
+    crate::bench_size(name, "thrift", compact_buffer.as_slice());
+    crate::bench_size_variant(name, "thrift", "binary", binary_buffer.as_slice());
+
+    let mut protocol = TCompactInputProtocol::new(compact_buffer.as_slice());
+    assert!(
+        <T::Message>::read_from_in_protocol(&mut protocol)
+            .unwrap()
+            .into()
+            == *data
+    );
+    let mut protocol = TBinaryInputProtocol::new(binary_buffer.as_slice(), true);
+    assert!(
+        <T::Message>::read_from_in_protocol(&mut protocol)
+            .unwrap()
+            .into()
+            == *data
+    );
+
+    group.finish();
+}
@@ -0,0 +1,38 @@
+use criterion::{black_box, Criterion};
+use serde::{Deserialize, Serialize};
+
+pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq,
+{
+    const BUFFER_LEN: usize = 50_000_000;
+
+    let Some(deserialize_buffer) =
+        crate::encode_or_skip(name, "toml", data, toml::to_string, |s| toml::from_str(s))
+    else {
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("{}/toml", name));
+
+    let mut serialize_buffer = String::with_capacity(BUFFER_LEN);
+    let mut serialize = || {
+        black_box(&mut serialize_buffer).clear();
+        black_box(&data)
+            .serialize(toml::Serializer::new(&mut serialize_buffer))
+            .unwrap();
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "toml", "serialize", serialize);
+
+    let mut deserialize = || {
+        black_box(toml::from_str::<T>(black_box(&deserialize_buffer)).unwrap());
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "toml", "deserialize", deserialize);
+
+    crate::bench_size(name, "toml", deserialize_buffer.as_bytes());
+
+    group.finish();
+}
@@ -14,24 +14,24 @@ where
 
     let mut buffer = [0u8; BUFFER_LEN];
 
-    group.bench_function("serialize", |b| {
-        b.iter(|| {
-            let array: &[u8; PAGE_SIZE] = unsafe { transmute(black_box(data)) };
-            black_box(buffer.copy_from_slice(array));
-        })
-    });
+    let mut serialize = || {
+        let array: &[u8; PAGE_SIZE] = unsafe { transmute(black_box(data)) };
+        black_box(buffer.copy_from_slice(array));
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "transmute", "serialize", serialize);
     group.bench_function("read (unvalidated)", |b| {
         b.iter(|| {
             let value: &T = unsafe { transmute(black_box(&buffer)) };
             read(value);
         })
     });
-    group.bench_function("deserialize", |b| {
-        b.iter(|| {
-            let value: T = unsafe { transmute_copy(black_box(&buffer)) };
-            black_box(value);
-        })
-    });
+    let mut deserialize = || {
+        let value: T = unsafe { transmute_copy(black_box(&buffer)) };
+        black_box(value);
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "transmute", "deserialize", deserialize);
 
     let mut update_buffer = buffer.clone();
     group.bench_function("update (unvalidated)", |b| {
@@ -8,17 +8,17 @@ pub fn bench<T: Wiring + Unwiring + PartialEq>(name: &'static str, c: &mut Crite
 
     let mut wire: Vec<u8> = Vec::with_capacity(BUFFER_LEN);
 
-    group.bench_function("serialize", |b| {
-        b.iter(|| BufWire::new(&mut wire).wire(black_box(data)).unwrap())
-    });
+    let mut serialize = || BufWire::new(&mut wire).wire(black_box(data)).unwrap();
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "wiring", "serialize", serialize);
 
     BufWire::new(&mut wire).wire(data).unwrap();
 
     let buffer = wire.as_slice();
 
-    group.bench_function("deserialize", |b| {
-        b.iter(|| BufUnWire::new(black_box(buffer)).unwire::<T>().unwrap())
-    });
+    let mut deserialize = || BufUnWire::new(black_box(buffer)).unwire::<T>().unwrap();
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "wiring", "deserialize", deserialize);
 
     crate::bench_size(name, "wiring", wire.as_slice());
 
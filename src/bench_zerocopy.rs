@@ -0,0 +1,146 @@
+use criterion::{black_box, Criterion};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
+
+/// Benchmarks casting a single value with a fully-initialized layout to and from bytes.
+pub fn bench<T, R, U>(name: &'static str, c: &mut Criterion, data: &T, read: R, update: U)
+where
+    T: FromBytes + IntoBytes + Immutable + KnownLayout + PartialEq,
+    R: Fn(&T),
+    U: Fn(&mut T),
+{
+    let mut group = c.benchmark_group(format!("{}/zerocopy", name));
+
+    // Backed by a `T` so that the bytes are always aligned for it.
+    let mut buffer = T::new_zeroed();
+
+    let mut serialize = || {
+        black_box(&mut buffer)
+            .as_mut_bytes()
+            .copy_from_slice(black_box(data).as_bytes());
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "zerocopy", "serialize", serialize);
+
+    buffer.as_mut_bytes().copy_from_slice(data.as_bytes());
+    let bytes = buffer.as_bytes();
+
+    group.bench_function("access (validated upfront with error)", |b| {
+        b.iter(|| black_box(T::ref_from_bytes(black_box(bytes)).unwrap()))
+    });
+
+    group.bench_function("read (validated upfront with error)", |b| {
+        b.iter(|| {
+            read(T::ref_from_bytes(black_box(bytes)).unwrap());
+            black_box(());
+        })
+    });
+
+    let mut deserialize = || {
+        let value = T::read_from_bytes(black_box(bytes)).unwrap();
+        black_box(value);
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "zerocopy", "deserialize", deserialize);
+
+    let mut update_buffer = T::read_from_bytes(bytes).unwrap();
+    let update_bytes = update_buffer.as_mut_bytes();
+    group.bench_function("update (validated upfront with error)", |b| {
+        b.iter(|| {
+            update(T::mut_from_bytes(black_box(&mut *update_bytes)).unwrap());
+            black_box(());
+        })
+    });
+
+    crate::bench_size(name, "zerocopy", bytes);
+
+    assert!(T::ref_from_bytes(bytes).unwrap() == data);
+
+    group.finish();
+}
+
+/// Benchmarks casting a slice of values with a fully-initialized layout to and from bytes.
+pub fn bench_slice<T, R, U>(name: &'static str, c: &mut Criterion, data: &[T], read: R, update: U)
+where
+    T: FromBytes + IntoBytes + Immutable + KnownLayout + Clone + PartialEq,
+    R: Fn(&[T]),
+    U: Fn(&mut [T]),
+{
+    let mut group = c.benchmark_group(format!("{}/zerocopy", name));
+
+    // Backed by `T`s so that the bytes are always aligned for them.
+    let mut buffer = T::new_vec_zeroed(data.len()).unwrap();
+
+    let mut serialize = || {
+        black_box(buffer.as_mut_slice())
+            .as_mut_bytes()
+            .copy_from_slice(black_box(data).as_bytes());
+        black_box(());
+    };
+    group.bench_function("serialize", |b| b.iter(&mut serialize));
+    crate::bench_latency(name, "zerocopy", "serialize", serialize);
+
+    buffer.as_mut_bytes().copy_from_slice(data.as_bytes());
+    let bytes = buffer.as_bytes();
+
+    group.bench_function("access (validated upfront with error)", |b| {
+        b.iter(|| black_box(<[T]>::ref_from_bytes(black_box(bytes)).unwrap()))
+    });
+
+    group.bench_function("read (validated upfront with error)", |b| {
+        b.iter(|| {
+            read(<[T]>::ref_from_bytes(black_box(bytes)).unwrap());
+            black_box(());
+        })
+    });
+
+    let mut deserialize = || {
+        let value: Vec<T> = <[T]>::ref_from_bytes(black_box(bytes)).unwrap().to_vec();
+        black_box(value);
+    };
+    group.bench_function("deserialize", |b| b.iter(&mut deserialize));
+    crate::bench_latency(name, "zerocopy", "deserialize", deserialize);
+
+    let mut update_buffer = buffer.clone();
+    let update_bytes = update_buffer.as_mut_bytes();
+    group.bench_function("update (validated upfront with error)", |b| {
+        b.iter(|| {
+            update(<[T]>::mut_from_bytes(black_box(&mut *update_bytes)).unwrap());
+            black_box(());
+        })
+    });
+
+    crate::bench_size(name, "zerocopy", bytes);
+
+    assert!(<[T]>::ref_from_bytes(bytes).unwrap() == data);
+
+    group.finish();
+}
+
+/// Benchmarks reading the elements at `indices` out of a large slice of values.
+pub fn bench_slice_read_random<T, R>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &[T],
+    indices: &[usize],
+    read: R,
+) where
+    T: FromBytes + IntoBytes + Immutable + KnownLayout,
+    R: Fn(&[T], &[usize]),
+{
+    let mut group = c.benchmark_group(format!("{}/zerocopy", name));
+
+    let bytes = data.as_bytes();
+
+    group.bench_function("read_random (validated upfront with error)", |b| {
+        b.iter(|| {
+            read(
+                <[T]>::ref_from_bytes(black_box(bytes)).unwrap(),
+                black_box(indices),
+            );
+            black_box(());
+        })
+    });
+
+    group.finish();
+}
@@ -0,0 +1,312 @@
+// Written by hand to follow the output of Thrift Compiler (0.17.0). build.rs doesn't regenerate
+// it, so keep it in step with the .thrift schema by hand.
+
+#![allow(unused_imports)]
+#![allow(unused_extern_crates)]
+#![allow(clippy::too_many_arguments, clippy::type_complexity, clippy::vec_box)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::{From, TryFrom};
+use std::default::Default;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+use thrift::OrderedFloat;
+use thrift::{ApplicationError, ApplicationErrorKind, ProtocolError, ProtocolErrorKind, TThriftClient};
+use thrift::protocol::{TFieldIdentifier, TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType, TInputProtocol, TOutputProtocol, TSerializable, TSetIdentifier, TStructIdentifier, TType};
+use thrift::protocol::field_id;
+use thrift::protocol::verify_expected_message_type;
+use thrift::protocol::verify_expected_sequence_number;
+use thrift::protocol::verify_expected_service_call;
+use thrift::protocol::verify_required_field_exists;
+use thrift::server::TProcessor;
+
+//
+// Address
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Address {
+  pub x0: i8,
+  pub x1: i8,
+  pub x2: i8,
+  pub x3: i8,
+}
+
+impl Address {
+  pub fn new(x0: i8, x1: i8, x2: i8, x3: i8) -> Address {
+    Address {
+      x0,
+      x1,
+      x2,
+      x3,
+    }
+  }
+}
+
+impl TSerializable for Address {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Address> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i8> = None;
+    let mut f_2: Option<i8> = None;
+    let mut f_3: Option<i8> = None;
+    let mut f_4: Option<i8> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i8()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_i8()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_i8()?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = i_prot.read_i8()?;
+          f_4 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Address.x0", &f_1)?;
+    verify_required_field_exists("Address.x1", &f_2)?;
+    verify_required_field_exists("Address.x2", &f_3)?;
+    verify_required_field_exists("Address.x3", &f_4)?;
+    let ret = Address {
+      x0: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      x1: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      x2: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      x3: f_4.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Address");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x0", TType::I08, 1))?;
+    o_prot.write_i8(self.x0)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x1", TType::I08, 2))?;
+    o_prot.write_i8(self.x1)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x2", TType::I08, 3))?;
+    o_prot.write_i8(self.x2)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x3", TType::I08, 4))?;
+    o_prot.write_i8(self.x3)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Log
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Log {
+  pub address: Address,
+  pub identity: String,
+  pub userid: String,
+  pub date: String,
+  pub request: String,
+  pub code: i16,
+  pub size: i64,
+}
+
+impl Log {
+  pub fn new(address: Address, identity: String, userid: String, date: String, request: String, code: i16, size: i64) -> Log {
+    Log {
+      address,
+      identity,
+      userid,
+      date,
+      request,
+      code,
+      size,
+    }
+  }
+}
+
+impl TSerializable for Log {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Log> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Address> = None;
+    let mut f_2: Option<String> = None;
+    let mut f_3: Option<String> = None;
+    let mut f_4: Option<String> = None;
+    let mut f_5: Option<String> = None;
+    let mut f_6: Option<i16> = None;
+    let mut f_7: Option<i64> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = Address::read_from_in_protocol(i_prot)?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_string()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_string()?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = i_prot.read_string()?;
+          f_4 = Some(val);
+        },
+        5 => {
+          let val = i_prot.read_string()?;
+          f_5 = Some(val);
+        },
+        6 => {
+          let val = i_prot.read_i16()?;
+          f_6 = Some(val);
+        },
+        7 => {
+          let val = i_prot.read_i64()?;
+          f_7 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Log.address", &f_1)?;
+    verify_required_field_exists("Log.identity", &f_2)?;
+    verify_required_field_exists("Log.userid", &f_3)?;
+    verify_required_field_exists("Log.date", &f_4)?;
+    verify_required_field_exists("Log.request", &f_5)?;
+    verify_required_field_exists("Log.code", &f_6)?;
+    verify_required_field_exists("Log.size", &f_7)?;
+    let ret = Log {
+      address: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      identity: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      userid: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      date: f_4.expect("auto-generated code should have checked for presence of required fields"),
+      request: f_5.expect("auto-generated code should have checked for presence of required fields"),
+      code: f_6.expect("auto-generated code should have checked for presence of required fields"),
+      size: f_7.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Log");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("address", TType::Struct, 1))?;
+    self.address.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("identity", TType::String, 2))?;
+    o_prot.write_string(&self.identity)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("userid", TType::String, 3))?;
+    o_prot.write_string(&self.userid)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("date", TType::String, 4))?;
+    o_prot.write_string(&self.date)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("request", TType::String, 5))?;
+    o_prot.write_string(&self.request)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("code", TType::I16, 6))?;
+    o_prot.write_i16(self.code)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("size", TType::I64, 7))?;
+    o_prot.write_i64(self.size)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Logs
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Logs {
+  pub logs: Vec<Log>,
+}
+
+impl Logs {
+  pub fn new(logs: Vec<Log>) -> Logs {
+    Logs {
+      logs,
+    }
+  }
+}
+
+impl TSerializable for Logs {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Logs> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<Log>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Log> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_0 = Log::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_0);
+          }
+          i_prot.read_list_end()?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Logs.logs", &f_1)?;
+    let ret = Logs {
+      logs: f_1.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Logs");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("logs", TType::List, 1))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.logs.len() as i32))?;
+    for e in &self.logs {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
@@ -0,0 +1,30 @@
+//! Zero-copy layout of the log data set for `musli-zerocopy`.
+
+use musli_zerocopy::{Ref, ZeroCopy};
+
+#[derive(Clone, Copy, ZeroCopy)]
+#[repr(C)]
+pub struct Address {
+    pub x0: u8,
+    pub x1: u8,
+    pub x2: u8,
+    pub x3: u8,
+}
+
+#[derive(ZeroCopy)]
+#[repr(C)]
+pub struct Log {
+    pub address: Address,
+    pub identity: Ref<str>,
+    pub userid: Ref<str>,
+    pub date: Ref<str>,
+    pub request: Ref<str>,
+    pub code: u16,
+    pub size: u64,
+}
+
+#[derive(ZeroCopy)]
+#[repr(C)]
+pub struct Logs {
+    pub logs: Ref<[Log]>,
+}
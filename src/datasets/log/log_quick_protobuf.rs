@@ -0,0 +1,148 @@
+// Automatically generated rust module for 'log.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Address {
+    pub x0: u32,
+    pub x1: u32,
+    pub x2: u32,
+    pub x3: u32,
+}
+
+impl<'a> MessageRead<'a> for Address {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.x0 = r.read_uint32(bytes)?,
+                Ok(16) => msg.x1 = r.read_uint32(bytes)?,
+                Ok(24) => msg.x2 = r.read_uint32(bytes)?,
+                Ok(32) => msg.x3 = r.read_uint32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for Address {
+    fn get_size(&self) -> usize {
+        0
+        + if self.x0 == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.x0) as u64) }
+        + if self.x1 == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.x1) as u64) }
+        + if self.x2 == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.x2) as u64) }
+        + if self.x3 == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.x3) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.x0 != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.x0))?; }
+        if self.x1 != 0u32 { w.write_with_tag(16, |w| w.write_uint32(*&self.x1))?; }
+        if self.x2 != 0u32 { w.write_with_tag(24, |w| w.write_uint32(*&self.x2))?; }
+        if self.x3 != 0u32 { w.write_with_tag(32, |w| w.write_uint32(*&self.x3))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Log<'a> {
+    pub address: Option<log::Address>,
+    pub identity: Cow<'a, str>,
+    pub userid: Cow<'a, str>,
+    pub date: Cow<'a, str>,
+    pub request: Cow<'a, str>,
+    pub code: u32,
+    pub size: u64,
+}
+
+impl<'a> MessageRead<'a> for Log<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.address = Some(r.read_message::<log::Address>(bytes)?),
+                Ok(18) => msg.identity = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(26) => msg.userid = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(34) => msg.date = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(42) => msg.request = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(48) => msg.code = r.read_uint32(bytes)?,
+                Ok(56) => msg.size = r.read_uint64(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Log<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.address.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + if self.identity == "" { 0 } else { 1 + sizeof_len((&self.identity).len()) }
+        + if self.userid == "" { 0 } else { 1 + sizeof_len((&self.userid).len()) }
+        + if self.date == "" { 0 } else { 1 + sizeof_len((&self.date).len()) }
+        + if self.request == "" { 0 } else { 1 + sizeof_len((&self.request).len()) }
+        + if self.code == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.code) as u64) }
+        + if self.size == 0u64 { 0 } else { 1 + sizeof_varint(*(&self.size) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.address { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if self.identity != "" { w.write_with_tag(18, |w| w.write_string(&**&self.identity))?; }
+        if self.userid != "" { w.write_with_tag(26, |w| w.write_string(&**&self.userid))?; }
+        if self.date != "" { w.write_with_tag(34, |w| w.write_string(&**&self.date))?; }
+        if self.request != "" { w.write_with_tag(42, |w| w.write_string(&**&self.request))?; }
+        if self.code != 0u32 { w.write_with_tag(48, |w| w.write_uint32(*&self.code))?; }
+        if self.size != 0u64 { w.write_with_tag(56, |w| w.write_uint64(*&self.size))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Logs<'a> {
+    pub logs: Vec<log::Log<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Logs<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.logs.push(r.read_message::<log::Log>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Logs<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.logs.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.logs { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
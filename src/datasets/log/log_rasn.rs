@@ -0,0 +1,55 @@
+//! ASN.1 types for the log data set.
+//!
+//! ```asn1
+//! Log DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+//!     Address ::= SEQUENCE {
+//!         x0 INTEGER (0..255),
+//!         x1 INTEGER (0..255),
+//!         x2 INTEGER (0..255),
+//!         x3 INTEGER (0..255)
+//!     }
+//!
+//!     Log ::= SEQUENCE {
+//!         address Address,
+//!         identity UTF8String,
+//!         userid UTF8String,
+//!         date UTF8String,
+//!         request UTF8String,
+//!         code INTEGER (0..65535),
+//!         size INTEGER (0..18446744073709551615)
+//!     }
+//!
+//!     Logs ::= SEQUENCE {
+//!         logs SEQUENCE OF Log
+//!     }
+//! END
+//! ```
+
+use rasn::{AsnType, Decode, Encode};
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Address {
+    pub x0: u8,
+    pub x1: u8,
+    pub x2: u8,
+    pub x3: u8,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Log {
+    pub address: Address,
+    pub identity: String,
+    pub userid: String,
+    pub date: String,
+    pub request: String,
+    pub code: u16,
+    pub size: u64,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Logs {
+    pub logs: Vec<Log>,
+}
@@ -4,9 +4,23 @@ pub mod log_capnp;
 #[path = "log_generated.rs"]
 #[allow(unused_imports, clippy::all)]
 pub mod log_fb;
+#[cfg(feature = "musli-zerocopy")]
+pub mod log_musli_zerocopy;
 #[cfg(feature = "prost")]
 #[path = "prost.log.rs"]
 pub mod log_prost;
+#[cfg(feature = "protobuf")]
+pub mod log_protobuf;
+#[cfg(feature = "quick-protobuf")]
+pub mod log_quick_protobuf;
+#[cfg(feature = "rasn")]
+pub mod log_rasn;
+#[cfg(feature = "thrift")]
+#[path = "log.rs"]
+#[allow(clippy::all)]
+pub mod log_thrift;
+
+use std::{borrow::Cow, hint::black_box};
 
 #[cfg(feature = "flatbuffers")]
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
@@ -14,21 +28,39 @@ use flatbuffers::{FlatBufferBuilder, WIPOffset};
 pub use log_capnp as cp;
 #[cfg(feature = "flatbuffers")]
 pub use log_fb::log as fb;
+#[cfg(feature = "musli-zerocopy")]
+pub use log_musli_zerocopy as zc;
+// pb-rs qualifies the generated types with the last component of the proto package
+#[cfg(feature = "quick-protobuf")]
+use log_quick_protobuf as log;
 #[cfg(feature = "nanoserde")]
 use nanoserde::{DeBin, SerBin};
 use rand::Rng;
+use serde::de::IgnoredAny;
 #[cfg(feature = "wiring")]
 use wiring::prelude::{Unwiring, Wiring};
 
+#[cfg(feature = "apache-avro")]
+use crate::bench_avro;
 #[cfg(feature = "capnp")]
 use crate::bench_capnp;
 #[cfg(feature = "flatbuffers")]
 use crate::bench_flatbuffers;
+#[cfg(feature = "musli-zerocopy")]
+use crate::bench_musli_zerocopy;
 #[cfg(feature = "prost")]
 use crate::bench_prost;
-use crate::Generate;
+#[cfg(feature = "protobuf")]
+use crate::bench_protobuf;
+#[cfg(feature = "quick-protobuf")]
+use crate::bench_quick_protobuf;
+#[cfg(feature = "rasn")]
+use crate::bench_rasn;
+#[cfg(feature = "thrift")]
+use crate::bench_thrift;
+use crate::{Borrowable, Generate, Projectable, ReadRandom};
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Generate)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
@@ -38,6 +70,7 @@ use crate::Generate;
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -58,26 +91,19 @@ use crate::Generate;
 pub struct Address {
     #[cfg_attr(feature = "bilrost", bilrost(encoding(varint)))]
     #[cfg_attr(feature = "wiring", fixed)]
+    #[generate(range = "0..=255")]
     pub x0: u8,
     #[cfg_attr(feature = "bilrost", bilrost(encoding(varint)))]
+    #[generate(range = "0..=255")]
     pub x1: u8,
     #[cfg_attr(feature = "bilrost", bilrost(encoding(varint)))]
+    #[generate(range = "0..=255")]
     pub x2: u8,
     #[cfg_attr(feature = "bilrost", bilrost(encoding(varint)))]
+    #[generate(range = "0..=255")]
     pub x3: u8,
 }
 
-impl Generate for Address {
-    fn generate<R: Rng>(rand: &mut R) -> Self {
-        Self {
-            x0: rand.gen_range(0..=255),
-            x1: rand.gen_range(0..=255),
-            x2: rand.gen_range(0..=255),
-            x3: rand.gen_range(0..=255),
-        }
-    }
-}
-
 #[cfg(feature = "flatbuffers")]
 impl From<Address> for fb::Address {
     #[inline]
@@ -127,6 +153,141 @@ impl From<log_prost::Address> for Address {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Address {
+    type Message = log_protobuf::Address;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            x0: self.x0 as u32,
+            x1: self.x1 as u32,
+            x2: self.x2 as u32,
+            x3: self.x3 as u32,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<log_protobuf::Address> for Address {
+    fn from(value: log_protobuf::Address) -> Self {
+        Address {
+            x0: value.x0.try_into().unwrap(),
+            x1: value.x1.try_into().unwrap(),
+            x2: value.x2.try_into().unwrap(),
+            x3: value.x3.try_into().unwrap(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl bench_quick_protobuf::Serialize for Address {
+    type Message<'a> = log_quick_protobuf::Address;
+
+    #[inline]
+    fn serialize_qpb(&self) -> Self::Message<'_> {
+        Self::Message {
+            x0: self.x0 as u32,
+            x1: self.x1 as u32,
+            x2: self.x2 as u32,
+            x3: self.x3 as u32,
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<log_quick_protobuf::Address> for Address {
+    fn from(value: log_quick_protobuf::Address) -> Self {
+        Address {
+            x0: value.x0.try_into().unwrap(),
+            x1: value.x1.try_into().unwrap(),
+            x2: value.x2.try_into().unwrap(),
+            x3: value.x3.try_into().unwrap(),
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl bench_rasn::Serialize for Address {
+    type Message = log_rasn::Address;
+
+    #[inline]
+    fn serialize_rasn(&self) -> Self::Message {
+        log_rasn::Address {
+            x0: self.x0,
+            x1: self.x1,
+            x2: self.x2,
+            x3: self.x3,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<log_rasn::Address> for Address {
+    fn from(value: log_rasn::Address) -> Self {
+        Address {
+            x0: value.x0,
+            x1: value.x1,
+            x2: value.x2,
+            x3: value.x3,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Address {
+    type Message = log_thrift::Address;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            x0: self.x0 as i8,
+            x1: self.x1 as i8,
+            x2: self.x2 as i8,
+            x3: self.x3 as i8,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<log_thrift::Address> for Address {
+    fn from(value: log_thrift::Address) -> Self {
+        Address {
+            x0: value.x0 as u8,
+            x1: value.x1 as u8,
+            x2: value.x2 as u8,
+            x3: value.x3 as u8,
+        }
+    }
+}
+
+#[cfg(feature = "musli-zerocopy")]
+impl From<Address> for zc::Address {
+    #[inline]
+    fn from(value: Address) -> Self {
+        Self {
+            x0: value.x0,
+            x1: value.x1,
+            x2: value.x2,
+            x3: value.x3,
+        }
+    }
+}
+
+#[cfg(feature = "musli-zerocopy")]
+impl From<zc::Address> for Address {
+    #[inline]
+    fn from(value: zc::Address) -> Self {
+        Self {
+            x0: value.x0,
+            x1: value.x1,
+            x2: value.x2,
+            x3: value.x3,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
@@ -137,6 +298,7 @@ impl From<log_prost::Address> for Address {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -165,6 +327,8 @@ pub struct Log {
     pub size: u64,
 }
 
+// Not derived, because `date` and `request` are drawn before `address` and changing the order would
+// change the generated logs.
 impl Generate for Log {
     fn generate<R: Rng>(rand: &mut R) -> Self {
         const USERID: [&str; 9] = [
@@ -304,6 +468,176 @@ impl From<log_prost::Log> for Log {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Log {
+    type Message = log_protobuf::Log;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        log_protobuf::Log {
+            address: Some(self.address.serialize_protobuf()).into(),
+            identity: self.identity.clone(),
+            userid: self.userid.clone(),
+            date: self.date.clone(),
+            request: self.request.clone(),
+            code: self.code as u32,
+            size: self.size,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<log_protobuf::Log> for Log {
+    fn from(value: log_protobuf::Log) -> Self {
+        Log {
+            address: value.address.unwrap().into(),
+            identity: value.identity,
+            userid: value.userid,
+            date: value.date,
+            request: value.request,
+            code: value.code.try_into().unwrap(),
+            size: value.size,
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl bench_quick_protobuf::Serialize for Log {
+    type Message<'a> = log_quick_protobuf::Log<'a>;
+
+    #[inline]
+    fn serialize_qpb(&self) -> Self::Message<'_> {
+        log_quick_protobuf::Log {
+            address: Some(self.address.serialize_qpb()),
+            identity: self.identity.as_str().into(),
+            userid: self.userid.as_str().into(),
+            date: self.date.as_str().into(),
+            request: self.request.as_str().into(),
+            code: self.code as u32,
+            size: self.size,
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<log_quick_protobuf::Log<'_>> for Log {
+    fn from(value: log_quick_protobuf::Log<'_>) -> Self {
+        Log {
+            address: value.address.unwrap().into(),
+            identity: value.identity.into_owned(),
+            userid: value.userid.into_owned(),
+            date: value.date.into_owned(),
+            request: value.request.into_owned(),
+            code: value.code.try_into().unwrap(),
+            size: value.size,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl bench_rasn::Serialize for Log {
+    type Message = log_rasn::Log;
+
+    #[inline]
+    fn serialize_rasn(&self) -> Self::Message {
+        log_rasn::Log {
+            address: self.address.serialize_rasn(),
+            identity: self.identity.clone(),
+            userid: self.userid.clone(),
+            date: self.date.clone(),
+            request: self.request.clone(),
+            code: self.code,
+            size: self.size,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<log_rasn::Log> for Log {
+    fn from(value: log_rasn::Log) -> Self {
+        Log {
+            address: value.address.into(),
+            identity: value.identity,
+            userid: value.userid,
+            date: value.date,
+            request: value.request,
+            code: value.code,
+            size: value.size,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Log {
+    type Message = log_thrift::Log;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        log_thrift::Log {
+            address: self.address.serialize_thrift(),
+            identity: self.identity.clone(),
+            userid: self.userid.clone(),
+            date: self.date.clone(),
+            request: self.request.clone(),
+            code: self.code as i16,
+            size: self.size as i64,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<log_thrift::Log> for Log {
+    fn from(value: log_thrift::Log) -> Self {
+        Log {
+            address: value.address.into(),
+            identity: value.identity,
+            userid: value.userid,
+            date: value.date,
+            request: value.request,
+            code: value.code as u16,
+            size: value.size as u64,
+        }
+    }
+}
+
+#[cfg(feature = "musli-zerocopy")]
+impl bench_musli_zerocopy::Serialize for Log {
+    type Target = zc::Log;
+
+    #[inline]
+    fn serialize_musli_zerocopy(
+        &self,
+        buf: &mut musli_zerocopy::OwnedBuf,
+    ) -> Result<Self::Target, musli_zerocopy::Error> {
+        Ok(zc::Log {
+            address: self.address.into(),
+            identity: buf.store_unsized(self.identity.as_str())?,
+            userid: buf.store_unsized(self.userid.as_str())?,
+            date: buf.store_unsized(self.date.as_str())?,
+            request: buf.store_unsized(self.request.as_str())?,
+            code: self.code,
+            size: self.size,
+        })
+    }
+
+    #[inline]
+    fn deserialize_musli_zerocopy(
+        buf: &musli_zerocopy::Buf,
+        target: &Self::Target,
+    ) -> Result<Self, musli_zerocopy::Error> {
+        Ok(Log {
+            address: target.address.into(),
+            identity: buf.load(target.identity)?.into(),
+            userid: buf.load(target.userid)?.into(),
+            date: buf.load(target.date)?.into(),
+            request: buf.load(target.request)?.into(),
+            code: target.code,
+            size: target.size,
+        })
+    }
+}
+
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
@@ -314,6 +648,7 @@ impl From<log_prost::Log> for Log {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -336,6 +671,11 @@ pub struct Logs {
     pub logs: Vec<Log>,
 }
 
+#[cfg(feature = "apache-avro")]
+impl bench_avro::Schema for Logs {
+    const SCHEMA: &'static str = include_str!("log.avsc");
+}
+
 #[cfg(feature = "flatbuffers")]
 impl<'a> bench_flatbuffers::Serialize<'a> for Logs {
     type Target = fb::Logs<'a>;
@@ -393,3 +733,239 @@ impl From<log_prost::Logs> for Logs {
         }
     }
 }
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Logs {
+    type Message = log_protobuf::Logs;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        let mut result = Self::Message::default();
+        for log in self.logs.iter() {
+            result.logs.push(log.serialize_protobuf());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<log_protobuf::Logs> for Logs {
+    fn from(value: log_protobuf::Logs) -> Self {
+        Logs {
+            logs: value.logs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl bench_quick_protobuf::Serialize for Logs {
+    type Message<'a> = log_quick_protobuf::Logs<'a>;
+
+    #[inline]
+    fn serialize_qpb(&self) -> Self::Message<'_> {
+        let mut result = Self::Message::default();
+        for log in self.logs.iter() {
+            result.logs.push(log.serialize_qpb());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<log_quick_protobuf::Logs<'_>> for Logs {
+    fn from(value: log_quick_protobuf::Logs<'_>) -> Self {
+        Logs {
+            logs: value.logs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl bench_rasn::Serialize for Logs {
+    type Message = log_rasn::Logs;
+
+    #[inline]
+    fn serialize_rasn(&self) -> Self::Message {
+        log_rasn::Logs {
+            logs: self.logs.iter().map(|log| log.serialize_rasn()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<log_rasn::Logs> for Logs {
+    fn from(value: log_rasn::Logs) -> Self {
+        Logs {
+            logs: value.logs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Logs {
+    type Message = log_thrift::Logs;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        log_thrift::Logs {
+            logs: self.logs.iter().map(|log| log.serialize_thrift()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<log_thrift::Logs> for Logs {
+    fn from(value: log_thrift::Logs) -> Self {
+        Logs {
+            logs: value.logs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "musli-zerocopy")]
+impl bench_musli_zerocopy::Serialize for Logs {
+    type Target = zc::Logs;
+
+    #[inline]
+    fn serialize_musli_zerocopy(
+        &self,
+        buf: &mut musli_zerocopy::OwnedBuf,
+    ) -> Result<Self::Target, musli_zerocopy::Error> {
+        let mut logs = Vec::with_capacity(self.logs.len());
+        for log in self.logs.iter() {
+            logs.push(log.serialize_musli_zerocopy(buf)?);
+        }
+        Ok(zc::Logs {
+            logs: buf.store_slice(&logs)?,
+        })
+    }
+
+    #[inline]
+    fn deserialize_musli_zerocopy(
+        buf: &musli_zerocopy::Buf,
+        target: &Self::Target,
+    ) -> Result<Self, musli_zerocopy::Error> {
+        let mut logs = Vec::with_capacity(target.logs.len());
+        for log in buf.load(target.logs)?.iter() {
+            logs.push(Log::deserialize_musli_zerocopy(buf, log)?);
+        }
+        Ok(Logs { logs })
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct BorrowedLog<'a> {
+    pub address: Address,
+    #[serde(borrow)]
+    pub identity: Cow<'a, str>,
+    #[serde(borrow)]
+    pub userid: Cow<'a, str>,
+    #[serde(borrow)]
+    pub date: Cow<'a, str>,
+    #[serde(borrow)]
+    pub request: Cow<'a, str>,
+    pub code: u16,
+    pub size: u64,
+}
+
+impl From<BorrowedLog<'_>> for Log {
+    fn from(value: BorrowedLog<'_>) -> Self {
+        Log {
+            address: value.address,
+            identity: value.identity.into(),
+            userid: value.userid.into(),
+            date: value.date.into(),
+            request: value.request.into(),
+            code: value.code,
+            size: value.size,
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct BorrowedLogs<'a> {
+    #[serde(borrow)]
+    pub logs: Vec<BorrowedLog<'a>>,
+}
+
+impl From<BorrowedLogs<'_>> for Logs {
+    fn from(value: BorrowedLogs<'_>) -> Self {
+        Logs {
+            logs: value.logs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Borrowable for Logs {
+    type Borrowed<'de> = BorrowedLogs<'de>;
+}
+
+#[derive(serde::Deserialize)]
+pub struct ProjectedLog {
+    pub address: IgnoredAny,
+    pub identity: IgnoredAny,
+    pub userid: IgnoredAny,
+    pub date: IgnoredAny,
+    pub request: IgnoredAny,
+    pub code: u16,
+    pub size: IgnoredAny,
+}
+
+#[derive(serde::Deserialize)]
+pub struct ProjectedLogs {
+    pub logs: Vec<ProjectedLog>,
+}
+
+impl From<ProjectedLogs> for Vec<u16> {
+    fn from(value: ProjectedLogs) -> Self {
+        value.logs.into_iter().map(|log| log.code).collect()
+    }
+}
+
+impl Projectable for Logs {
+    type Fields = Vec<u16>;
+    type Projection = ProjectedLogs;
+
+    fn project(&self) -> Self::Fields {
+        self.logs.iter().map(|log| log.code).collect()
+    }
+}
+
+impl ReadRandom for Logs {
+    fn read_random(&self, indices: &[usize]) {
+        for &i in indices {
+            let log = &self.logs[i];
+            black_box(&log.address);
+            black_box(log.code);
+            black_box(log.size);
+        }
+    }
+}
+
+/// prost messages that only have `Log.code`, so decoding skips every other field by its wire type.
+#[cfg(feature = "prost")]
+pub mod projected_pb {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Log {
+        #[prost(uint32, tag = "6")]
+        pub code: u32,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Logs {
+        #[prost(message, repeated, tag = "1")]
+        pub logs: Vec<Log>,
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<projected_pb::Logs> for Vec<u16> {
+    fn from(value: projected_pb::Logs) -> Self {
+        value.logs.into_iter().map(|log| log.code as u16).collect()
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Project for Logs {
+    type Projection = projected_pb::Logs;
+}
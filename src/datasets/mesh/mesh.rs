@@ -0,0 +1,264 @@
+// Written by hand to follow the output of Thrift Compiler (0.17.0). build.rs doesn't regenerate
+// it, so keep it in step with the .thrift schema by hand.
+
+#![allow(unused_imports)]
+#![allow(unused_extern_crates)]
+#![allow(clippy::too_many_arguments, clippy::type_complexity, clippy::vec_box)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::{From, TryFrom};
+use std::default::Default;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+use thrift::OrderedFloat;
+use thrift::{ApplicationError, ApplicationErrorKind, ProtocolError, ProtocolErrorKind, TThriftClient};
+use thrift::protocol::{TFieldIdentifier, TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType, TInputProtocol, TOutputProtocol, TSerializable, TSetIdentifier, TStructIdentifier, TType};
+use thrift::protocol::field_id;
+use thrift::protocol::verify_expected_message_type;
+use thrift::protocol::verify_expected_sequence_number;
+use thrift::protocol::verify_expected_service_call;
+use thrift::protocol::verify_required_field_exists;
+use thrift::server::TProcessor;
+
+//
+// Vector3
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vector3 {
+  pub x: OrderedFloat<f64>,
+  pub y: OrderedFloat<f64>,
+  pub z: OrderedFloat<f64>,
+}
+
+impl Vector3 {
+  pub fn new(x: OrderedFloat<f64>, y: OrderedFloat<f64>, z: OrderedFloat<f64>) -> Vector3 {
+    Vector3 {
+      x,
+      y,
+      z,
+    }
+  }
+}
+
+impl TSerializable for Vector3 {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Vector3> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<OrderedFloat<f64>> = None;
+    let mut f_2: Option<OrderedFloat<f64>> = None;
+    let mut f_3: Option<OrderedFloat<f64>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_3 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Vector3.x", &f_1)?;
+    verify_required_field_exists("Vector3.y", &f_2)?;
+    verify_required_field_exists("Vector3.z", &f_3)?;
+    let ret = Vector3 {
+      x: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      y: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      z: f_3.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Vector3");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x", TType::Double, 1))?;
+    o_prot.write_double(self.x.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("y", TType::Double, 2))?;
+    o_prot.write_double(self.y.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("z", TType::Double, 3))?;
+    o_prot.write_double(self.z.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Triangle
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Triangle {
+  pub v0: Vector3,
+  pub v1: Vector3,
+  pub v2: Vector3,
+  pub normal: Vector3,
+}
+
+impl Triangle {
+  pub fn new(v0: Vector3, v1: Vector3, v2: Vector3, normal: Vector3) -> Triangle {
+    Triangle {
+      v0,
+      v1,
+      v2,
+      normal,
+    }
+  }
+}
+
+impl TSerializable for Triangle {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Triangle> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vector3> = None;
+    let mut f_2: Option<Vector3> = None;
+    let mut f_3: Option<Vector3> = None;
+    let mut f_4: Option<Vector3> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = Vector3::read_from_in_protocol(i_prot)?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = Vector3::read_from_in_protocol(i_prot)?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = Vector3::read_from_in_protocol(i_prot)?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = Vector3::read_from_in_protocol(i_prot)?;
+          f_4 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Triangle.v0", &f_1)?;
+    verify_required_field_exists("Triangle.v1", &f_2)?;
+    verify_required_field_exists("Triangle.v2", &f_3)?;
+    verify_required_field_exists("Triangle.normal", &f_4)?;
+    let ret = Triangle {
+      v0: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      v1: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      v2: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      normal: f_4.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Triangle");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("v0", TType::Struct, 1))?;
+    self.v0.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("v1", TType::Struct, 2))?;
+    self.v1.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("v2", TType::Struct, 3))?;
+    self.v2.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("normal", TType::Struct, 4))?;
+    self.normal.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Mesh
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Mesh {
+  pub triangles: Vec<Triangle>,
+}
+
+impl Mesh {
+  pub fn new(triangles: Vec<Triangle>) -> Mesh {
+    Mesh {
+      triangles,
+    }
+  }
+}
+
+impl TSerializable for Mesh {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Mesh> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<Triangle>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Triangle> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_0 = Triangle::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_0);
+          }
+          i_prot.read_list_end()?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Mesh.triangles", &f_1)?;
+    let ret = Mesh {
+      triangles: f_1.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Mesh");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("triangles", TType::List, 1))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.triangles.len() as i32))?;
+    for e in &self.triangles {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
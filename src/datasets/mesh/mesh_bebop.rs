@@ -0,0 +1,158 @@
+// Written by hand to follow the output of bebopc 3.2.3, which downloads from GitHub and so
+// couldn't be run when this was added. build.rs doesn't regenerate it, so keep it in step with the
+// .bop schema by hand.
+
+#![allow(warnings)]
+
+use ::bebop::FixedSized as _;
+use ::bebop::SubRecord as _;
+use ::core::convert::TryInto as _;
+use ::std::io::Write as _;
+
+#[derive(Clone, Debug, PartialEq, Copy)]
+#[repr(packed)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl ::bebop::FixedSized for Vector3 {}
+
+impl<'raw> ::bebop::SubRecord<'raw> for Vector3 {
+    const MIN_SERIALIZED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const EXACT_SERIALIZED_SIZE: Option<usize> = Some(Self::SERIALIZED_SIZE);
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+
+    ::bebop::define_serialize_chained!(*Self => |zelf, dest| {
+        Ok(
+            ::bebop::packed_read!(zelf.x)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.y)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.z)._serialize_chained(dest)?
+        )
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        if raw.len() - i < Self::MIN_SERIALIZED_SIZE {
+            let missing = Self::MIN_SERIALIZED_SIZE - (raw.len() - i);
+            return Err(::bebop::DeserializeError::MoreDataExpected(missing));
+        }
+
+        let (read, v0) = <f32>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v1) = <f32>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v2) = <f32>::_deserialize_chained(&raw[i..])?;
+        i += read;
+
+        Ok((
+            i,
+            Self {
+                x: v0,
+                y: v1,
+                z: v2,
+            },
+        ))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for Vector3 {}
+
+#[derive(Clone, Debug, PartialEq, Copy)]
+#[repr(packed)]
+pub struct Triangle {
+    pub v0: Vector3,
+    pub v1: Vector3,
+    pub v2: Vector3,
+    pub normal: Vector3,
+}
+
+impl ::bebop::FixedSized for Triangle {}
+
+impl<'raw> ::bebop::SubRecord<'raw> for Triangle {
+    const MIN_SERIALIZED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const EXACT_SERIALIZED_SIZE: Option<usize> = Some(Self::SERIALIZED_SIZE);
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+
+    ::bebop::define_serialize_chained!(*Self => |zelf, dest| {
+        Ok(
+            ::bebop::packed_read!(zelf.v0)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.v1)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.v2)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.normal)._serialize_chained(dest)?
+        )
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        if raw.len() - i < Self::MIN_SERIALIZED_SIZE {
+            let missing = Self::MIN_SERIALIZED_SIZE - (raw.len() - i);
+            return Err(::bebop::DeserializeError::MoreDataExpected(missing));
+        }
+
+        let (read, v0) = <Vector3>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v1) = <Vector3>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v2) = <Vector3>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v3) = <Vector3>::_deserialize_chained(&raw[i..])?;
+        i += read;
+
+        Ok((
+            i,
+            Self {
+                v0: v0,
+                v1: v1,
+                v2: v2,
+                normal: v3,
+            },
+        ))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for Triangle {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mesh<'raw> {
+    pub triangles: ::bebop::SliceWrapper<'raw, Triangle>,
+}
+
+impl<'raw> ::bebop::SubRecord<'raw> for Mesh<'raw> {
+    const MIN_SERIALIZED_SIZE: usize = <::bebop::SliceWrapper<'raw, Triangle>>::MIN_SERIALIZED_SIZE;
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.triangles.serialized_size()
+    }
+
+    ::bebop::define_serialize_chained!(Self => |zelf, dest| {
+        Ok(
+            zelf.triangles._serialize_chained(dest)?
+        )
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        if raw.len() - i < Self::MIN_SERIALIZED_SIZE {
+            let missing = Self::MIN_SERIALIZED_SIZE - (raw.len() - i);
+            return Err(::bebop::DeserializeError::MoreDataExpected(missing));
+        }
+
+        let (read, v0) = <::bebop::SliceWrapper<'raw, Triangle>>::_deserialize_chained(&raw[i..])?;
+        i += read;
+
+        Ok((i, Self { triangles: v0 }))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for Mesh<'raw> {}
@@ -0,0 +1,26 @@
+//! Zero-copy layout of the mesh data set for `musli-zerocopy`.
+
+use musli_zerocopy::{Ref, ZeroCopy};
+
+#[derive(Clone, Copy, ZeroCopy)]
+#[repr(C)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+#[derive(Clone, Copy, ZeroCopy)]
+#[repr(C)]
+pub struct Triangle {
+    pub v0: Vector3,
+    pub v1: Vector3,
+    pub v2: Vector3,
+    pub normal: Vector3,
+}
+
+#[derive(ZeroCopy)]
+#[repr(C)]
+pub struct Mesh {
+    pub triangles: Ref<[Triangle]>,
+}
@@ -0,0 +1,529 @@
+// This file is generated by rust-protobuf 3.5.1. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `datasets/mesh/mesh.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_5_1;
+
+// @@protoc_insertion_point(message:prost.mesh.Vector3)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Vector3 {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mesh.Vector3.x)
+    pub x: f32,
+    // @@protoc_insertion_point(field:prost.mesh.Vector3.y)
+    pub y: f32,
+    // @@protoc_insertion_point(field:prost.mesh.Vector3.z)
+    pub z: f32,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mesh.Vector3.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Vector3 {
+    fn default() -> &'a Vector3 {
+        <Vector3 as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Vector3 {
+    pub fn new() -> Vector3 {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "x",
+            |m: &Vector3| { &m.x },
+            |m: &mut Vector3| { &mut m.x },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "y",
+            |m: &Vector3| { &m.y },
+            |m: &mut Vector3| { &mut m.y },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "z",
+            |m: &Vector3| { &m.z },
+            |m: &mut Vector3| { &mut m.z },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Vector3>(
+            "Vector3",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Vector3 {
+    const NAME: &'static str = "Vector3";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                13 => {
+                    self.x = is.read_float()?;
+                },
+                21 => {
+                    self.y = is.read_float()?;
+                },
+                29 => {
+                    self.z = is.read_float()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.x != 0. {
+            my_size += 1 + 4;
+        }
+        if self.y != 0. {
+            my_size += 1 + 4;
+        }
+        if self.z != 0. {
+            my_size += 1 + 4;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.x != 0. {
+            os.write_float(1, self.x)?;
+        }
+        if self.y != 0. {
+            os.write_float(2, self.y)?;
+        }
+        if self.z != 0. {
+            os.write_float(3, self.z)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Vector3 {
+        Vector3::new()
+    }
+
+    fn clear(&mut self) {
+        self.x = 0.;
+        self.y = 0.;
+        self.z = 0.;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Vector3 {
+        static instance: Vector3 = Vector3 {
+            x: 0.,
+            y: 0.,
+            z: 0.,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Vector3 {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Vector3").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Vector3 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Vector3 {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.mesh.Triangle)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Triangle {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mesh.Triangle.v0)
+    pub v0: ::protobuf::MessageField<Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.Triangle.v1)
+    pub v1: ::protobuf::MessageField<Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.Triangle.v2)
+    pub v2: ::protobuf::MessageField<Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.Triangle.normal)
+    pub normal: ::protobuf::MessageField<Vector3>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mesh.Triangle.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Triangle {
+    fn default() -> &'a Triangle {
+        <Triangle as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Triangle {
+    pub fn new() -> Triangle {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Vector3>(
+            "v0",
+            |m: &Triangle| { &m.v0 },
+            |m: &mut Triangle| { &mut m.v0 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Vector3>(
+            "v1",
+            |m: &Triangle| { &m.v1 },
+            |m: &mut Triangle| { &mut m.v1 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Vector3>(
+            "v2",
+            |m: &Triangle| { &m.v2 },
+            |m: &mut Triangle| { &mut m.v2 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Vector3>(
+            "normal",
+            |m: &Triangle| { &m.normal },
+            |m: &mut Triangle| { &mut m.normal },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Triangle>(
+            "Triangle",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Triangle {
+    const NAME: &'static str = "Triangle";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v0)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v1)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v2)?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.normal)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.v0.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.v1.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.v2.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.normal.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.v0.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.v1.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.v2.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.normal.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Triangle {
+        Triangle::new()
+    }
+
+    fn clear(&mut self) {
+        self.v0.clear();
+        self.v1.clear();
+        self.v2.clear();
+        self.normal.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Triangle {
+        static instance: Triangle = Triangle {
+            v0: ::protobuf::MessageField::none(),
+            v1: ::protobuf::MessageField::none(),
+            v2: ::protobuf::MessageField::none(),
+            normal: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Triangle {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Triangle").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Triangle {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Triangle {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.mesh.Mesh)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Mesh {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mesh.Mesh.triangles)
+    pub triangles: ::std::vec::Vec<Triangle>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mesh.Mesh.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Mesh {
+    fn default() -> &'a Mesh {
+        <Mesh as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Mesh {
+    pub fn new() -> Mesh {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "triangles",
+            |m: &Mesh| { &m.triangles },
+            |m: &mut Mesh| { &mut m.triangles },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Mesh>(
+            "Mesh",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Mesh {
+    const NAME: &'static str = "Mesh";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.triangles.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.triangles {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.triangles {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Mesh {
+        Mesh::new()
+    }
+
+    fn clear(&mut self) {
+        self.triangles.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Mesh {
+        static instance: Mesh = Mesh {
+            triangles: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Mesh {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Mesh").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Mesh {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Mesh {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x18datasets/mesh/mesh.proto\x12\nprost.mesh\"3\n\x07Vector3\x12\x0c\n\
+    \x01x\x18\x01\x20\x01(\x02R\x01x\x12\x0c\n\x01y\x18\x02\x20\x01(\x02R\
+    \x01y\x12\x0c\n\x01z\x18\x03\x20\x01(\x02R\x01z\"\xa6\x01\n\x08Triangle\
+    \x12#\n\x02v0\x18\x01\x20\x01(\x0b2\x13.prost.mesh.Vector3R\x02v0\x12#\n\
+    \x02v1\x18\x02\x20\x01(\x0b2\x13.prost.mesh.Vector3R\x02v1\x12#\n\x02v2\
+    \x18\x03\x20\x01(\x0b2\x13.prost.mesh.Vector3R\x02v2\x12+\n\x06normal\
+    \x18\x04\x20\x01(\x0b2\x13.prost.mesh.Vector3R\x06normal\":\n\x04Mesh\
+    \x122\n\ttriangles\x18\x01\x20\x03(\x0b2\x14.prost.mesh.TriangleR\ttrian\
+    glesb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(3);
+            messages.push(Vector3::generated_message_descriptor_data());
+            messages.push(Triangle::generated_message_descriptor_data());
+            messages.push(Mesh::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -0,0 +1,131 @@
+// Automatically generated rust module for 'mesh.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl<'a> MessageRead<'a> for Vector3 {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(13) => msg.x = r.read_float(bytes)?,
+                Ok(21) => msg.y = r.read_float(bytes)?,
+                Ok(29) => msg.z = r.read_float(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for Vector3 {
+    fn get_size(&self) -> usize {
+        0
+        + if self.x == 0f32 { 0 } else { 1 + 4 }
+        + if self.y == 0f32 { 0 } else { 1 + 4 }
+        + if self.z == 0f32 { 0 } else { 1 + 4 }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.x != 0f32 { w.write_with_tag(13, |w| w.write_float(*&self.x))?; }
+        if self.y != 0f32 { w.write_with_tag(21, |w| w.write_float(*&self.y))?; }
+        if self.z != 0f32 { w.write_with_tag(29, |w| w.write_float(*&self.z))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Triangle {
+    pub v0: Option<mesh::Vector3>,
+    pub v1: Option<mesh::Vector3>,
+    pub v2: Option<mesh::Vector3>,
+    pub normal: Option<mesh::Vector3>,
+}
+
+impl<'a> MessageRead<'a> for Triangle {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.v0 = Some(r.read_message::<mesh::Vector3>(bytes)?),
+                Ok(18) => msg.v1 = Some(r.read_message::<mesh::Vector3>(bytes)?),
+                Ok(26) => msg.v2 = Some(r.read_message::<mesh::Vector3>(bytes)?),
+                Ok(34) => msg.normal = Some(r.read_message::<mesh::Vector3>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for Triangle {
+    fn get_size(&self) -> usize {
+        0
+        + self.v0.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.v1.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.v2.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.normal.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.v0 { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.v1 { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.v2 { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.normal { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Mesh {
+    pub triangles: Vec<mesh::Triangle>,
+}
+
+impl<'a> MessageRead<'a> for Mesh {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.triangles.push(r.read_message::<mesh::Triangle>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for Mesh {
+    fn get_size(&self) -> usize {
+        0
+        + self.triangles.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.triangles { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
@@ -0,0 +1,24 @@
+//! SimpleSerialize containers for the mesh data set.
+
+use ssz_derive::{Decode, Encode};
+
+// SSZ has no floating point types, so floats are stored as their bits.
+#[derive(Encode, Decode)]
+pub struct Vector3 {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+#[derive(Encode, Decode)]
+pub struct Triangle {
+    pub v0: Vector3,
+    pub v1: Vector3,
+    pub v2: Vector3,
+    pub normal: Vector3,
+}
+
+#[derive(Encode, Decode)]
+pub struct Mesh {
+    pub triangles: Vec<Triangle>,
+}
@@ -1,34 +1,77 @@
+#[cfg(feature = "bebop")]
+pub mod mesh_bebop;
 #[cfg(feature = "capnp")]
 pub mod mesh_capnp;
 #[cfg(feature = "flatbuffers")]
 #[path = "mesh_generated.rs"]
 #[allow(unused_imports, clippy::all)]
 pub mod mesh_fb;
+#[cfg(feature = "musli-zerocopy")]
+pub mod mesh_musli_zerocopy;
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+pub mod mesh_pod;
 #[cfg(feature = "prost")]
 #[path = "prost.mesh.rs"]
 pub mod mesh_prost;
+#[cfg(feature = "protobuf")]
+pub mod mesh_protobuf;
+#[cfg(feature = "quick-protobuf")]
+pub mod mesh_quick_protobuf;
+#[cfg(feature = "ssz")]
+pub mod mesh_ssz;
+#[cfg(feature = "thrift")]
+#[path = "mesh.rs"]
+#[allow(clippy::all)]
+pub mod mesh_thrift;
 
+use std::hint::black_box;
+
+#[cfg(feature = "bebop")]
+use bebop::{Record, SeResult, SliceWrapper};
 #[cfg(feature = "flatbuffers")]
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
 #[cfg(feature = "capnp")]
 pub use mesh_capnp as cp;
 #[cfg(feature = "flatbuffers")]
 pub use mesh_fb::mesh as fb;
+#[cfg(feature = "musli-zerocopy")]
+pub use mesh_musli_zerocopy as zc;
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+pub use mesh_pod as pod;
+// pb-rs qualifies the generated types with the last component of the proto package
+#[cfg(feature = "quick-protobuf")]
+use mesh_quick_protobuf as mesh;
 #[cfg(feature = "nanoserde")]
 use nanoserde::{DeBin, SerBin};
-use rand::Rng;
+use serde::de::IgnoredAny;
+#[cfg(feature = "thrift")]
+use thrift::OrderedFloat;
 #[cfg(feature = "wiring")]
 use wiring::prelude::{Unwiring, Wiring};
 
+#[cfg(feature = "apache-avro")]
+use crate::bench_avro;
+#[cfg(feature = "bebop")]
+use crate::bench_bebop;
 #[cfg(feature = "capnp")]
 use crate::bench_capnp;
+#[cfg(feature = "ssz")]
+use crate::bench_ethereum_ssz;
 #[cfg(feature = "flatbuffers")]
 use crate::bench_flatbuffers;
+#[cfg(feature = "musli-zerocopy")]
+use crate::bench_musli_zerocopy;
 #[cfg(feature = "prost")]
 use crate::bench_prost;
-use crate::Generate;
+#[cfg(feature = "protobuf")]
+use crate::bench_protobuf;
+#[cfg(feature = "quick-protobuf")]
+use crate::bench_quick_protobuf;
+#[cfg(feature = "thrift")]
+use crate::bench_thrift;
+use crate::{Generate, Projectable, ReadRandom};
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Generate)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
@@ -38,6 +81,7 @@ use crate::Generate;
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -62,16 +106,6 @@ pub struct Vector3 {
     pub z: f32,
 }
 
-impl Generate for Vector3 {
-    fn generate<R: Rng>(rand: &mut R) -> Self {
-        Self {
-            x: rand.gen(),
-            y: rand.gen(),
-            z: rand.gen(),
-        }
-    }
-}
-
 #[cfg(feature = "flatbuffers")]
 impl From<Vector3> for fb::Vector3 {
     #[inline]
@@ -118,7 +152,167 @@ impl From<mesh_prost::Vector3> for Vector3 {
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Vector3 {
+    type Message = mesh_protobuf::Vector3;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mesh_protobuf::Vector3> for Vector3 {
+    fn from(value: mesh_protobuf::Vector3) -> Self {
+        Vector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl bench_quick_protobuf::Serialize for Vector3 {
+    type Message<'a> = mesh_quick_protobuf::Vector3;
+
+    #[inline]
+    fn serialize_qpb(&self) -> Self::Message<'_> {
+        Self::Message {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<mesh_quick_protobuf::Vector3> for Vector3 {
+    fn from(value: mesh_quick_protobuf::Vector3) -> Self {
+        Vector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Vector3 {
+    type Message = mesh_thrift::Vector3;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            x: OrderedFloat(self.x.into()),
+            y: OrderedFloat(self.y.into()),
+            z: OrderedFloat(self.z.into()),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<mesh_thrift::Vector3> for Vector3 {
+    fn from(value: mesh_thrift::Vector3) -> Self {
+        Vector3 {
+            x: value.x.0 as f32,
+            y: value.y.0 as f32,
+            z: value.z.0 as f32,
+        }
+    }
+}
+
+#[cfg(feature = "musli-zerocopy")]
+impl From<Vector3> for zc::Vector3 {
+    #[inline]
+    fn from(value: Vector3) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(feature = "musli-zerocopy")]
+impl From<zc::Vector3> for Vector3 {
+    #[inline]
+    fn from(value: zc::Vector3) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+impl From<Vector3> for pod::Vector3 {
+    #[inline]
+    fn from(value: Vector3) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<Vector3> for mesh_bebop::Vector3 {
+    #[inline]
+    fn from(value: Vector3) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<mesh_bebop::Vector3> for Vector3 {
+    #[inline]
+    fn from(value: mesh_bebop::Vector3) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<Vector3> for mesh_ssz::Vector3 {
+    #[inline]
+    fn from(value: Vector3) -> Self {
+        Self {
+            x: value.x.to_bits(),
+            y: value.y.to_bits(),
+            z: value.z.to_bits(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<mesh_ssz::Vector3> for Vector3 {
+    #[inline]
+    fn from(value: mesh_ssz::Vector3) -> Self {
+        Self {
+            x: f32::from_bits(value.x),
+            y: f32::from_bits(value.y),
+            z: f32::from_bits(value.z),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Generate)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
@@ -128,6 +322,7 @@ impl From<mesh_prost::Vector3> for Vector3 {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -153,17 +348,6 @@ pub struct Triangle {
     pub normal: Vector3,
 }
 
-impl Generate for Triangle {
-    fn generate<R: Rng>(rand: &mut R) -> Self {
-        Self {
-            v0: Vector3::generate(rand),
-            v1: Vector3::generate(rand),
-            v2: Vector3::generate(rand),
-            normal: Vector3::generate(rand),
-        }
-    }
-}
-
 #[cfg(feature = "flatbuffers")]
 impl From<Triangle> for fb::Triangle {
     #[inline]
@@ -219,6 +403,179 @@ impl From<mesh_prost::Triangle> for Triangle {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Triangle {
+    type Message = mesh_protobuf::Triangle;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            v0: Some(self.v0.serialize_protobuf()).into(),
+            v1: Some(self.v1.serialize_protobuf()).into(),
+            v2: Some(self.v2.serialize_protobuf()).into(),
+            normal: Some(self.normal.serialize_protobuf()).into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mesh_protobuf::Triangle> for Triangle {
+    fn from(value: mesh_protobuf::Triangle) -> Self {
+        Triangle {
+            v0: value.v0.unwrap().into(),
+            v1: value.v1.unwrap().into(),
+            v2: value.v2.unwrap().into(),
+            normal: value.normal.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl bench_quick_protobuf::Serialize for Triangle {
+    type Message<'a> = mesh_quick_protobuf::Triangle;
+
+    #[inline]
+    fn serialize_qpb(&self) -> Self::Message<'_> {
+        Self::Message {
+            v0: Some(self.v0.serialize_qpb()),
+            v1: Some(self.v1.serialize_qpb()),
+            v2: Some(self.v2.serialize_qpb()),
+            normal: Some(self.normal.serialize_qpb()),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<mesh_quick_protobuf::Triangle> for Triangle {
+    fn from(value: mesh_quick_protobuf::Triangle) -> Self {
+        Triangle {
+            v0: value.v0.unwrap().into(),
+            v1: value.v1.unwrap().into(),
+            v2: value.v2.unwrap().into(),
+            normal: value.normal.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Triangle {
+    type Message = mesh_thrift::Triangle;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            v0: self.v0.serialize_thrift(),
+            v1: self.v1.serialize_thrift(),
+            v2: self.v2.serialize_thrift(),
+            normal: self.normal.serialize_thrift(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<mesh_thrift::Triangle> for Triangle {
+    fn from(value: mesh_thrift::Triangle) -> Self {
+        Triangle {
+            v0: value.v0.into(),
+            v1: value.v1.into(),
+            v2: value.v2.into(),
+            normal: value.normal.into(),
+        }
+    }
+}
+
+#[cfg(feature = "musli-zerocopy")]
+impl From<Triangle> for zc::Triangle {
+    #[inline]
+    fn from(value: Triangle) -> Self {
+        Self {
+            v0: value.v0.into(),
+            v1: value.v1.into(),
+            v2: value.v2.into(),
+            normal: value.normal.into(),
+        }
+    }
+}
+
+#[cfg(feature = "musli-zerocopy")]
+impl From<zc::Triangle> for Triangle {
+    #[inline]
+    fn from(value: zc::Triangle) -> Self {
+        Self {
+            v0: value.v0.into(),
+            v1: value.v1.into(),
+            v2: value.v2.into(),
+            normal: value.normal.into(),
+        }
+    }
+}
+
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+impl From<Triangle> for pod::Triangle {
+    #[inline]
+    fn from(value: Triangle) -> Self {
+        Self {
+            v0: value.v0.into(),
+            v1: value.v1.into(),
+            v2: value.v2.into(),
+            normal: value.normal.into(),
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<Triangle> for mesh_bebop::Triangle {
+    #[inline]
+    fn from(value: Triangle) -> Self {
+        Self {
+            v0: value.v0.into(),
+            v1: value.v1.into(),
+            v2: value.v2.into(),
+            normal: value.normal.into(),
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<mesh_bebop::Triangle> for Triangle {
+    #[inline]
+    fn from(value: mesh_bebop::Triangle) -> Self {
+        Self {
+            v0: value.v0.into(),
+            v1: value.v1.into(),
+            v2: value.v2.into(),
+            normal: value.normal.into(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<Triangle> for mesh_ssz::Triangle {
+    #[inline]
+    fn from(value: Triangle) -> Self {
+        Self {
+            v0: value.v0.into(),
+            v1: value.v1.into(),
+            v2: value.v2.into(),
+            normal: value.normal.into(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<mesh_ssz::Triangle> for Triangle {
+    #[inline]
+    fn from(value: mesh_ssz::Triangle) -> Self {
+        Self {
+            v0: value.v0.into(),
+            v1: value.v1.into(),
+            v2: value.v2.into(),
+            normal: value.normal.into(),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
@@ -229,6 +586,7 @@ impl From<mesh_prost::Triangle> for Triangle {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -251,6 +609,11 @@ pub struct Mesh {
     pub triangles: Vec<Triangle>,
 }
 
+#[cfg(feature = "apache-avro")]
+impl bench_avro::Schema for Mesh {
+    const SCHEMA: &'static str = include_str!("mesh.avsc");
+}
+
 #[cfg(feature = "flatbuffers")]
 impl<'a> bench_flatbuffers::Serialize<'a> for Mesh {
     type Target = fb::Mesh<'a>;
@@ -310,3 +673,238 @@ impl From<mesh_prost::Mesh> for Mesh {
         }
     }
 }
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Mesh {
+    type Message = mesh_protobuf::Mesh;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        let mut result = Self::Message::default();
+        for triangle in self.triangles.iter() {
+            result.triangles.push(triangle.serialize_protobuf());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mesh_protobuf::Mesh> for Mesh {
+    fn from(value: mesh_protobuf::Mesh) -> Self {
+        Mesh {
+            triangles: value.triangles.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl bench_quick_protobuf::Serialize for Mesh {
+    type Message<'a> = mesh_quick_protobuf::Mesh;
+
+    #[inline]
+    fn serialize_qpb(&self) -> Self::Message<'_> {
+        let mut result = Self::Message::default();
+        for triangle in self.triangles.iter() {
+            result.triangles.push(triangle.serialize_qpb());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<mesh_quick_protobuf::Mesh> for Mesh {
+    fn from(value: mesh_quick_protobuf::Mesh) -> Self {
+        Mesh {
+            triangles: value.triangles.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Mesh {
+    type Message = mesh_thrift::Mesh;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            triangles: self
+                .triangles
+                .iter()
+                .map(|triangle| triangle.serialize_thrift())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<mesh_thrift::Mesh> for Mesh {
+    fn from(value: mesh_thrift::Mesh) -> Self {
+        Mesh {
+            triangles: value.triangles.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "musli-zerocopy")]
+impl bench_musli_zerocopy::Serialize for Mesh {
+    type Target = zc::Mesh;
+
+    #[inline]
+    fn serialize_musli_zerocopy(
+        &self,
+        buf: &mut musli_zerocopy::OwnedBuf,
+    ) -> Result<Self::Target, musli_zerocopy::Error> {
+        // Triangles don't refer to anything else, so they can be stored back to back without
+        // collecting them first.
+        let offset = buf.next_offset::<zc::Triangle>()?;
+        for triangle in self.triangles.iter() {
+            buf.store(&zc::Triangle::from(*triangle))?;
+        }
+        Ok(zc::Mesh {
+            triangles: musli_zerocopy::Ref::with_metadata(offset, self.triangles.len()),
+        })
+    }
+
+    #[inline]
+    fn deserialize_musli_zerocopy(
+        buf: &musli_zerocopy::Buf,
+        target: &Self::Target,
+    ) -> Result<Self, musli_zerocopy::Error> {
+        Ok(Mesh {
+            triangles: buf
+                .load(target.triangles)?
+                .iter()
+                .map(|&triangle| triangle.into())
+                .collect(),
+        })
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl bench_bebop::Serialize for Mesh {
+    type Message<'raw> = mesh_bebop::Mesh<'raw>;
+
+    #[inline]
+    fn serialize_bebop<W: std::io::Write>(&self, dest: &mut W) -> SeResult<usize> {
+        let triangles: Vec<mesh_bebop::Triangle> = self
+            .triangles
+            .iter()
+            .map(|&triangle| triangle.into())
+            .collect();
+        mesh_bebop::Mesh {
+            triangles: SliceWrapper::Cooked(&triangles),
+        }
+        .serialize(dest)
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<mesh_bebop::Mesh<'_>> for Mesh {
+    fn from(value: mesh_bebop::Mesh<'_>) -> Self {
+        Mesh {
+            triangles: value.triangles.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl bench_ethereum_ssz::Serialize for Mesh {
+    type Message = mesh_ssz::Mesh;
+
+    #[inline]
+    fn serialize_ssz(&self) -> Self::Message {
+        Self::Message {
+            triangles: self
+                .triangles
+                .iter()
+                .map(|&triangle| triangle.into())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<mesh_ssz::Mesh> for Mesh {
+    fn from(value: mesh_ssz::Mesh) -> Self {
+        Mesh {
+            triangles: value.triangles.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct ProjectedTriangle {
+    pub v0: IgnoredAny,
+    pub v1: IgnoredAny,
+    pub v2: IgnoredAny,
+    pub normal: Vector3,
+}
+
+#[derive(serde::Deserialize)]
+pub struct ProjectedMesh {
+    pub triangles: Vec<ProjectedTriangle>,
+}
+
+impl From<ProjectedMesh> for Vec<Vector3> {
+    fn from(value: ProjectedMesh) -> Self {
+        value
+            .triangles
+            .into_iter()
+            .map(|triangle| triangle.normal)
+            .collect()
+    }
+}
+
+impl ReadRandom for Mesh {
+    fn read_random(&self, indices: &[usize]) {
+        for &i in indices {
+            black_box(&self.triangles[i].normal);
+        }
+    }
+}
+
+impl Projectable for Mesh {
+    type Fields = Vec<Vector3>;
+    type Projection = ProjectedMesh;
+
+    fn project(&self) -> Self::Fields {
+        self.triangles
+            .iter()
+            .map(|triangle| triangle.normal)
+            .collect()
+    }
+}
+
+/// prost messages that only have the normal of each triangle, which leaves its vertices unparsed.
+#[cfg(feature = "prost")]
+pub mod projected_pb {
+    use super::mesh_prost;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Triangle {
+        #[prost(message, optional, tag = "4")]
+        pub normal: Option<mesh_prost::Vector3>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Mesh {
+        #[prost(message, repeated, tag = "1")]
+        pub triangles: Vec<Triangle>,
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<projected_pb::Mesh> for Vec<Vector3> {
+    fn from(value: projected_pb::Mesh) -> Self {
+        value
+            .triangles
+            .into_iter()
+            .map(|triangle| triangle.normal.unwrap().into())
+            .collect()
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Project for Mesh {
+    type Projection = projected_pb::Mesh;
+}
@@ -0,0 +1,1496 @@
+// Written by hand to follow the output of Thrift Compiler (0.17.0). build.rs doesn't regenerate
+// it, so keep it in step with the .thrift schema by hand.
+
+#![allow(unused_imports)]
+#![allow(unused_extern_crates)]
+#![allow(clippy::too_many_arguments, clippy::type_complexity, clippy::vec_box)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::{From, TryFrom};
+use std::default::Default;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+use thrift::OrderedFloat;
+use thrift::{ApplicationError, ApplicationErrorKind, ProtocolError, ProtocolErrorKind, TThriftClient};
+use thrift::protocol::{TFieldIdentifier, TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType, TInputProtocol, TOutputProtocol, TSerializable, TSetIdentifier, TStructIdentifier, TType};
+use thrift::protocol::field_id;
+use thrift::protocol::verify_expected_message_type;
+use thrift::protocol::verify_expected_sequence_number;
+use thrift::protocol::verify_expected_service_call;
+use thrift::protocol::verify_required_field_exists;
+use thrift::server::TProcessor;
+
+//
+// GameType
+//
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GameType(pub i32);
+
+impl GameType {
+  pub const SURVIVAL: GameType = GameType(0);
+  pub const CREATIVE: GameType = GameType(1);
+  pub const ADVENTURE: GameType = GameType(2);
+  pub const SPECTATOR: GameType = GameType(3);
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::SURVIVAL,
+    Self::CREATIVE,
+    Self::ADVENTURE,
+    Self::SPECTATOR,
+  ];
+}
+
+impl TSerializable for GameType {
+  #[allow(clippy::trivially_copy_pass_by_ref)]
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    o_prot.write_i32(self.0)
+  }
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<GameType> {
+    let enum_value = i_prot.read_i32()?;
+    Ok(GameType::from(enum_value))
+  }
+}
+
+impl From<i32> for GameType {
+  fn from(i: i32) -> Self {
+    match i {
+      0 => GameType::SURVIVAL,
+      1 => GameType::CREATIVE,
+      2 => GameType::ADVENTURE,
+      3 => GameType::SPECTATOR,
+      _ => GameType(i)
+    }
+  }
+}
+
+impl From<&i32> for GameType {
+  fn from(i: &i32) -> Self {
+    GameType::from(*i)
+  }
+}
+
+impl From<GameType> for i32 {
+  fn from(e: GameType) -> i32 {
+    e.0
+  }
+}
+
+impl From<&GameType> for i32 {
+  fn from(e: &GameType) -> i32 {
+    e.0
+  }
+}
+
+//
+// Item
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Item {
+  pub count: i8,
+  pub slot: i8,
+  pub id: String,
+}
+
+impl Item {
+  pub fn new(count: i8, slot: i8, id: String) -> Item {
+    Item {
+      count,
+      slot,
+      id,
+    }
+  }
+}
+
+impl TSerializable for Item {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Item> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i8> = None;
+    let mut f_2: Option<i8> = None;
+    let mut f_3: Option<String> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i8()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_i8()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_string()?;
+          f_3 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Item.count", &f_1)?;
+    verify_required_field_exists("Item.slot", &f_2)?;
+    verify_required_field_exists("Item.id", &f_3)?;
+    let ret = Item {
+      count: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      slot: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      id: f_3.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Item");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("count", TType::I08, 1))?;
+    o_prot.write_i8(self.count)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("slot", TType::I08, 2))?;
+    o_prot.write_i8(self.slot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("id", TType::String, 3))?;
+    o_prot.write_string(&self.id)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Abilities
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Abilities {
+  pub walk_speed: OrderedFloat<f64>,
+  pub fly_speed: OrderedFloat<f64>,
+  pub may_fly: bool,
+  pub flying: bool,
+  pub invulnerable: bool,
+  pub may_build: bool,
+  pub instabuild: bool,
+}
+
+impl Abilities {
+  pub fn new(walk_speed: OrderedFloat<f64>, fly_speed: OrderedFloat<f64>, may_fly: bool, flying: bool, invulnerable: bool, may_build: bool, instabuild: bool) -> Abilities {
+    Abilities {
+      walk_speed,
+      fly_speed,
+      may_fly,
+      flying,
+      invulnerable,
+      may_build,
+      instabuild,
+    }
+  }
+}
+
+impl TSerializable for Abilities {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Abilities> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<OrderedFloat<f64>> = None;
+    let mut f_2: Option<OrderedFloat<f64>> = None;
+    let mut f_3: Option<bool> = None;
+    let mut f_4: Option<bool> = None;
+    let mut f_5: Option<bool> = None;
+    let mut f_6: Option<bool> = None;
+    let mut f_7: Option<bool> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_bool()?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = i_prot.read_bool()?;
+          f_4 = Some(val);
+        },
+        5 => {
+          let val = i_prot.read_bool()?;
+          f_5 = Some(val);
+        },
+        6 => {
+          let val = i_prot.read_bool()?;
+          f_6 = Some(val);
+        },
+        7 => {
+          let val = i_prot.read_bool()?;
+          f_7 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Abilities.walk_speed", &f_1)?;
+    verify_required_field_exists("Abilities.fly_speed", &f_2)?;
+    verify_required_field_exists("Abilities.may_fly", &f_3)?;
+    verify_required_field_exists("Abilities.flying", &f_4)?;
+    verify_required_field_exists("Abilities.invulnerable", &f_5)?;
+    verify_required_field_exists("Abilities.may_build", &f_6)?;
+    verify_required_field_exists("Abilities.instabuild", &f_7)?;
+    let ret = Abilities {
+      walk_speed: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      fly_speed: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      may_fly: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      flying: f_4.expect("auto-generated code should have checked for presence of required fields"),
+      invulnerable: f_5.expect("auto-generated code should have checked for presence of required fields"),
+      may_build: f_6.expect("auto-generated code should have checked for presence of required fields"),
+      instabuild: f_7.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Abilities");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("walk_speed", TType::Double, 1))?;
+    o_prot.write_double(self.walk_speed.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("fly_speed", TType::Double, 2))?;
+    o_prot.write_double(self.fly_speed.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("may_fly", TType::Bool, 3))?;
+    o_prot.write_bool(self.may_fly)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("flying", TType::Bool, 4))?;
+    o_prot.write_bool(self.flying)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("invulnerable", TType::Bool, 5))?;
+    o_prot.write_bool(self.invulnerable)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("may_build", TType::Bool, 6))?;
+    o_prot.write_bool(self.may_build)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("instabuild", TType::Bool, 7))?;
+    o_prot.write_bool(self.instabuild)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Vector3d
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vector3d {
+  pub x: OrderedFloat<f64>,
+  pub y: OrderedFloat<f64>,
+  pub z: OrderedFloat<f64>,
+}
+
+impl Vector3d {
+  pub fn new(x: OrderedFloat<f64>, y: OrderedFloat<f64>, z: OrderedFloat<f64>) -> Vector3d {
+    Vector3d {
+      x,
+      y,
+      z,
+    }
+  }
+}
+
+impl TSerializable for Vector3d {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Vector3d> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<OrderedFloat<f64>> = None;
+    let mut f_2: Option<OrderedFloat<f64>> = None;
+    let mut f_3: Option<OrderedFloat<f64>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_3 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Vector3d.x", &f_1)?;
+    verify_required_field_exists("Vector3d.y", &f_2)?;
+    verify_required_field_exists("Vector3d.z", &f_3)?;
+    let ret = Vector3d {
+      x: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      y: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      z: f_3.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Vector3d");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x", TType::Double, 1))?;
+    o_prot.write_double(self.x.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("y", TType::Double, 2))?;
+    o_prot.write_double(self.y.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("z", TType::Double, 3))?;
+    o_prot.write_double(self.z.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Vector2f
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vector2f {
+  pub x: OrderedFloat<f64>,
+  pub y: OrderedFloat<f64>,
+}
+
+impl Vector2f {
+  pub fn new(x: OrderedFloat<f64>, y: OrderedFloat<f64>) -> Vector2f {
+    Vector2f {
+      x,
+      y,
+    }
+  }
+}
+
+impl TSerializable for Vector2f {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Vector2f> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<OrderedFloat<f64>> = None;
+    let mut f_2: Option<OrderedFloat<f64>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_2 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Vector2f.x", &f_1)?;
+    verify_required_field_exists("Vector2f.y", &f_2)?;
+    let ret = Vector2f {
+      x: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      y: f_2.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Vector2f");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x", TType::Double, 1))?;
+    o_prot.write_double(self.x.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("y", TType::Double, 2))?;
+    o_prot.write_double(self.y.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Uuid
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Uuid {
+  pub x0: i32,
+  pub x1: i32,
+  pub x2: i32,
+  pub x3: i32,
+}
+
+impl Uuid {
+  pub fn new(x0: i32, x1: i32, x2: i32, x3: i32) -> Uuid {
+    Uuid {
+      x0,
+      x1,
+      x2,
+      x3,
+    }
+  }
+}
+
+impl TSerializable for Uuid {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Uuid> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i32> = None;
+    let mut f_2: Option<i32> = None;
+    let mut f_3: Option<i32> = None;
+    let mut f_4: Option<i32> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i32()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_i32()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_i32()?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = i_prot.read_i32()?;
+          f_4 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Uuid.x0", &f_1)?;
+    verify_required_field_exists("Uuid.x1", &f_2)?;
+    verify_required_field_exists("Uuid.x2", &f_3)?;
+    verify_required_field_exists("Uuid.x3", &f_4)?;
+    let ret = Uuid {
+      x0: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      x1: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      x2: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      x3: f_4.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Uuid");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x0", TType::I32, 1))?;
+    o_prot.write_i32(self.x0)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x1", TType::I32, 2))?;
+    o_prot.write_i32(self.x1)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x2", TType::I32, 3))?;
+    o_prot.write_i32(self.x2)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x3", TType::I32, 4))?;
+    o_prot.write_i32(self.x3)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Entity
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Entity {
+  pub id: String,
+  pub pos: Vector3d,
+  pub motion: Vector3d,
+  pub rotation: Vector2f,
+  pub fall_distance: OrderedFloat<f64>,
+  pub fire: i16,
+  pub air: i16,
+  pub on_ground: bool,
+  pub no_gravity: bool,
+  pub invulnerable: bool,
+  pub portal_cooldown: i32,
+  pub uuid: Uuid,
+  pub custom_name: Option<String>,
+  pub custom_name_visible: bool,
+  pub silent: bool,
+  pub glowing: bool,
+}
+
+impl Entity {
+  pub fn new<F13>(id: String, pos: Vector3d, motion: Vector3d, rotation: Vector2f, fall_distance: OrderedFloat<f64>, fire: i16, air: i16, on_ground: bool, no_gravity: bool, invulnerable: bool, portal_cooldown: i32, uuid: Uuid, custom_name: F13, custom_name_visible: bool, silent: bool, glowing: bool) -> Entity where F13: Into<Option<String>> {
+    Entity {
+      id,
+      pos,
+      motion,
+      rotation,
+      fall_distance,
+      fire,
+      air,
+      on_ground,
+      no_gravity,
+      invulnerable,
+      portal_cooldown,
+      uuid,
+      custom_name: custom_name.into(),
+      custom_name_visible,
+      silent,
+      glowing,
+    }
+  }
+}
+
+impl TSerializable for Entity {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Entity> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<String> = None;
+    let mut f_2: Option<Vector3d> = None;
+    let mut f_3: Option<Vector3d> = None;
+    let mut f_4: Option<Vector2f> = None;
+    let mut f_5: Option<OrderedFloat<f64>> = None;
+    let mut f_6: Option<i16> = None;
+    let mut f_7: Option<i16> = None;
+    let mut f_8: Option<bool> = None;
+    let mut f_9: Option<bool> = None;
+    let mut f_10: Option<bool> = None;
+    let mut f_11: Option<i32> = None;
+    let mut f_12: Option<Uuid> = None;
+    let mut f_13: Option<String> = None;
+    let mut f_14: Option<bool> = None;
+    let mut f_15: Option<bool> = None;
+    let mut f_16: Option<bool> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_string()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = Vector3d::read_from_in_protocol(i_prot)?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = Vector3d::read_from_in_protocol(i_prot)?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = Vector2f::read_from_in_protocol(i_prot)?;
+          f_4 = Some(val);
+        },
+        5 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_5 = Some(val);
+        },
+        6 => {
+          let val = i_prot.read_i16()?;
+          f_6 = Some(val);
+        },
+        7 => {
+          let val = i_prot.read_i16()?;
+          f_7 = Some(val);
+        },
+        8 => {
+          let val = i_prot.read_bool()?;
+          f_8 = Some(val);
+        },
+        9 => {
+          let val = i_prot.read_bool()?;
+          f_9 = Some(val);
+        },
+        10 => {
+          let val = i_prot.read_bool()?;
+          f_10 = Some(val);
+        },
+        11 => {
+          let val = i_prot.read_i32()?;
+          f_11 = Some(val);
+        },
+        12 => {
+          let val = Uuid::read_from_in_protocol(i_prot)?;
+          f_12 = Some(val);
+        },
+        13 => {
+          let val = i_prot.read_string()?;
+          f_13 = Some(val);
+        },
+        14 => {
+          let val = i_prot.read_bool()?;
+          f_14 = Some(val);
+        },
+        15 => {
+          let val = i_prot.read_bool()?;
+          f_15 = Some(val);
+        },
+        16 => {
+          let val = i_prot.read_bool()?;
+          f_16 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Entity.id", &f_1)?;
+    verify_required_field_exists("Entity.pos", &f_2)?;
+    verify_required_field_exists("Entity.motion", &f_3)?;
+    verify_required_field_exists("Entity.rotation", &f_4)?;
+    verify_required_field_exists("Entity.fall_distance", &f_5)?;
+    verify_required_field_exists("Entity.fire", &f_6)?;
+    verify_required_field_exists("Entity.air", &f_7)?;
+    verify_required_field_exists("Entity.on_ground", &f_8)?;
+    verify_required_field_exists("Entity.no_gravity", &f_9)?;
+    verify_required_field_exists("Entity.invulnerable", &f_10)?;
+    verify_required_field_exists("Entity.portal_cooldown", &f_11)?;
+    verify_required_field_exists("Entity.uuid", &f_12)?;
+    verify_required_field_exists("Entity.custom_name_visible", &f_14)?;
+    verify_required_field_exists("Entity.silent", &f_15)?;
+    verify_required_field_exists("Entity.glowing", &f_16)?;
+    let ret = Entity {
+      id: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      pos: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      motion: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      rotation: f_4.expect("auto-generated code should have checked for presence of required fields"),
+      fall_distance: f_5.expect("auto-generated code should have checked for presence of required fields"),
+      fire: f_6.expect("auto-generated code should have checked for presence of required fields"),
+      air: f_7.expect("auto-generated code should have checked for presence of required fields"),
+      on_ground: f_8.expect("auto-generated code should have checked for presence of required fields"),
+      no_gravity: f_9.expect("auto-generated code should have checked for presence of required fields"),
+      invulnerable: f_10.expect("auto-generated code should have checked for presence of required fields"),
+      portal_cooldown: f_11.expect("auto-generated code should have checked for presence of required fields"),
+      uuid: f_12.expect("auto-generated code should have checked for presence of required fields"),
+      custom_name: f_13,
+      custom_name_visible: f_14.expect("auto-generated code should have checked for presence of required fields"),
+      silent: f_15.expect("auto-generated code should have checked for presence of required fields"),
+      glowing: f_16.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Entity");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("id", TType::String, 1))?;
+    o_prot.write_string(&self.id)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("pos", TType::Struct, 2))?;
+    self.pos.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("motion", TType::Struct, 3))?;
+    self.motion.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("rotation", TType::Struct, 4))?;
+    self.rotation.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("fall_distance", TType::Double, 5))?;
+    o_prot.write_double(self.fall_distance.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("fire", TType::I16, 6))?;
+    o_prot.write_i16(self.fire)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("air", TType::I16, 7))?;
+    o_prot.write_i16(self.air)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("on_ground", TType::Bool, 8))?;
+    o_prot.write_bool(self.on_ground)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("no_gravity", TType::Bool, 9))?;
+    o_prot.write_bool(self.no_gravity)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("invulnerable", TType::Bool, 10))?;
+    o_prot.write_bool(self.invulnerable)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("portal_cooldown", TType::I32, 11))?;
+    o_prot.write_i32(self.portal_cooldown)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("uuid", TType::Struct, 12))?;
+    self.uuid.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    if let Some(ref fld_var) = self.custom_name {
+      o_prot.write_field_begin(&TFieldIdentifier::new("custom_name", TType::String, 13))?;
+      o_prot.write_string(fld_var)?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_begin(&TFieldIdentifier::new("custom_name_visible", TType::Bool, 14))?;
+    o_prot.write_bool(self.custom_name_visible)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("silent", TType::Bool, 15))?;
+    o_prot.write_bool(self.silent)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("glowing", TType::Bool, 16))?;
+    o_prot.write_bool(self.glowing)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// RecipeBook
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RecipeBook {
+  pub recipes: Vec<String>,
+  pub to_be_displayed: Vec<String>,
+  pub is_filtering_craftable: bool,
+  pub is_gui_open: bool,
+  pub is_furnace_filtering_craftable: bool,
+  pub is_furnace_gui_open: bool,
+  pub is_blasting_furnace_filtering_craftable: bool,
+  pub is_blasting_furnace_gui_open: bool,
+  pub is_smoker_filtering_craftable: bool,
+  pub is_smoker_gui_open: bool,
+}
+
+impl RecipeBook {
+  pub fn new(recipes: Vec<String>, to_be_displayed: Vec<String>, is_filtering_craftable: bool, is_gui_open: bool, is_furnace_filtering_craftable: bool, is_furnace_gui_open: bool, is_blasting_furnace_filtering_craftable: bool, is_blasting_furnace_gui_open: bool, is_smoker_filtering_craftable: bool, is_smoker_gui_open: bool) -> RecipeBook {
+    RecipeBook {
+      recipes,
+      to_be_displayed,
+      is_filtering_craftable,
+      is_gui_open,
+      is_furnace_filtering_craftable,
+      is_furnace_gui_open,
+      is_blasting_furnace_filtering_craftable,
+      is_blasting_furnace_gui_open,
+      is_smoker_filtering_craftable,
+      is_smoker_gui_open,
+    }
+  }
+}
+
+impl TSerializable for RecipeBook {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<RecipeBook> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<String>> = None;
+    let mut f_2: Option<Vec<String>> = None;
+    let mut f_3: Option<bool> = None;
+    let mut f_4: Option<bool> = None;
+    let mut f_5: Option<bool> = None;
+    let mut f_6: Option<bool> = None;
+    let mut f_7: Option<bool> = None;
+    let mut f_8: Option<bool> = None;
+    let mut f_9: Option<bool> = None;
+    let mut f_10: Option<bool> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<String> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_0 = i_prot.read_string()?;
+            val.push(list_elem_0);
+          }
+          i_prot.read_list_end()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<String> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_1 = i_prot.read_string()?;
+            val.push(list_elem_1);
+          }
+          i_prot.read_list_end()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_bool()?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = i_prot.read_bool()?;
+          f_4 = Some(val);
+        },
+        5 => {
+          let val = i_prot.read_bool()?;
+          f_5 = Some(val);
+        },
+        6 => {
+          let val = i_prot.read_bool()?;
+          f_6 = Some(val);
+        },
+        7 => {
+          let val = i_prot.read_bool()?;
+          f_7 = Some(val);
+        },
+        8 => {
+          let val = i_prot.read_bool()?;
+          f_8 = Some(val);
+        },
+        9 => {
+          let val = i_prot.read_bool()?;
+          f_9 = Some(val);
+        },
+        10 => {
+          let val = i_prot.read_bool()?;
+          f_10 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("RecipeBook.recipes", &f_1)?;
+    verify_required_field_exists("RecipeBook.to_be_displayed", &f_2)?;
+    verify_required_field_exists("RecipeBook.is_filtering_craftable", &f_3)?;
+    verify_required_field_exists("RecipeBook.is_gui_open", &f_4)?;
+    verify_required_field_exists("RecipeBook.is_furnace_filtering_craftable", &f_5)?;
+    verify_required_field_exists("RecipeBook.is_furnace_gui_open", &f_6)?;
+    verify_required_field_exists("RecipeBook.is_blasting_furnace_filtering_craftable", &f_7)?;
+    verify_required_field_exists("RecipeBook.is_blasting_furnace_gui_open", &f_8)?;
+    verify_required_field_exists("RecipeBook.is_smoker_filtering_craftable", &f_9)?;
+    verify_required_field_exists("RecipeBook.is_smoker_gui_open", &f_10)?;
+    let ret = RecipeBook {
+      recipes: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      to_be_displayed: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      is_filtering_craftable: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      is_gui_open: f_4.expect("auto-generated code should have checked for presence of required fields"),
+      is_furnace_filtering_craftable: f_5.expect("auto-generated code should have checked for presence of required fields"),
+      is_furnace_gui_open: f_6.expect("auto-generated code should have checked for presence of required fields"),
+      is_blasting_furnace_filtering_craftable: f_7.expect("auto-generated code should have checked for presence of required fields"),
+      is_blasting_furnace_gui_open: f_8.expect("auto-generated code should have checked for presence of required fields"),
+      is_smoker_filtering_craftable: f_9.expect("auto-generated code should have checked for presence of required fields"),
+      is_smoker_gui_open: f_10.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("RecipeBook");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("recipes", TType::List, 1))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::String, self.recipes.len() as i32))?;
+    for e in &self.recipes {
+      o_prot.write_string(e)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("to_be_displayed", TType::List, 2))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::String, self.to_be_displayed.len() as i32))?;
+    for e in &self.to_be_displayed {
+      o_prot.write_string(e)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("is_filtering_craftable", TType::Bool, 3))?;
+    o_prot.write_bool(self.is_filtering_craftable)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("is_gui_open", TType::Bool, 4))?;
+    o_prot.write_bool(self.is_gui_open)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("is_furnace_filtering_craftable", TType::Bool, 5))?;
+    o_prot.write_bool(self.is_furnace_filtering_craftable)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("is_furnace_gui_open", TType::Bool, 6))?;
+    o_prot.write_bool(self.is_furnace_gui_open)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("is_blasting_furnace_filtering_craftable", TType::Bool, 7))?;
+    o_prot.write_bool(self.is_blasting_furnace_filtering_craftable)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("is_blasting_furnace_gui_open", TType::Bool, 8))?;
+    o_prot.write_bool(self.is_blasting_furnace_gui_open)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("is_smoker_filtering_craftable", TType::Bool, 9))?;
+    o_prot.write_bool(self.is_smoker_filtering_craftable)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("is_smoker_gui_open", TType::Bool, 10))?;
+    o_prot.write_bool(self.is_smoker_gui_open)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Vehicle
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vehicle {
+  pub uuid: Uuid,
+  pub entity: Entity,
+}
+
+impl Vehicle {
+  pub fn new(uuid: Uuid, entity: Entity) -> Vehicle {
+    Vehicle {
+      uuid,
+      entity,
+    }
+  }
+}
+
+impl TSerializable for Vehicle {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Vehicle> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Uuid> = None;
+    let mut f_2: Option<Entity> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = Uuid::read_from_in_protocol(i_prot)?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = Entity::read_from_in_protocol(i_prot)?;
+          f_2 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Vehicle.uuid", &f_1)?;
+    verify_required_field_exists("Vehicle.entity", &f_2)?;
+    let ret = Vehicle {
+      uuid: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      entity: f_2.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Vehicle");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("uuid", TType::Struct, 1))?;
+    self.uuid.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("entity", TType::Struct, 2))?;
+    self.entity.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Player
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Player {
+  pub game_type: GameType,
+  pub previous_game_type: GameType,
+  pub score: i64,
+  pub dimension: String,
+  pub selected_item_slot: i32,
+  pub selected_item: Item,
+  pub spawn_dimension: Option<String>,
+  pub spawn_x: i64,
+  pub spawn_y: i64,
+  pub spawn_z: i64,
+  pub spawn_forced: Option<bool>,
+  pub sleep_timer: i16,
+  pub food_exhaustion_level: OrderedFloat<f64>,
+  pub food_saturation_level: OrderedFloat<f64>,
+  pub food_tick_timer: i32,
+  pub xp_level: i32,
+  pub xp_p: OrderedFloat<f64>,
+  pub xp_total: i32,
+  pub xp_seed: i32,
+  pub inventory: Vec<Item>,
+  pub ender_items: Vec<Item>,
+  pub abilities: Abilities,
+  pub entered_nether_position: Option<Vector3d>,
+  pub root_vehicle: Option<Vehicle>,
+  pub shoulder_entity_left: Option<Entity>,
+  pub shoulder_entity_right: Option<Entity>,
+  pub seen_credits: bool,
+  pub recipe_book: RecipeBook,
+}
+
+impl Player {
+  pub fn new<F7, F11, F23, F24, F25, F26>(game_type: GameType, previous_game_type: GameType, score: i64, dimension: String, selected_item_slot: i32, selected_item: Item, spawn_dimension: F7, spawn_x: i64, spawn_y: i64, spawn_z: i64, spawn_forced: F11, sleep_timer: i16, food_exhaustion_level: OrderedFloat<f64>, food_saturation_level: OrderedFloat<f64>, food_tick_timer: i32, xp_level: i32, xp_p: OrderedFloat<f64>, xp_total: i32, xp_seed: i32, inventory: Vec<Item>, ender_items: Vec<Item>, abilities: Abilities, entered_nether_position: F23, root_vehicle: F24, shoulder_entity_left: F25, shoulder_entity_right: F26, seen_credits: bool, recipe_book: RecipeBook) -> Player where F7: Into<Option<String>>, F11: Into<Option<bool>>, F23: Into<Option<Vector3d>>, F24: Into<Option<Vehicle>>, F25: Into<Option<Entity>>, F26: Into<Option<Entity>> {
+    Player {
+      game_type,
+      previous_game_type,
+      score,
+      dimension,
+      selected_item_slot,
+      selected_item,
+      spawn_dimension: spawn_dimension.into(),
+      spawn_x,
+      spawn_y,
+      spawn_z,
+      spawn_forced: spawn_forced.into(),
+      sleep_timer,
+      food_exhaustion_level,
+      food_saturation_level,
+      food_tick_timer,
+      xp_level,
+      xp_p,
+      xp_total,
+      xp_seed,
+      inventory,
+      ender_items,
+      abilities,
+      entered_nether_position: entered_nether_position.into(),
+      root_vehicle: root_vehicle.into(),
+      shoulder_entity_left: shoulder_entity_left.into(),
+      shoulder_entity_right: shoulder_entity_right.into(),
+      seen_credits,
+      recipe_book,
+    }
+  }
+}
+
+impl TSerializable for Player {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Player> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<GameType> = None;
+    let mut f_2: Option<GameType> = None;
+    let mut f_3: Option<i64> = None;
+    let mut f_4: Option<String> = None;
+    let mut f_5: Option<i32> = None;
+    let mut f_6: Option<Item> = None;
+    let mut f_7: Option<String> = None;
+    let mut f_8: Option<i64> = None;
+    let mut f_9: Option<i64> = None;
+    let mut f_10: Option<i64> = None;
+    let mut f_11: Option<bool> = None;
+    let mut f_12: Option<i16> = None;
+    let mut f_13: Option<OrderedFloat<f64>> = None;
+    let mut f_14: Option<OrderedFloat<f64>> = None;
+    let mut f_15: Option<i32> = None;
+    let mut f_16: Option<i32> = None;
+    let mut f_17: Option<OrderedFloat<f64>> = None;
+    let mut f_18: Option<i32> = None;
+    let mut f_19: Option<i32> = None;
+    let mut f_20: Option<Vec<Item>> = None;
+    let mut f_21: Option<Vec<Item>> = None;
+    let mut f_22: Option<Abilities> = None;
+    let mut f_23: Option<Vector3d> = None;
+    let mut f_24: Option<Vehicle> = None;
+    let mut f_25: Option<Entity> = None;
+    let mut f_26: Option<Entity> = None;
+    let mut f_27: Option<bool> = None;
+    let mut f_28: Option<RecipeBook> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = GameType::read_from_in_protocol(i_prot)?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = GameType::read_from_in_protocol(i_prot)?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_i64()?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = i_prot.read_string()?;
+          f_4 = Some(val);
+        },
+        5 => {
+          let val = i_prot.read_i32()?;
+          f_5 = Some(val);
+        },
+        6 => {
+          let val = Item::read_from_in_protocol(i_prot)?;
+          f_6 = Some(val);
+        },
+        7 => {
+          let val = i_prot.read_string()?;
+          f_7 = Some(val);
+        },
+        8 => {
+          let val = i_prot.read_i64()?;
+          f_8 = Some(val);
+        },
+        9 => {
+          let val = i_prot.read_i64()?;
+          f_9 = Some(val);
+        },
+        10 => {
+          let val = i_prot.read_i64()?;
+          f_10 = Some(val);
+        },
+        11 => {
+          let val = i_prot.read_bool()?;
+          f_11 = Some(val);
+        },
+        12 => {
+          let val = i_prot.read_i16()?;
+          f_12 = Some(val);
+        },
+        13 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_13 = Some(val);
+        },
+        14 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_14 = Some(val);
+        },
+        15 => {
+          let val = i_prot.read_i32()?;
+          f_15 = Some(val);
+        },
+        16 => {
+          let val = i_prot.read_i32()?;
+          f_16 = Some(val);
+        },
+        17 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_17 = Some(val);
+        },
+        18 => {
+          let val = i_prot.read_i32()?;
+          f_18 = Some(val);
+        },
+        19 => {
+          let val = i_prot.read_i32()?;
+          f_19 = Some(val);
+        },
+        20 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Item> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_2 = Item::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_2);
+          }
+          i_prot.read_list_end()?;
+          f_20 = Some(val);
+        },
+        21 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Item> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_3 = Item::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_3);
+          }
+          i_prot.read_list_end()?;
+          f_21 = Some(val);
+        },
+        22 => {
+          let val = Abilities::read_from_in_protocol(i_prot)?;
+          f_22 = Some(val);
+        },
+        23 => {
+          let val = Vector3d::read_from_in_protocol(i_prot)?;
+          f_23 = Some(val);
+        },
+        24 => {
+          let val = Vehicle::read_from_in_protocol(i_prot)?;
+          f_24 = Some(val);
+        },
+        25 => {
+          let val = Entity::read_from_in_protocol(i_prot)?;
+          f_25 = Some(val);
+        },
+        26 => {
+          let val = Entity::read_from_in_protocol(i_prot)?;
+          f_26 = Some(val);
+        },
+        27 => {
+          let val = i_prot.read_bool()?;
+          f_27 = Some(val);
+        },
+        28 => {
+          let val = RecipeBook::read_from_in_protocol(i_prot)?;
+          f_28 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Player.game_type", &f_1)?;
+    verify_required_field_exists("Player.previous_game_type", &f_2)?;
+    verify_required_field_exists("Player.score", &f_3)?;
+    verify_required_field_exists("Player.dimension", &f_4)?;
+    verify_required_field_exists("Player.selected_item_slot", &f_5)?;
+    verify_required_field_exists("Player.selected_item", &f_6)?;
+    verify_required_field_exists("Player.spawn_x", &f_8)?;
+    verify_required_field_exists("Player.spawn_y", &f_9)?;
+    verify_required_field_exists("Player.spawn_z", &f_10)?;
+    verify_required_field_exists("Player.sleep_timer", &f_12)?;
+    verify_required_field_exists("Player.food_exhaustion_level", &f_13)?;
+    verify_required_field_exists("Player.food_saturation_level", &f_14)?;
+    verify_required_field_exists("Player.food_tick_timer", &f_15)?;
+    verify_required_field_exists("Player.xp_level", &f_16)?;
+    verify_required_field_exists("Player.xp_p", &f_17)?;
+    verify_required_field_exists("Player.xp_total", &f_18)?;
+    verify_required_field_exists("Player.xp_seed", &f_19)?;
+    verify_required_field_exists("Player.inventory", &f_20)?;
+    verify_required_field_exists("Player.ender_items", &f_21)?;
+    verify_required_field_exists("Player.abilities", &f_22)?;
+    verify_required_field_exists("Player.seen_credits", &f_27)?;
+    verify_required_field_exists("Player.recipe_book", &f_28)?;
+    let ret = Player {
+      game_type: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      previous_game_type: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      score: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      dimension: f_4.expect("auto-generated code should have checked for presence of required fields"),
+      selected_item_slot: f_5.expect("auto-generated code should have checked for presence of required fields"),
+      selected_item: f_6.expect("auto-generated code should have checked for presence of required fields"),
+      spawn_dimension: f_7,
+      spawn_x: f_8.expect("auto-generated code should have checked for presence of required fields"),
+      spawn_y: f_9.expect("auto-generated code should have checked for presence of required fields"),
+      spawn_z: f_10.expect("auto-generated code should have checked for presence of required fields"),
+      spawn_forced: f_11,
+      sleep_timer: f_12.expect("auto-generated code should have checked for presence of required fields"),
+      food_exhaustion_level: f_13.expect("auto-generated code should have checked for presence of required fields"),
+      food_saturation_level: f_14.expect("auto-generated code should have checked for presence of required fields"),
+      food_tick_timer: f_15.expect("auto-generated code should have checked for presence of required fields"),
+      xp_level: f_16.expect("auto-generated code should have checked for presence of required fields"),
+      xp_p: f_17.expect("auto-generated code should have checked for presence of required fields"),
+      xp_total: f_18.expect("auto-generated code should have checked for presence of required fields"),
+      xp_seed: f_19.expect("auto-generated code should have checked for presence of required fields"),
+      inventory: f_20.expect("auto-generated code should have checked for presence of required fields"),
+      ender_items: f_21.expect("auto-generated code should have checked for presence of required fields"),
+      abilities: f_22.expect("auto-generated code should have checked for presence of required fields"),
+      entered_nether_position: f_23,
+      root_vehicle: f_24,
+      shoulder_entity_left: f_25,
+      shoulder_entity_right: f_26,
+      seen_credits: f_27.expect("auto-generated code should have checked for presence of required fields"),
+      recipe_book: f_28.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Player");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("game_type", TType::I32, 1))?;
+    self.game_type.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("previous_game_type", TType::I32, 2))?;
+    self.previous_game_type.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("score", TType::I64, 3))?;
+    o_prot.write_i64(self.score)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("dimension", TType::String, 4))?;
+    o_prot.write_string(&self.dimension)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("selected_item_slot", TType::I32, 5))?;
+    o_prot.write_i32(self.selected_item_slot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("selected_item", TType::Struct, 6))?;
+    self.selected_item.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    if let Some(ref fld_var) = self.spawn_dimension {
+      o_prot.write_field_begin(&TFieldIdentifier::new("spawn_dimension", TType::String, 7))?;
+      o_prot.write_string(fld_var)?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_begin(&TFieldIdentifier::new("spawn_x", TType::I64, 8))?;
+    o_prot.write_i64(self.spawn_x)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("spawn_y", TType::I64, 9))?;
+    o_prot.write_i64(self.spawn_y)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("spawn_z", TType::I64, 10))?;
+    o_prot.write_i64(self.spawn_z)?;
+    o_prot.write_field_end()?;
+    if let Some(fld_var) = self.spawn_forced {
+      o_prot.write_field_begin(&TFieldIdentifier::new("spawn_forced", TType::Bool, 11))?;
+      o_prot.write_bool(fld_var)?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_begin(&TFieldIdentifier::new("sleep_timer", TType::I16, 12))?;
+    o_prot.write_i16(self.sleep_timer)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("food_exhaustion_level", TType::Double, 13))?;
+    o_prot.write_double(self.food_exhaustion_level.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("food_saturation_level", TType::Double, 14))?;
+    o_prot.write_double(self.food_saturation_level.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("food_tick_timer", TType::I32, 15))?;
+    o_prot.write_i32(self.food_tick_timer)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("xp_level", TType::I32, 16))?;
+    o_prot.write_i32(self.xp_level)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("xp_p", TType::Double, 17))?;
+    o_prot.write_double(self.xp_p.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("xp_total", TType::I32, 18))?;
+    o_prot.write_i32(self.xp_total)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("xp_seed", TType::I32, 19))?;
+    o_prot.write_i32(self.xp_seed)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("inventory", TType::List, 20))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.inventory.len() as i32))?;
+    for e in &self.inventory {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("ender_items", TType::List, 21))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.ender_items.len() as i32))?;
+    for e in &self.ender_items {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("abilities", TType::Struct, 22))?;
+    self.abilities.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    if let Some(ref fld_var) = self.entered_nether_position {
+      o_prot.write_field_begin(&TFieldIdentifier::new("entered_nether_position", TType::Struct, 23))?;
+      fld_var.write_to_out_protocol(o_prot)?;
+      o_prot.write_field_end()?
+    }
+    if let Some(ref fld_var) = self.root_vehicle {
+      o_prot.write_field_begin(&TFieldIdentifier::new("root_vehicle", TType::Struct, 24))?;
+      fld_var.write_to_out_protocol(o_prot)?;
+      o_prot.write_field_end()?
+    }
+    if let Some(ref fld_var) = self.shoulder_entity_left {
+      o_prot.write_field_begin(&TFieldIdentifier::new("shoulder_entity_left", TType::Struct, 25))?;
+      fld_var.write_to_out_protocol(o_prot)?;
+      o_prot.write_field_end()?
+    }
+    if let Some(ref fld_var) = self.shoulder_entity_right {
+      o_prot.write_field_begin(&TFieldIdentifier::new("shoulder_entity_right", TType::Struct, 26))?;
+      fld_var.write_to_out_protocol(o_prot)?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_begin(&TFieldIdentifier::new("seen_credits", TType::Bool, 27))?;
+    o_prot.write_bool(self.seen_credits)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("recipe_book", TType::Struct, 28))?;
+    self.recipe_book.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Players
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Players {
+  pub players: Vec<Player>,
+}
+
+impl Players {
+  pub fn new(players: Vec<Player>) -> Players {
+    Players {
+      players,
+    }
+  }
+}
+
+impl TSerializable for Players {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Players> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<Player>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Player> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_4 = Player::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_4);
+          }
+          i_prot.read_list_end()?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Players.players", &f_1)?;
+    let ret = Players {
+      players: f_1.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Players");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("players", TType::List, 1))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.players.len() as i32))?;
+    for e in &self.players {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
@@ -0,0 +1,238 @@
+//! ASN.1 types for the minecraft_savedata data set.
+//!
+//! rasn doesn't support `REAL`, so floats are stored as the `INTEGER` value of their bits.
+//!
+//! ```asn1
+//! MinecraftSavedata DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+//!     GameType ::= ENUMERATED { survival, creative, adventure, spectator }
+//!
+//!     Item ::= SEQUENCE {
+//!         count INTEGER (-128..127),
+//!         slot INTEGER (0..255),
+//!         id UTF8String
+//!     }
+//!
+//!     Abilities ::= SEQUENCE {
+//!         walkSpeed INTEGER (0..4294967295),
+//!         flySpeed INTEGER (0..4294967295),
+//!         mayFly BOOLEAN,
+//!         flying BOOLEAN,
+//!         invulnerable BOOLEAN,
+//!         mayBuild BOOLEAN,
+//!         instabuild BOOLEAN
+//!     }
+//!
+//!     Vector3d ::= SEQUENCE {
+//!         x INTEGER (0..18446744073709551615),
+//!         y INTEGER (0..18446744073709551615),
+//!         z INTEGER (0..18446744073709551615)
+//!     }
+//!
+//!     Vector2f ::= SEQUENCE {
+//!         x INTEGER (0..4294967295),
+//!         y INTEGER (0..4294967295)
+//!     }
+//!
+//!     Uuid ::= SEQUENCE (SIZE (4)) OF INTEGER (0..4294967295)
+//!
+//!     Entity ::= SEQUENCE {
+//!         id UTF8String,
+//!         pos Vector3d,
+//!         motion Vector3d,
+//!         rotation Vector2f,
+//!         fallDistance INTEGER (0..4294967295),
+//!         fire INTEGER (0..65535),
+//!         air INTEGER (0..65535),
+//!         onGround BOOLEAN,
+//!         noGravity BOOLEAN,
+//!         invulnerable BOOLEAN,
+//!         portalCooldown INTEGER (-2147483648..2147483647),
+//!         uuid Uuid,
+//!         customName UTF8String OPTIONAL,
+//!         customNameVisible BOOLEAN,
+//!         silent BOOLEAN,
+//!         glowing BOOLEAN
+//!     }
+//!
+//!     RecipeBook ::= SEQUENCE {
+//!         recipes SEQUENCE OF UTF8String,
+//!         toBeDisplayed SEQUENCE OF UTF8String,
+//!         isFilteringCraftable BOOLEAN,
+//!         isGuiOpen BOOLEAN,
+//!         isFurnaceFilteringCraftable BOOLEAN,
+//!         isFurnaceGuiOpen BOOLEAN,
+//!         isBlastingFurnaceFilteringCraftable BOOLEAN,
+//!         isBlastingFurnaceGuiOpen BOOLEAN,
+//!         isSmokerFilteringCraftable BOOLEAN,
+//!         isSmokerGuiOpen BOOLEAN
+//!     }
+//!
+//!     Vehicle ::= SEQUENCE {
+//!         uuid Uuid,
+//!         entity Entity
+//!     }
+//!
+//!     Player ::= SEQUENCE {
+//!         gameType GameType,
+//!         previousGameType GameType,
+//!         score INTEGER (-9223372036854775808..9223372036854775807),
+//!         dimension UTF8String,
+//!         selectedItemSlot INTEGER (0..4294967295),
+//!         selectedItem Item,
+//!         spawnDimension UTF8String OPTIONAL,
+//!         spawnX INTEGER (-9223372036854775808..9223372036854775807),
+//!         spawnY INTEGER (-9223372036854775808..9223372036854775807),
+//!         spawnZ INTEGER (-9223372036854775808..9223372036854775807),
+//!         spawnForced BOOLEAN OPTIONAL,
+//!         sleepTimer INTEGER (0..65535),
+//!         foodExhaustionLevel INTEGER (0..4294967295),
+//!         foodSaturationLevel INTEGER (0..4294967295),
+//!         foodTickTimer INTEGER (0..4294967295),
+//!         xpLevel INTEGER (0..4294967295),
+//!         xpP INTEGER (0..4294967295),
+//!         xpTotal INTEGER (-2147483648..2147483647),
+//!         xpSeed INTEGER (-2147483648..2147483647),
+//!         inventory SEQUENCE OF Item,
+//!         enderItems SEQUENCE OF Item,
+//!         abilities Abilities,
+//!         enteredNetherPosition Vector3d OPTIONAL,
+//!         rootVehicle Vehicle OPTIONAL,
+//!         shoulderEntityLeft Entity OPTIONAL,
+//!         shoulderEntityRight Entity OPTIONAL,
+//!         seenCredits BOOLEAN,
+//!         recipeBook RecipeBook
+//!     }
+//!
+//!     Players ::= SEQUENCE {
+//!         players SEQUENCE OF Player
+//!     }
+//! END
+//! ```
+
+use rasn::{AsnType, Decode, Encode};
+
+#[derive(AsnType, Encode, Decode, Clone, Copy, Debug, PartialEq)]
+#[rasn(enumerated, automatic_tags)]
+pub enum GameType {
+    Survival,
+    Creative,
+    Adventure,
+    Spectator,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Item {
+    pub count: i8,
+    pub slot: u8,
+    pub id: String,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Abilities {
+    pub walk_speed: u32,
+    pub fly_speed: u32,
+    pub may_fly: bool,
+    pub flying: bool,
+    pub invulnerable: bool,
+    pub may_build: bool,
+    pub instabuild: bool,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Vector3d {
+    pub x: u64,
+    pub y: u64,
+    pub z: u64,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Vector2f {
+    pub x: u32,
+    pub y: u32,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Entity {
+    pub id: String,
+    pub pos: Vector3d,
+    pub motion: Vector3d,
+    pub rotation: Vector2f,
+    pub fall_distance: u32,
+    pub fire: u16,
+    pub air: u16,
+    pub on_ground: bool,
+    pub no_gravity: bool,
+    pub invulnerable: bool,
+    pub portal_cooldown: i32,
+    pub uuid: [u32; 4],
+    pub custom_name: Option<String>,
+    pub custom_name_visible: bool,
+    pub silent: bool,
+    pub glowing: bool,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct RecipeBook {
+    pub recipes: Vec<String>,
+    pub to_be_displayed: Vec<String>,
+    pub is_filtering_craftable: bool,
+    pub is_gui_open: bool,
+    pub is_furnace_filtering_craftable: bool,
+    pub is_furnace_gui_open: bool,
+    pub is_blasting_furnace_filtering_craftable: bool,
+    pub is_blasting_furnace_gui_open: bool,
+    pub is_smoker_filtering_craftable: bool,
+    pub is_smoker_gui_open: bool,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Vehicle {
+    pub uuid: [u32; 4],
+    pub entity: Entity,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Player {
+    pub game_type: GameType,
+    pub previous_game_type: GameType,
+    pub score: i64,
+    pub dimension: String,
+    pub selected_item_slot: u32,
+    pub selected_item: Item,
+    pub spawn_dimension: Option<String>,
+    pub spawn_x: i64,
+    pub spawn_y: i64,
+    pub spawn_z: i64,
+    pub spawn_forced: Option<bool>,
+    pub sleep_timer: u16,
+    pub food_exhaustion_level: u32,
+    pub food_saturation_level: u32,
+    pub food_tick_timer: u32,
+    pub xp_level: u32,
+    pub xp_p: u32,
+    pub xp_total: i32,
+    pub xp_seed: i32,
+    pub inventory: Vec<Item>,
+    pub ender_items: Vec<Item>,
+    pub abilities: Abilities,
+    pub entered_nether_position: Option<Vector3d>,
+    pub root_vehicle: Option<Vehicle>,
+    pub shoulder_entity_left: Option<Entity>,
+    pub shoulder_entity_right: Option<Entity>,
+    pub seen_credits: bool,
+    pub recipe_book: RecipeBook,
+}
+
+#[derive(AsnType, Encode, Decode)]
+#[rasn(automatic_tags)]
+pub struct Players {
+    pub players: Vec<Player>,
+}
@@ -7,6 +7,16 @@ pub mod minecraft_savedata_fb;
 #[cfg(feature = "prost")]
 #[path = "prost.minecraft_savedata.rs"]
 pub mod minecraft_savedata_prost;
+#[cfg(feature = "protobuf")]
+pub mod minecraft_savedata_protobuf;
+#[cfg(feature = "rasn")]
+pub mod minecraft_savedata_rasn;
+#[cfg(feature = "thrift")]
+#[path = "minecraft_savedata.rs"]
+#[allow(clippy::all)]
+pub mod minecraft_savedata_thrift;
+
+use std::{borrow::Cow, hint::black_box};
 
 #[cfg(feature = "flatbuffers")]
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
@@ -16,21 +26,35 @@ pub use minecraft_savedata_capnp as cp;
 pub use minecraft_savedata_fb::minecraft_savedata as fb;
 #[cfg(feature = "prost")]
 use minecraft_savedata_prost as pb;
+#[cfg(feature = "rasn")]
+use minecraft_savedata_rasn as asn;
+#[cfg(feature = "thrift")]
+use minecraft_savedata_thrift as th;
 #[cfg(feature = "nanoserde")]
 use nanoserde::{DeBin, SerBin};
-use rand::Rng;
+use serde::de::IgnoredAny;
+#[cfg(feature = "thrift")]
+use thrift::OrderedFloat;
 #[cfg(feature = "wiring")]
 use wiring::prelude::{Unwiring, Wiring};
 
+#[cfg(feature = "apache-avro")]
+use crate::bench_avro;
 #[cfg(feature = "capnp")]
 use crate::bench_capnp;
 #[cfg(feature = "flatbuffers")]
 use crate::bench_flatbuffers;
 #[cfg(feature = "prost")]
 use crate::bench_prost;
-use crate::{generate_vec, Generate};
-
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "protobuf")]
+use crate::bench_protobuf;
+#[cfg(feature = "rasn")]
+use crate::bench_rasn;
+#[cfg(feature = "thrift")]
+use crate::bench_thrift;
+use crate::{Borrowable, Generate, Projectable, ReadRandom};
+
+#[derive(Clone, Copy, PartialEq, Eq, Generate)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Enumeration))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
@@ -40,6 +64,7 @@ use crate::{generate_vec, Generate};
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -72,18 +97,6 @@ pub enum GameType {
 #[cfg(feature = "rkyv")]
 unsafe impl rkyv::traits::NoUndef for ArchivedGameType {}
 
-impl Generate for GameType {
-    fn generate<R: Rng>(rand: &mut R) -> Self {
-        match rand.gen_range(0..4) {
-            0 => GameType::Survival,
-            1 => GameType::Creative,
-            2 => GameType::Adventure,
-            3 => GameType::Spectator,
-            _ => unreachable!(),
-        }
-    }
-}
-
 #[cfg(feature = "flatbuffers")]
 impl From<GameType> for fb::GameType {
     #[inline]
@@ -135,7 +148,94 @@ impl From<pb::GameType> for GameType {
     }
 }
 
-#[derive(Clone, PartialEq)]
+#[cfg(feature = "protobuf")]
+impl From<GameType> for minecraft_savedata_protobuf::GameType {
+    #[inline]
+    fn from(value: GameType) -> Self {
+        match value {
+            GameType::Survival => minecraft_savedata_protobuf::GameType::SURVIVAL,
+            GameType::Creative => minecraft_savedata_protobuf::GameType::CREATIVE,
+            GameType::Adventure => minecraft_savedata_protobuf::GameType::ADVENTURE,
+            GameType::Spectator => minecraft_savedata_protobuf::GameType::SPECTATOR,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<minecraft_savedata_protobuf::GameType> for GameType {
+    fn from(value: minecraft_savedata_protobuf::GameType) -> Self {
+        match value {
+            minecraft_savedata_protobuf::GameType::SURVIVAL => GameType::Survival,
+            minecraft_savedata_protobuf::GameType::CREATIVE => GameType::Creative,
+            minecraft_savedata_protobuf::GameType::ADVENTURE => GameType::Adventure,
+            minecraft_savedata_protobuf::GameType::SPECTATOR => GameType::Spectator,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<GameType> for asn::GameType {
+    #[inline]
+    fn from(value: GameType) -> Self {
+        match value {
+            GameType::Survival => asn::GameType::Survival,
+            GameType::Creative => asn::GameType::Creative,
+            GameType::Adventure => asn::GameType::Adventure,
+            GameType::Spectator => asn::GameType::Spectator,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<asn::GameType> for GameType {
+    fn from(value: asn::GameType) -> Self {
+        match value {
+            asn::GameType::Survival => GameType::Survival,
+            asn::GameType::Creative => GameType::Creative,
+            asn::GameType::Adventure => GameType::Adventure,
+            asn::GameType::Spectator => GameType::Spectator,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<GameType> for th::GameType {
+    #[inline]
+    fn from(value: GameType) -> Self {
+        match value {
+            GameType::Survival => th::GameType::SURVIVAL,
+            GameType::Creative => th::GameType::CREATIVE,
+            GameType::Adventure => th::GameType::ADVENTURE,
+            GameType::Spectator => th::GameType::SPECTATOR,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::GameType> for GameType {
+    fn from(value: th::GameType) -> Self {
+        match value {
+            th::GameType::SURVIVAL => GameType::Survival,
+            th::GameType::CREATIVE => GameType::Creative,
+            th::GameType::ADVENTURE => GameType::Adventure,
+            th::GameType::SPECTATOR => GameType::Spectator,
+            _ => panic!("invalid game type"),
+        }
+    }
+}
+
+const ITEM_IDS: [&str; 8] = [
+    "dirt",
+    "stone",
+    "pickaxe",
+    "sand",
+    "gravel",
+    "shovel",
+    "chestplate",
+    "steak",
+];
+
+#[derive(Clone, PartialEq, Generate)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
@@ -145,6 +245,7 @@ impl From<pb::GameType> for GameType {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -168,29 +269,10 @@ pub struct Item {
     pub count: i8,
     #[cfg_attr(feature = "bilrost", bilrost(encoding(varint)))]
     pub slot: u8,
+    #[generate(choice = "ITEM_IDS")]
     pub id: String,
 }
 
-impl Generate for Item {
-    fn generate<R: Rng>(rng: &mut R) -> Self {
-        const IDS: [&str; 8] = [
-            "dirt",
-            "stone",
-            "pickaxe",
-            "sand",
-            "gravel",
-            "shovel",
-            "chestplate",
-            "steak",
-        ];
-        Self {
-            count: rng.gen(),
-            slot: rng.gen(),
-            id: IDS[rng.gen_range(0..IDS.len())].to_string(),
-        }
-    }
-}
-
 #[cfg(feature = "flatbuffers")]
 impl<'a> bench_flatbuffers::Serialize<'a> for Item {
     type Target = fb::Item<'a>;
@@ -250,7 +332,83 @@ impl From<pb::Item> for Item {
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Item {
+    type Message = minecraft_savedata_protobuf::Item;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            count: self.count as i32,
+            slot: self.slot as u32,
+            id: self.id.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<minecraft_savedata_protobuf::Item> for Item {
+    fn from(value: minecraft_savedata_protobuf::Item) -> Self {
+        Item {
+            count: value.count.try_into().unwrap(),
+            slot: value.slot.try_into().unwrap(),
+            id: value.id,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl bench_rasn::Serialize for Item {
+    type Message = asn::Item;
+
+    #[inline]
+    fn serialize_rasn(&self) -> Self::Message {
+        asn::Item {
+            count: self.count,
+            slot: self.slot,
+            id: self.id.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<asn::Item> for Item {
+    fn from(value: asn::Item) -> Self {
+        Item {
+            count: value.count,
+            slot: value.slot,
+            id: value.id,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Item {
+    type Message = th::Item;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            count: self.count,
+            slot: self.slot as i8,
+            id: self.id.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Item> for Item {
+    fn from(value: th::Item) -> Self {
+        Item {
+            count: value.count,
+            slot: value.slot as u8,
+            id: value.id,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Generate)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
@@ -260,6 +418,7 @@ impl From<pb::Item> for Item {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -288,20 +447,6 @@ pub struct Abilities {
     pub instabuild: bool,
 }
 
-impl Generate for Abilities {
-    fn generate<R: Rng>(rng: &mut R) -> Self {
-        Self {
-            walk_speed: rng.gen(),
-            fly_speed: rng.gen(),
-            may_fly: rng.gen_bool(0.5),
-            flying: rng.gen_bool(0.5),
-            invulnerable: rng.gen_bool(0.5),
-            may_build: rng.gen_bool(0.5),
-            instabuild: rng.gen_bool(0.5),
-        }
-    }
-}
-
 #[cfg(feature = "flatbuffers")]
 impl From<Abilities> for fb::Abilities {
     #[inline]
@@ -368,7 +513,114 @@ impl From<pb::Abilities> for Abilities {
     }
 }
 
-#[derive(Clone, PartialEq)]
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Abilities {
+    type Message = minecraft_savedata_protobuf::Abilities;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            walk_speed: self.walk_speed,
+            fly_speed: self.fly_speed,
+            may_fly: self.may_fly,
+            flying: self.flying,
+            invulnerable: self.invulnerable,
+            may_build: self.may_build,
+            instabuild: self.instabuild,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<minecraft_savedata_protobuf::Abilities> for Abilities {
+    fn from(value: minecraft_savedata_protobuf::Abilities) -> Self {
+        Abilities {
+            walk_speed: value.walk_speed,
+            fly_speed: value.fly_speed,
+            may_fly: value.may_fly,
+            flying: value.flying,
+            invulnerable: value.invulnerable,
+            may_build: value.may_build,
+            instabuild: value.instabuild,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl bench_rasn::Serialize for Abilities {
+    type Message = asn::Abilities;
+
+    #[inline]
+    fn serialize_rasn(&self) -> Self::Message {
+        asn::Abilities {
+            walk_speed: self.walk_speed.to_bits(),
+            fly_speed: self.fly_speed.to_bits(),
+            may_fly: self.may_fly,
+            flying: self.flying,
+            invulnerable: self.invulnerable,
+            may_build: self.may_build,
+            instabuild: self.instabuild,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<asn::Abilities> for Abilities {
+    fn from(value: asn::Abilities) -> Self {
+        Abilities {
+            walk_speed: f32::from_bits(value.walk_speed),
+            fly_speed: f32::from_bits(value.fly_speed),
+            may_fly: value.may_fly,
+            flying: value.flying,
+            invulnerable: value.invulnerable,
+            may_build: value.may_build,
+            instabuild: value.instabuild,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Abilities {
+    type Message = th::Abilities;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            walk_speed: OrderedFloat(self.walk_speed.into()),
+            fly_speed: OrderedFloat(self.fly_speed.into()),
+            may_fly: self.may_fly,
+            flying: self.flying,
+            invulnerable: self.invulnerable,
+            may_build: self.may_build,
+            instabuild: self.instabuild,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Abilities> for Abilities {
+    fn from(value: th::Abilities) -> Self {
+        Abilities {
+            walk_speed: value.walk_speed.0 as f32,
+            fly_speed: value.fly_speed.0 as f32,
+            may_fly: value.may_fly,
+            flying: value.flying,
+            invulnerable: value.invulnerable,
+            may_build: value.may_build,
+            instabuild: value.instabuild,
+        }
+    }
+}
+
+const ENTITY_IDS: [&str; 8] = [
+    "cow", "sheep", "zombie", "skeleton", "spider", "creeper", "parrot", "bee",
+];
+const CUSTOM_NAMES: [&str; 8] = [
+    "rainbow", "princess", "steve", "johnny", "missy", "coward", "fairy", "howard",
+];
+
+#[derive(Clone, PartialEq, Generate)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
@@ -378,6 +630,7 @@ impl From<pb::Abilities> for Abilities {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -396,6 +649,7 @@ impl From<pb::Abilities> for Abilities {
 #[cfg_attr(feature = "nanoserde", derive(nanoserde::SerBin, nanoserde::DeBin))]
 #[cfg_attr(feature = "wiring", derive(Wiring, Unwiring))]
 pub struct Entity {
+    #[generate(choice = "ENTITY_IDS")]
     pub id: String,
     #[cfg_attr(feature = "wiring", fixed(11))]
     pub pos: (f64, f64, f64),
@@ -410,6 +664,7 @@ pub struct Entity {
     pub portal_cooldown: i32,
     #[cfg_attr(feature = "bilrost", bilrost(encoding = "packed<fixed>"))]
     pub uuid: [u32; 4],
+    #[generate(choice = "CUSTOM_NAMES")]
     pub custom_name: Option<String>,
     #[cfg_attr(feature = "wiring", fixed)]
     pub custom_name_visible: bool,
@@ -417,37 +672,6 @@ pub struct Entity {
     pub glowing: bool,
 }
 
-impl Generate for Entity {
-    fn generate<R: Rng>(rng: &mut R) -> Self {
-        const IDS: [&str; 8] = [
-            "cow", "sheep", "zombie", "skeleton", "spider", "creeper", "parrot", "bee",
-        ];
-        const CUSTOM_NAMES: [&str; 8] = [
-            "rainbow", "princess", "steve", "johnny", "missy", "coward", "fairy", "howard",
-        ];
-
-        Self {
-            id: IDS[rng.gen_range(0..IDS.len())].to_string(),
-            pos: <(f64, f64, f64) as Generate>::generate(rng),
-            motion: <(f64, f64, f64) as Generate>::generate(rng),
-            rotation: <(f32, f32) as Generate>::generate(rng),
-            fall_distance: rng.gen(),
-            fire: rng.gen(),
-            air: rng.gen(),
-            on_ground: rng.gen_bool(0.5),
-            no_gravity: rng.gen_bool(0.5),
-            invulnerable: rng.gen_bool(0.5),
-            portal_cooldown: rng.gen(),
-            uuid: <[u32; 4] as Generate>::generate(rng),
-            custom_name: <Option<()> as Generate>::generate(rng)
-                .map(|_| CUSTOM_NAMES[rng.gen_range(0..CUSTOM_NAMES.len())].to_string()),
-            custom_name_visible: rng.gen_bool(0.5),
-            silent: rng.gen_bool(0.5),
-            glowing: rng.gen_bool(0.5),
-        }
-    }
-}
-
 #[cfg(feature = "flatbuffers")]
 impl<'a> bench_flatbuffers::Serialize<'a> for Entity {
     type Target = fb::Entity<'a>;
@@ -621,102 +845,385 @@ impl From<pb::Entity> for Entity {
     }
 }
 
-#[derive(Clone, PartialEq)]
-#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
-#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
-#[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
-#[cfg_attr(
-    feature = "borsh",
-    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
-)]
-#[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
-#[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
-#[cfg_attr(
-    feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
-)]
-#[cfg_attr(
-    feature = "scale",
-    derive(parity_scale_codec_derive::Encode, parity_scale_codec_derive::Decode)
-)]
-#[derive(serde::Serialize, serde::Deserialize)]
-#[cfg_attr(
-    feature = "simd-json",
-    derive(simd_json_derive::Serialize, simd_json_derive::Deserialize)
-)]
-#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
-#[cfg_attr(feature = "savefile", derive(savefile_derive::Savefile))]
-#[cfg_attr(feature = "nanoserde", derive(nanoserde::SerBin, nanoserde::DeBin))]
-#[cfg_attr(feature = "wiring", derive(Wiring, Unwiring))]
-pub struct RecipeBook {
-    #[cfg_attr(feature = "bilrost", bilrost(encoding(packed)))]
-    pub recipes: Vec<String>,
-    #[cfg_attr(feature = "bilrost", bilrost(encoding(packed)))]
-    pub to_be_displayed: Vec<String>,
-    #[cfg_attr(feature = "wiring", fixed)]
-    pub is_filtering_craftable: bool,
-    pub is_gui_open: bool,
-    pub is_furnace_filtering_craftable: bool,
-    pub is_furnace_gui_open: bool,
-    pub is_blasting_furnace_filtering_craftable: bool,
-    pub is_blasting_furnace_gui_open: bool,
-    pub is_smoker_filtering_craftable: bool,
-    pub is_smoker_gui_open: bool,
+#[cfg(feature = "protobuf")]
+impl From<(f64, f64, f64)> for minecraft_savedata_protobuf::Vector3d {
+    #[inline]
+    fn from(value: (f64, f64, f64)) -> Self {
+        Self {
+            x: value.0,
+            y: value.1,
+            z: value.2,
+            ..Default::default()
+        }
+    }
 }
 
-impl Generate for RecipeBook {
-    fn generate<R: Rng>(rng: &mut R) -> Self {
-        const RECIPES: [&str; 8] = [
-            "pickaxe",
-            "torch",
-            "bow",
-            "crafting table",
-            "furnace",
-            "shears",
-            "arrow",
-            "tnt",
-        ];
-        const MAX_RECIPES: usize = 30;
-        const MAX_DISPLAYED_RECIPES: usize = 10;
+#[cfg(feature = "protobuf")]
+impl From<[u32; 4]> for minecraft_savedata_protobuf::Uuid {
+    #[inline]
+    fn from(value: [u32; 4]) -> Self {
         Self {
-            recipes: generate_vec::<_, ()>(rng, 0..MAX_RECIPES)
-                .iter()
-                .map(|_| RECIPES[rng.gen_range(0..RECIPES.len())].to_string())
-                .collect(),
-            to_be_displayed: generate_vec::<_, ()>(rng, 0..MAX_DISPLAYED_RECIPES)
-                .iter()
-                .map(|_| RECIPES[rng.gen_range(0..RECIPES.len())].to_string())
-                .collect(),
-            is_filtering_craftable: rng.gen_bool(0.5),
-            is_gui_open: rng.gen_bool(0.5),
-            is_furnace_filtering_craftable: rng.gen_bool(0.5),
-            is_furnace_gui_open: rng.gen_bool(0.5),
-            is_blasting_furnace_filtering_craftable: rng.gen_bool(0.5),
-            is_blasting_furnace_gui_open: rng.gen_bool(0.5),
-            is_smoker_filtering_craftable: rng.gen_bool(0.5),
-            is_smoker_gui_open: rng.gen_bool(0.5),
+            x0: value[0],
+            x1: value[1],
+            x2: value[2],
+            x3: value[3],
+            ..Default::default()
         }
     }
 }
 
-#[cfg(feature = "flatbuffers")]
-impl<'a> bench_flatbuffers::Serialize<'a> for RecipeBook {
-    type Target = fb::RecipeBook<'a>;
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Entity {
+    type Message = minecraft_savedata_protobuf::Entity;
 
     #[inline]
-    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
-    where
-        'a: 'b,
-    {
-        let mut recipes = Vec::new();
-        for recipe in self.recipes.iter() {
-            recipes.push(fbb.create_string(recipe));
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            id: self.id.clone(),
+            pos: Some(self.pos.into()).into(),
+            motion: Some(self.motion.into()).into(),
+            rotation: Some(minecraft_savedata_protobuf::Vector2f {
+                x: self.rotation.0,
+                y: self.rotation.1,
+                ..Default::default()
+            })
+            .into(),
+            fall_distance: self.fall_distance,
+            fire: self.fire as u32,
+            air: self.air as u32,
+            on_ground: self.on_ground,
+            no_gravity: self.no_gravity,
+            invulnerable: self.invulnerable,
+            portal_cooldown: self.portal_cooldown,
+            uuid: Some(self.uuid.into()).into(),
+            custom_name: self.custom_name.clone(),
+            custom_name_visible: self.custom_name_visible,
+            silent: self.silent,
+            glowing: self.glowing,
+            ..Default::default()
         }
-        let recipes = fbb.create_vector(&recipes);
+    }
+}
 
-        let mut to_be_displayed = Vec::new();
-        for name in self.to_be_displayed.iter() {
-            to_be_displayed.push(fbb.create_string(name));
+#[cfg(feature = "protobuf")]
+impl From<minecraft_savedata_protobuf::Vector3d> for (f64, f64, f64) {
+    fn from(value: minecraft_savedata_protobuf::Vector3d) -> Self {
+        (value.x, value.y, value.z)
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<minecraft_savedata_protobuf::Vector2f> for (f32, f32) {
+    fn from(value: minecraft_savedata_protobuf::Vector2f) -> Self {
+        (value.x, value.y)
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<minecraft_savedata_protobuf::Uuid> for [u32; 4] {
+    fn from(value: minecraft_savedata_protobuf::Uuid) -> Self {
+        [value.x0, value.x1, value.x2, value.x3]
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<minecraft_savedata_protobuf::Entity> for Entity {
+    fn from(value: minecraft_savedata_protobuf::Entity) -> Self {
+        Entity {
+            id: value.id,
+            pos: value.pos.unwrap().into(),
+            motion: value.motion.unwrap().into(),
+            rotation: value.rotation.unwrap().into(),
+            fall_distance: value.fall_distance,
+            fire: value.fire.try_into().unwrap(),
+            air: value.air.try_into().unwrap(),
+            on_ground: value.on_ground,
+            no_gravity: value.no_gravity,
+            invulnerable: value.invulnerable,
+            portal_cooldown: value.portal_cooldown,
+            uuid: value.uuid.unwrap().into(),
+            custom_name: value.custom_name,
+            custom_name_visible: value.custom_name_visible,
+            silent: value.silent,
+            glowing: value.glowing,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<(f64, f64, f64)> for asn::Vector3d {
+    #[inline]
+    fn from(value: (f64, f64, f64)) -> Self {
+        Self {
+            x: value.0.to_bits(),
+            y: value.1.to_bits(),
+            z: value.2.to_bits(),
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<(f32, f32)> for asn::Vector2f {
+    #[inline]
+    fn from(value: (f32, f32)) -> Self {
+        Self {
+            x: value.0.to_bits(),
+            y: value.1.to_bits(),
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl bench_rasn::Serialize for Entity {
+    type Message = asn::Entity;
+
+    #[inline]
+    fn serialize_rasn(&self) -> Self::Message {
+        asn::Entity {
+            id: self.id.clone(),
+            pos: self.pos.into(),
+            motion: self.motion.into(),
+            rotation: self.rotation.into(),
+            fall_distance: self.fall_distance.to_bits(),
+            fire: self.fire,
+            air: self.air,
+            on_ground: self.on_ground,
+            no_gravity: self.no_gravity,
+            invulnerable: self.invulnerable,
+            portal_cooldown: self.portal_cooldown,
+            uuid: self.uuid,
+            custom_name: self.custom_name.clone(),
+            custom_name_visible: self.custom_name_visible,
+            silent: self.silent,
+            glowing: self.glowing,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<asn::Vector3d> for (f64, f64, f64) {
+    fn from(value: asn::Vector3d) -> Self {
+        (
+            f64::from_bits(value.x),
+            f64::from_bits(value.y),
+            f64::from_bits(value.z),
+        )
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<asn::Vector2f> for (f32, f32) {
+    fn from(value: asn::Vector2f) -> Self {
+        (f32::from_bits(value.x), f32::from_bits(value.y))
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<asn::Entity> for Entity {
+    fn from(value: asn::Entity) -> Self {
+        Entity {
+            id: value.id,
+            pos: value.pos.into(),
+            motion: value.motion.into(),
+            rotation: value.rotation.into(),
+            fall_distance: f32::from_bits(value.fall_distance),
+            fire: value.fire,
+            air: value.air,
+            on_ground: value.on_ground,
+            no_gravity: value.no_gravity,
+            invulnerable: value.invulnerable,
+            portal_cooldown: value.portal_cooldown,
+            uuid: value.uuid,
+            custom_name: value.custom_name,
+            custom_name_visible: value.custom_name_visible,
+            silent: value.silent,
+            glowing: value.glowing,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<(f64, f64, f64)> for th::Vector3d {
+    #[inline]
+    fn from(value: (f64, f64, f64)) -> Self {
+        Self {
+            x: OrderedFloat(value.0),
+            y: OrderedFloat(value.1),
+            z: OrderedFloat(value.2),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<[u32; 4]> for th::Uuid {
+    #[inline]
+    fn from(value: [u32; 4]) -> Self {
+        Self {
+            x0: value[0] as i32,
+            x1: value[1] as i32,
+            x2: value[2] as i32,
+            x3: value[3] as i32,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Entity {
+    type Message = th::Entity;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            id: self.id.clone(),
+            pos: self.pos.into(),
+            motion: self.motion.into(),
+            rotation: th::Vector2f {
+                x: OrderedFloat(self.rotation.0.into()),
+                y: OrderedFloat(self.rotation.1.into()),
+            },
+            fall_distance: OrderedFloat(self.fall_distance.into()),
+            fire: self.fire as i16,
+            air: self.air as i16,
+            on_ground: self.on_ground,
+            no_gravity: self.no_gravity,
+            invulnerable: self.invulnerable,
+            portal_cooldown: self.portal_cooldown,
+            uuid: self.uuid.into(),
+            custom_name: self.custom_name.clone(),
+            custom_name_visible: self.custom_name_visible,
+            silent: self.silent,
+            glowing: self.glowing,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Vector3d> for (f64, f64, f64) {
+    fn from(value: th::Vector3d) -> Self {
+        (value.x.0, value.y.0, value.z.0)
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Vector2f> for (f32, f32) {
+    fn from(value: th::Vector2f) -> Self {
+        (value.x.0 as f32, value.y.0 as f32)
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Uuid> for [u32; 4] {
+    fn from(value: th::Uuid) -> Self {
+        [
+            value.x0 as u32,
+            value.x1 as u32,
+            value.x2 as u32,
+            value.x3 as u32,
+        ]
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Entity> for Entity {
+    fn from(value: th::Entity) -> Self {
+        Entity {
+            id: value.id,
+            pos: value.pos.into(),
+            motion: value.motion.into(),
+            rotation: value.rotation.into(),
+            fall_distance: value.fall_distance.0 as f32,
+            fire: value.fire as u16,
+            air: value.air as u16,
+            on_ground: value.on_ground,
+            no_gravity: value.no_gravity,
+            invulnerable: value.invulnerable,
+            portal_cooldown: value.portal_cooldown,
+            uuid: value.uuid.into(),
+            custom_name: value.custom_name,
+            custom_name_visible: value.custom_name_visible,
+            silent: value.silent,
+            glowing: value.glowing,
+        }
+    }
+}
+
+const RECIPES: [&str; 8] = [
+    "pickaxe",
+    "torch",
+    "bow",
+    "crafting table",
+    "furnace",
+    "shears",
+    "arrow",
+    "tnt",
+];
+const MAX_RECIPES: usize = 30;
+const MAX_DISPLAYED_RECIPES: usize = 10;
+
+#[derive(Clone, PartialEq, Generate)]
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
+#[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(
+    feature = "scale",
+    derive(parity_scale_codec_derive::Encode, parity_scale_codec_derive::Decode)
+)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[cfg_attr(
+    feature = "simd-json",
+    derive(simd_json_derive::Serialize, simd_json_derive::Deserialize)
+)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "savefile", derive(savefile_derive::Savefile))]
+#[cfg_attr(feature = "nanoserde", derive(nanoserde::SerBin, nanoserde::DeBin))]
+#[cfg_attr(feature = "wiring", derive(Wiring, Unwiring))]
+pub struct RecipeBook {
+    #[cfg_attr(feature = "bilrost", bilrost(encoding(packed)))]
+    #[generate(len = "0..MAX_RECIPES", choice = "RECIPES")]
+    pub recipes: Vec<String>,
+    #[cfg_attr(feature = "bilrost", bilrost(encoding(packed)))]
+    #[generate(len = "0..MAX_DISPLAYED_RECIPES", choice = "RECIPES")]
+    pub to_be_displayed: Vec<String>,
+    #[cfg_attr(feature = "wiring", fixed)]
+    pub is_filtering_craftable: bool,
+    pub is_gui_open: bool,
+    pub is_furnace_filtering_craftable: bool,
+    pub is_furnace_gui_open: bool,
+    pub is_blasting_furnace_filtering_craftable: bool,
+    pub is_blasting_furnace_gui_open: bool,
+    pub is_smoker_filtering_craftable: bool,
+    pub is_smoker_gui_open: bool,
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for RecipeBook {
+    type Target = fb::RecipeBook<'a>;
+
+    #[inline]
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let mut recipes = Vec::new();
+        for recipe in self.recipes.iter() {
+            recipes.push(fbb.create_string(recipe));
+        }
+        let recipes = fbb.create_vector(&recipes);
+
+        let mut to_be_displayed = Vec::new();
+        for name in self.to_be_displayed.iter() {
+            to_be_displayed.push(fbb.create_string(name));
         }
         let to_be_displayed = fbb.create_vector(&to_be_displayed);
 
@@ -813,7 +1320,129 @@ impl From<pb::RecipeBook> for RecipeBook {
     }
 }
 
-#[derive(Clone, PartialEq)]
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for RecipeBook {
+    type Message = minecraft_savedata_protobuf::RecipeBook;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            recipes: self.recipes.clone(),
+            to_be_displayed: self.to_be_displayed.clone(),
+            is_filtering_craftable: self.is_filtering_craftable,
+            is_gui_open: self.is_gui_open,
+            is_furnace_filtering_craftable: self.is_furnace_filtering_craftable,
+            is_furnace_gui_open: self.is_furnace_gui_open,
+            is_blasting_furnace_filtering_craftable: self.is_blasting_furnace_filtering_craftable,
+            is_blasting_furnace_gui_open: self.is_blasting_furnace_gui_open,
+            is_smoker_filtering_craftable: self.is_smoker_filtering_craftable,
+            is_smoker_gui_open: self.is_smoker_gui_open,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<minecraft_savedata_protobuf::RecipeBook> for RecipeBook {
+    fn from(value: minecraft_savedata_protobuf::RecipeBook) -> Self {
+        RecipeBook {
+            recipes: value.recipes,
+            to_be_displayed: value.to_be_displayed,
+            is_filtering_craftable: value.is_filtering_craftable,
+            is_gui_open: value.is_gui_open,
+            is_furnace_filtering_craftable: value.is_furnace_filtering_craftable,
+            is_furnace_gui_open: value.is_furnace_gui_open,
+            is_blasting_furnace_filtering_craftable: value.is_blasting_furnace_filtering_craftable,
+            is_blasting_furnace_gui_open: value.is_blasting_furnace_gui_open,
+            is_smoker_filtering_craftable: value.is_smoker_filtering_craftable,
+            is_smoker_gui_open: value.is_smoker_gui_open,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl bench_rasn::Serialize for RecipeBook {
+    type Message = asn::RecipeBook;
+
+    #[inline]
+    fn serialize_rasn(&self) -> Self::Message {
+        asn::RecipeBook {
+            recipes: self.recipes.clone(),
+            to_be_displayed: self.to_be_displayed.clone(),
+            is_filtering_craftable: self.is_filtering_craftable,
+            is_gui_open: self.is_gui_open,
+            is_furnace_filtering_craftable: self.is_furnace_filtering_craftable,
+            is_furnace_gui_open: self.is_furnace_gui_open,
+            is_blasting_furnace_filtering_craftable: self.is_blasting_furnace_filtering_craftable,
+            is_blasting_furnace_gui_open: self.is_blasting_furnace_gui_open,
+            is_smoker_filtering_craftable: self.is_smoker_filtering_craftable,
+            is_smoker_gui_open: self.is_smoker_gui_open,
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<asn::RecipeBook> for RecipeBook {
+    fn from(value: asn::RecipeBook) -> Self {
+        RecipeBook {
+            recipes: value.recipes,
+            to_be_displayed: value.to_be_displayed,
+            is_filtering_craftable: value.is_filtering_craftable,
+            is_gui_open: value.is_gui_open,
+            is_furnace_filtering_craftable: value.is_furnace_filtering_craftable,
+            is_furnace_gui_open: value.is_furnace_gui_open,
+            is_blasting_furnace_filtering_craftable: value.is_blasting_furnace_filtering_craftable,
+            is_blasting_furnace_gui_open: value.is_blasting_furnace_gui_open,
+            is_smoker_filtering_craftable: value.is_smoker_filtering_craftable,
+            is_smoker_gui_open: value.is_smoker_gui_open,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for RecipeBook {
+    type Message = th::RecipeBook;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            recipes: self.recipes.clone(),
+            to_be_displayed: self.to_be_displayed.clone(),
+            is_filtering_craftable: self.is_filtering_craftable,
+            is_gui_open: self.is_gui_open,
+            is_furnace_filtering_craftable: self.is_furnace_filtering_craftable,
+            is_furnace_gui_open: self.is_furnace_gui_open,
+            is_blasting_furnace_filtering_craftable: self.is_blasting_furnace_filtering_craftable,
+            is_blasting_furnace_gui_open: self.is_blasting_furnace_gui_open,
+            is_smoker_filtering_craftable: self.is_smoker_filtering_craftable,
+            is_smoker_gui_open: self.is_smoker_gui_open,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::RecipeBook> for RecipeBook {
+    fn from(value: th::RecipeBook) -> Self {
+        RecipeBook {
+            recipes: value.recipes,
+            to_be_displayed: value.to_be_displayed,
+            is_filtering_craftable: value.is_filtering_craftable,
+            is_gui_open: value.is_gui_open,
+            is_furnace_filtering_craftable: value.is_furnace_filtering_craftable,
+            is_furnace_gui_open: value.is_furnace_gui_open,
+            is_blasting_furnace_filtering_craftable: value.is_blasting_furnace_filtering_craftable,
+            is_blasting_furnace_gui_open: value.is_blasting_furnace_gui_open,
+            is_smoker_filtering_craftable: value.is_smoker_filtering_craftable,
+            is_smoker_gui_open: value.is_smoker_gui_open,
+        }
+    }
+}
+
+const DIMENSIONS: [&str; 3] = ["overworld", "nether", "end"];
+const MAX_ITEMS: usize = 40;
+const MAX_ENDER_ITEMS: usize = 27;
+
+#[derive(Clone, PartialEq, Generate)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
@@ -823,6 +1452,7 @@ impl From<pb::RecipeBook> for RecipeBook {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -845,9 +1475,11 @@ pub struct Player {
     pub game_type: GameType,
     pub previous_game_type: GameType,
     pub score: i64,
+    #[generate(choice = "DIMENSIONS")]
     pub dimension: String,
     pub selected_item_slot: u32,
     pub selected_item: Item,
+    #[generate(choice = "DIMENSIONS")]
     pub spawn_dimension: Option<String>,
     #[cfg_attr(feature = "wiring", fixed(3))]
     pub spawn_x: i64,
@@ -863,7 +1495,9 @@ pub struct Player {
     pub xp_p: f32,
     pub xp_total: i32,
     pub xp_seed: i32,
+    #[generate(len = "0..MAX_ITEMS")]
     pub inventory: Vec<Item>,
+    #[generate(len = "0..MAX_ENDER_ITEMS")]
     pub ender_items: Vec<Item>,
     pub abilities: Abilities,
     pub entered_nether_position: Option<(f64, f64, f64)>,
@@ -875,45 +1509,6 @@ pub struct Player {
     pub recipe_book: RecipeBook,
 }
 
-impl Generate for Player {
-    fn generate<R: Rng>(rng: &mut R) -> Self {
-        const DIMENSIONS: [&str; 3] = ["overworld", "nether", "end"];
-        const MAX_ITEMS: usize = 40;
-        const MAX_ENDER_ITEMS: usize = 27;
-        Self {
-            game_type: GameType::generate(rng),
-            previous_game_type: GameType::generate(rng),
-            score: rng.gen(),
-            dimension: DIMENSIONS[rng.gen_range(0..DIMENSIONS.len())].to_string(),
-            selected_item_slot: rng.gen(),
-            selected_item: Item::generate(rng),
-            spawn_dimension: <Option<()> as Generate>::generate(rng)
-                .map(|_| DIMENSIONS[rng.gen_range(0..DIMENSIONS.len())].to_string()),
-            spawn_x: rng.gen(),
-            spawn_y: rng.gen(),
-            spawn_z: rng.gen(),
-            spawn_forced: <Option<bool> as Generate>::generate(rng),
-            sleep_timer: rng.gen(),
-            food_exhaustion_level: rng.gen(),
-            food_saturation_level: rng.gen(),
-            food_tick_timer: rng.gen(),
-            xp_level: rng.gen(),
-            xp_p: rng.gen(),
-            xp_total: rng.gen(),
-            xp_seed: rng.gen(),
-            inventory: generate_vec(rng, 0..MAX_ITEMS),
-            ender_items: generate_vec(rng, 0..MAX_ENDER_ITEMS),
-            abilities: Abilities::generate(rng),
-            entered_nether_position: <Option<(f64, f64, f64)> as Generate>::generate(rng),
-            root_vehicle: <Option<([u32; 4], Entity)> as Generate>::generate(rng),
-            shoulder_entity_left: <Option<Entity> as Generate>::generate(rng),
-            shoulder_entity_right: <Option<Entity> as Generate>::generate(rng),
-            seen_credits: rng.gen_bool(0.5),
-            recipe_book: RecipeBook::generate(rng),
-        }
-    }
-}
-
 #[cfg(feature = "flatbuffers")]
 impl<'a> bench_flatbuffers::Serialize<'a> for Player {
     type Target = fb::Player<'a>;
@@ -1105,98 +1700,389 @@ impl<'a> bench_capnp::Serialize<'a> for Player {
 impl bench_prost::Serialize for Player {
     type Message = pb::Player;
 
-    fn serialize_pb(&self) -> Self::Message {
-        let mut result = Self::Message {
-            game_type: pb::GameType::from(self.game_type) as i32,
-            previous_game_type: self.previous_game_type as i32,
+    fn serialize_pb(&self) -> Self::Message {
+        let mut result = Self::Message {
+            game_type: pb::GameType::from(self.game_type) as i32,
+            previous_game_type: self.previous_game_type as i32,
+            score: self.score,
+            dimension: self.dimension.clone(),
+            selected_item_slot: self.selected_item_slot,
+            selected_item: Some(self.selected_item.serialize_pb()),
+            spawn_dimension: self.spawn_dimension.clone(),
+            spawn_x: self.spawn_x,
+            spawn_y: self.spawn_y,
+            spawn_z: self.spawn_z,
+            spawn_forced: self.spawn_forced,
+            sleep_timer: self.sleep_timer as u32,
+            food_exhaustion_level: self.food_exhaustion_level,
+            food_saturation_level: self.food_saturation_level,
+            food_tick_timer: self.food_tick_timer,
+            xp_level: self.xp_level,
+            xp_p: self.xp_p,
+            xp_total: self.xp_total,
+            xp_seed: self.xp_seed,
+            inventory: Default::default(),
+            ender_items: Default::default(),
+            abilities: Some(self.abilities.serialize_pb()),
+            entered_nether_position: self.entered_nether_position.map(|p| pb::Vector3d {
+                x: p.0,
+                y: p.1,
+                z: p.2,
+            }),
+            root_vehicle: self.root_vehicle.as_ref().map(|v| pb::Vehicle {
+                uuid: Some(pb::Uuid {
+                    x0: v.0[0],
+                    x1: v.0[1],
+                    x2: v.0[2],
+                    x3: v.0[3],
+                }),
+                entity: Some(v.1.serialize_pb()),
+            }),
+            shoulder_entity_left: self.shoulder_entity_left.as_ref().map(|e| e.serialize_pb()),
+            shoulder_entity_right: self
+                .shoulder_entity_right
+                .as_ref()
+                .map(|e| e.serialize_pb()),
+            seen_credits: self.seen_credits,
+            recipe_book: Some(self.recipe_book.serialize_pb()),
+        };
+        for item in self.inventory.iter() {
+            result.inventory.push(item.serialize_pb());
+        }
+        for item in self.ender_items.iter() {
+            result.ender_items.push(item.serialize_pb());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb::Player> for Player {
+    fn from(value: pb::Player) -> Self {
+        Player {
+            game_type: pb::GameType::try_from(value.game_type).unwrap().into(),
+            previous_game_type: pb::GameType::try_from(value.previous_game_type)
+                .unwrap()
+                .into(),
+            score: value.score,
+            dimension: value.dimension,
+            selected_item_slot: value.selected_item_slot,
+            selected_item: value.selected_item.unwrap().into(),
+            spawn_dimension: value.spawn_dimension,
+            spawn_x: value.spawn_x,
+            spawn_y: value.spawn_y,
+            spawn_z: value.spawn_z,
+            spawn_forced: value.spawn_forced,
+            sleep_timer: value.sleep_timer.try_into().unwrap(),
+            food_exhaustion_level: value.food_exhaustion_level,
+            food_saturation_level: value.food_saturation_level,
+            food_tick_timer: value.food_tick_timer,
+            xp_level: value.xp_level,
+            xp_p: value.xp_p,
+            xp_total: value.xp_total,
+            xp_seed: value.xp_seed,
+            inventory: value.inventory.into_iter().map(Into::into).collect(),
+            ender_items: value.ender_items.into_iter().map(Into::into).collect(),
+            abilities: value.abilities.unwrap().into(),
+            entered_nether_position: value.entered_nether_position.map(Into::into),
+            root_vehicle: value
+                .root_vehicle
+                .map(|vehicle| (vehicle.uuid.unwrap().into(), vehicle.entity.unwrap().into())),
+            shoulder_entity_left: value.shoulder_entity_left.map(Into::into),
+            shoulder_entity_right: value.shoulder_entity_right.map(Into::into),
+            seen_credits: value.seen_credits,
+            recipe_book: value.recipe_book.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Player {
+    type Message = minecraft_savedata_protobuf::Player;
+
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            game_type: minecraft_savedata_protobuf::GameType::from(self.game_type).into(),
+            previous_game_type: minecraft_savedata_protobuf::GameType::from(
+                self.previous_game_type,
+            )
+            .into(),
+            score: self.score,
+            dimension: self.dimension.clone(),
+            selected_item_slot: self.selected_item_slot,
+            selected_item: Some(self.selected_item.serialize_protobuf()).into(),
+            spawn_dimension: self.spawn_dimension.clone(),
+            spawn_x: self.spawn_x,
+            spawn_y: self.spawn_y,
+            spawn_z: self.spawn_z,
+            spawn_forced: self.spawn_forced,
+            sleep_timer: self.sleep_timer as u32,
+            food_exhaustion_level: self.food_exhaustion_level,
+            food_saturation_level: self.food_saturation_level,
+            food_tick_timer: self.food_tick_timer,
+            xp_level: self.xp_level,
+            xp_p: self.xp_p,
+            xp_total: self.xp_total,
+            xp_seed: self.xp_seed,
+            inventory: self
+                .inventory
+                .iter()
+                .map(|item| item.serialize_protobuf())
+                .collect(),
+            ender_items: self
+                .ender_items
+                .iter()
+                .map(|item| item.serialize_protobuf())
+                .collect(),
+            abilities: Some(self.abilities.serialize_protobuf()).into(),
+            entered_nether_position: self.entered_nether_position.map(Into::into).into(),
+            root_vehicle: self
+                .root_vehicle
+                .as_ref()
+                .map(|v| minecraft_savedata_protobuf::Vehicle {
+                    uuid: Some(v.0.into()).into(),
+                    entity: Some(v.1.serialize_protobuf()).into(),
+                    ..Default::default()
+                })
+                .into(),
+            shoulder_entity_left: self
+                .shoulder_entity_left
+                .as_ref()
+                .map(|e| e.serialize_protobuf())
+                .into(),
+            shoulder_entity_right: self
+                .shoulder_entity_right
+                .as_ref()
+                .map(|e| e.serialize_protobuf())
+                .into(),
+            seen_credits: self.seen_credits,
+            recipe_book: Some(self.recipe_book.serialize_protobuf()).into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<minecraft_savedata_protobuf::Player> for Player {
+    fn from(value: minecraft_savedata_protobuf::Player) -> Self {
+        Player {
+            game_type: value.game_type.unwrap().into(),
+            previous_game_type: value.previous_game_type.unwrap().into(),
+            score: value.score,
+            dimension: value.dimension,
+            selected_item_slot: value.selected_item_slot,
+            selected_item: value.selected_item.unwrap().into(),
+            spawn_dimension: value.spawn_dimension,
+            spawn_x: value.spawn_x,
+            spawn_y: value.spawn_y,
+            spawn_z: value.spawn_z,
+            spawn_forced: value.spawn_forced,
+            sleep_timer: value.sleep_timer.try_into().unwrap(),
+            food_exhaustion_level: value.food_exhaustion_level,
+            food_saturation_level: value.food_saturation_level,
+            food_tick_timer: value.food_tick_timer,
+            xp_level: value.xp_level,
+            xp_p: value.xp_p,
+            xp_total: value.xp_total,
+            xp_seed: value.xp_seed,
+            inventory: value.inventory.into_iter().map(Into::into).collect(),
+            ender_items: value.ender_items.into_iter().map(Into::into).collect(),
+            abilities: value.abilities.unwrap().into(),
+            entered_nether_position: value.entered_nether_position.into_option().map(Into::into),
+            root_vehicle: value
+                .root_vehicle
+                .into_option()
+                .map(|vehicle| (vehicle.uuid.unwrap().into(), vehicle.entity.unwrap().into())),
+            shoulder_entity_left: value.shoulder_entity_left.into_option().map(Into::into),
+            shoulder_entity_right: value.shoulder_entity_right.into_option().map(Into::into),
+            seen_credits: value.seen_credits,
+            recipe_book: value.recipe_book.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl bench_rasn::Serialize for Player {
+    type Message = asn::Player;
+
+    fn serialize_rasn(&self) -> Self::Message {
+        asn::Player {
+            game_type: self.game_type.into(),
+            previous_game_type: self.previous_game_type.into(),
+            score: self.score,
+            dimension: self.dimension.clone(),
+            selected_item_slot: self.selected_item_slot,
+            selected_item: self.selected_item.serialize_rasn(),
+            spawn_dimension: self.spawn_dimension.clone(),
+            spawn_x: self.spawn_x,
+            spawn_y: self.spawn_y,
+            spawn_z: self.spawn_z,
+            spawn_forced: self.spawn_forced,
+            sleep_timer: self.sleep_timer,
+            food_exhaustion_level: self.food_exhaustion_level.to_bits(),
+            food_saturation_level: self.food_saturation_level.to_bits(),
+            food_tick_timer: self.food_tick_timer,
+            xp_level: self.xp_level,
+            xp_p: self.xp_p.to_bits(),
+            xp_total: self.xp_total,
+            xp_seed: self.xp_seed,
+            inventory: self
+                .inventory
+                .iter()
+                .map(|item| item.serialize_rasn())
+                .collect(),
+            ender_items: self
+                .ender_items
+                .iter()
+                .map(|item| item.serialize_rasn())
+                .collect(),
+            abilities: self.abilities.serialize_rasn(),
+            entered_nether_position: self.entered_nether_position.map(Into::into),
+            root_vehicle: self.root_vehicle.as_ref().map(|v| asn::Vehicle {
+                uuid: v.0,
+                entity: v.1.serialize_rasn(),
+            }),
+            shoulder_entity_left: self
+                .shoulder_entity_left
+                .as_ref()
+                .map(|e| e.serialize_rasn()),
+            shoulder_entity_right: self
+                .shoulder_entity_right
+                .as_ref()
+                .map(|e| e.serialize_rasn()),
+            seen_credits: self.seen_credits,
+            recipe_book: self.recipe_book.serialize_rasn(),
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<asn::Player> for Player {
+    fn from(value: asn::Player) -> Self {
+        Player {
+            game_type: value.game_type.into(),
+            previous_game_type: value.previous_game_type.into(),
+            score: value.score,
+            dimension: value.dimension,
+            selected_item_slot: value.selected_item_slot,
+            selected_item: value.selected_item.into(),
+            spawn_dimension: value.spawn_dimension,
+            spawn_x: value.spawn_x,
+            spawn_y: value.spawn_y,
+            spawn_z: value.spawn_z,
+            spawn_forced: value.spawn_forced,
+            sleep_timer: value.sleep_timer,
+            food_exhaustion_level: f32::from_bits(value.food_exhaustion_level),
+            food_saturation_level: f32::from_bits(value.food_saturation_level),
+            food_tick_timer: value.food_tick_timer,
+            xp_level: value.xp_level,
+            xp_p: f32::from_bits(value.xp_p),
+            xp_total: value.xp_total,
+            xp_seed: value.xp_seed,
+            inventory: value.inventory.into_iter().map(Into::into).collect(),
+            ender_items: value.ender_items.into_iter().map(Into::into).collect(),
+            abilities: value.abilities.into(),
+            entered_nether_position: value.entered_nether_position.map(Into::into),
+            root_vehicle: value
+                .root_vehicle
+                .map(|vehicle| (vehicle.uuid, vehicle.entity.into())),
+            shoulder_entity_left: value.shoulder_entity_left.map(Into::into),
+            shoulder_entity_right: value.shoulder_entity_right.map(Into::into),
+            seen_credits: value.seen_credits,
+            recipe_book: value.recipe_book.into(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Player {
+    type Message = th::Player;
+
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            game_type: self.game_type.into(),
+            previous_game_type: self.previous_game_type.into(),
             score: self.score,
             dimension: self.dimension.clone(),
-            selected_item_slot: self.selected_item_slot,
-            selected_item: Some(self.selected_item.serialize_pb()),
+            selected_item_slot: self.selected_item_slot as i32,
+            selected_item: self.selected_item.serialize_thrift(),
             spawn_dimension: self.spawn_dimension.clone(),
             spawn_x: self.spawn_x,
             spawn_y: self.spawn_y,
             spawn_z: self.spawn_z,
             spawn_forced: self.spawn_forced,
-            sleep_timer: self.sleep_timer as u32,
-            food_exhaustion_level: self.food_exhaustion_level,
-            food_saturation_level: self.food_saturation_level,
-            food_tick_timer: self.food_tick_timer,
-            xp_level: self.xp_level,
-            xp_p: self.xp_p,
+            sleep_timer: self.sleep_timer as i16,
+            food_exhaustion_level: OrderedFloat(self.food_exhaustion_level.into()),
+            food_saturation_level: OrderedFloat(self.food_saturation_level.into()),
+            food_tick_timer: self.food_tick_timer as i32,
+            xp_level: self.xp_level as i32,
+            xp_p: OrderedFloat(self.xp_p.into()),
             xp_total: self.xp_total,
             xp_seed: self.xp_seed,
-            inventory: Default::default(),
-            ender_items: Default::default(),
-            abilities: Some(self.abilities.serialize_pb()),
-            entered_nether_position: self.entered_nether_position.map(|p| pb::Vector3d {
-                x: p.0,
-                y: p.1,
-                z: p.2,
-            }),
-            root_vehicle: self.root_vehicle.as_ref().map(|v| pb::Vehicle {
-                uuid: Some(pb::Uuid {
-                    x0: v.0[0],
-                    x1: v.0[1],
-                    x2: v.0[2],
-                    x3: v.0[3],
-                }),
-                entity: Some(v.1.serialize_pb()),
+            inventory: self
+                .inventory
+                .iter()
+                .map(|item| item.serialize_thrift())
+                .collect(),
+            ender_items: self
+                .ender_items
+                .iter()
+                .map(|item| item.serialize_thrift())
+                .collect(),
+            abilities: self.abilities.serialize_thrift(),
+            entered_nether_position: self.entered_nether_position.map(Into::into),
+            root_vehicle: self.root_vehicle.as_ref().map(|v| th::Vehicle {
+                uuid: v.0.into(),
+                entity: v.1.serialize_thrift(),
             }),
-            shoulder_entity_left: self.shoulder_entity_left.as_ref().map(|e| e.serialize_pb()),
+            shoulder_entity_left: self
+                .shoulder_entity_left
+                .as_ref()
+                .map(|e| e.serialize_thrift()),
             shoulder_entity_right: self
                 .shoulder_entity_right
                 .as_ref()
-                .map(|e| e.serialize_pb()),
+                .map(|e| e.serialize_thrift()),
             seen_credits: self.seen_credits,
-            recipe_book: Some(self.recipe_book.serialize_pb()),
-        };
-        for item in self.inventory.iter() {
-            result.inventory.push(item.serialize_pb());
+            recipe_book: self.recipe_book.serialize_thrift(),
         }
-        for item in self.ender_items.iter() {
-            result.ender_items.push(item.serialize_pb());
-        }
-        result
     }
 }
 
-#[cfg(feature = "prost")]
-impl From<pb::Player> for Player {
-    fn from(value: pb::Player) -> Self {
+#[cfg(feature = "thrift")]
+impl From<th::Player> for Player {
+    fn from(value: th::Player) -> Self {
         Player {
-            game_type: pb::GameType::try_from(value.game_type).unwrap().into(),
-            previous_game_type: pb::GameType::try_from(value.previous_game_type)
-                .unwrap()
-                .into(),
+            game_type: value.game_type.into(),
+            previous_game_type: value.previous_game_type.into(),
             score: value.score,
             dimension: value.dimension,
-            selected_item_slot: value.selected_item_slot,
-            selected_item: value.selected_item.unwrap().into(),
+            selected_item_slot: value.selected_item_slot as u32,
+            selected_item: value.selected_item.into(),
             spawn_dimension: value.spawn_dimension,
             spawn_x: value.spawn_x,
             spawn_y: value.spawn_y,
             spawn_z: value.spawn_z,
             spawn_forced: value.spawn_forced,
-            sleep_timer: value.sleep_timer.try_into().unwrap(),
-            food_exhaustion_level: value.food_exhaustion_level,
-            food_saturation_level: value.food_saturation_level,
-            food_tick_timer: value.food_tick_timer,
-            xp_level: value.xp_level,
-            xp_p: value.xp_p,
+            sleep_timer: value.sleep_timer as u16,
+            food_exhaustion_level: value.food_exhaustion_level.0 as f32,
+            food_saturation_level: value.food_saturation_level.0 as f32,
+            food_tick_timer: value.food_tick_timer as u32,
+            xp_level: value.xp_level as u32,
+            xp_p: value.xp_p.0 as f32,
             xp_total: value.xp_total,
             xp_seed: value.xp_seed,
             inventory: value.inventory.into_iter().map(Into::into).collect(),
             ender_items: value.ender_items.into_iter().map(Into::into).collect(),
-            abilities: value.abilities.unwrap().into(),
+            abilities: value.abilities.into(),
             entered_nether_position: value.entered_nether_position.map(Into::into),
             root_vehicle: value
                 .root_vehicle
-                .map(|vehicle| (vehicle.uuid.unwrap().into(), vehicle.entity.unwrap().into())),
+                .map(|vehicle| (vehicle.uuid.into(), vehicle.entity.into())),
             shoulder_entity_left: value.shoulder_entity_left.map(Into::into),
             shoulder_entity_right: value.shoulder_entity_right.map(Into::into),
             seen_credits: value.seen_credits,
-            recipe_book: value.recipe_book.unwrap().into(),
+            recipe_book: value.recipe_book.into(),
         }
     }
 }
@@ -1211,6 +2097,7 @@ impl From<pb::Player> for Player {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -1233,6 +2120,11 @@ pub struct Players {
     pub players: Vec<Player>,
 }
 
+#[cfg(feature = "apache-avro")]
+impl bench_avro::Schema for Players {
+    const SCHEMA: &'static str = include_str!("minecraft_savedata.avsc");
+}
+
 #[cfg(feature = "flatbuffers")]
 impl<'a> bench_flatbuffers::Serialize<'a> for Players {
     type Target = fb::Players<'a>;
@@ -1290,3 +2182,377 @@ impl From<pb::Players> for Players {
         }
     }
 }
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Players {
+    type Message = minecraft_savedata_protobuf::Players;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        let mut result = Self::Message::default();
+        for player in self.players.iter() {
+            result.players.push(player.serialize_protobuf());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<minecraft_savedata_protobuf::Players> for Players {
+    fn from(value: minecraft_savedata_protobuf::Players) -> Self {
+        Players {
+            players: value.players.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl bench_rasn::Serialize for Players {
+    type Message = asn::Players;
+
+    #[inline]
+    fn serialize_rasn(&self) -> Self::Message {
+        asn::Players {
+            players: self
+                .players
+                .iter()
+                .map(|player| player.serialize_rasn())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "rasn")]
+impl From<asn::Players> for Players {
+    fn from(value: asn::Players) -> Self {
+        Players {
+            players: value.players.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Players {
+    type Message = th::Players;
+
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            players: self
+                .players
+                .iter()
+                .map(|player| player.serialize_thrift())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Players> for Players {
+    fn from(value: th::Players) -> Self {
+        Players {
+            players: value.players.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A string that borrows from the input unless it has escapes that have to be undone.
+///
+/// serde only borrows a `Cow` when it's the whole type of a field, so this wraps the ones inside an
+/// `Option` or a `Vec`.
+#[derive(serde::Deserialize)]
+#[serde(transparent)]
+pub struct BorrowedStr<'a>(#[serde(borrow)] pub Cow<'a, str>);
+
+impl From<BorrowedStr<'_>> for String {
+    fn from(value: BorrowedStr<'_>) -> Self {
+        value.0.into_owned()
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct BorrowedItem<'a> {
+    pub count: i8,
+    pub slot: u8,
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+}
+
+impl From<BorrowedItem<'_>> for Item {
+    fn from(value: BorrowedItem<'_>) -> Self {
+        Item {
+            count: value.count,
+            slot: value.slot,
+            id: value.id.into(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct BorrowedEntity<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    pub pos: (f64, f64, f64),
+    pub motion: (f64, f64, f64),
+    pub rotation: (f32, f32),
+    pub fall_distance: f32,
+    pub fire: u16,
+    pub air: u16,
+    pub on_ground: bool,
+    pub no_gravity: bool,
+    pub invulnerable: bool,
+    pub portal_cooldown: i32,
+    pub uuid: [u32; 4],
+    #[serde(borrow)]
+    pub custom_name: Option<BorrowedStr<'a>>,
+    pub custom_name_visible: bool,
+    pub silent: bool,
+    pub glowing: bool,
+}
+
+impl From<BorrowedEntity<'_>> for Entity {
+    fn from(value: BorrowedEntity<'_>) -> Self {
+        Entity {
+            id: value.id.into(),
+            pos: value.pos,
+            motion: value.motion,
+            rotation: value.rotation,
+            fall_distance: value.fall_distance,
+            fire: value.fire,
+            air: value.air,
+            on_ground: value.on_ground,
+            no_gravity: value.no_gravity,
+            invulnerable: value.invulnerable,
+            portal_cooldown: value.portal_cooldown,
+            uuid: value.uuid,
+            custom_name: value.custom_name.map(Into::into),
+            custom_name_visible: value.custom_name_visible,
+            silent: value.silent,
+            glowing: value.glowing,
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct BorrowedRecipeBook<'a> {
+    #[serde(borrow)]
+    pub recipes: Vec<BorrowedStr<'a>>,
+    #[serde(borrow)]
+    pub to_be_displayed: Vec<BorrowedStr<'a>>,
+    pub is_filtering_craftable: bool,
+    pub is_gui_open: bool,
+    pub is_furnace_filtering_craftable: bool,
+    pub is_furnace_gui_open: bool,
+    pub is_blasting_furnace_filtering_craftable: bool,
+    pub is_blasting_furnace_gui_open: bool,
+    pub is_smoker_filtering_craftable: bool,
+    pub is_smoker_gui_open: bool,
+}
+
+impl From<BorrowedRecipeBook<'_>> for RecipeBook {
+    fn from(value: BorrowedRecipeBook<'_>) -> Self {
+        RecipeBook {
+            recipes: value.recipes.into_iter().map(Into::into).collect(),
+            to_be_displayed: value.to_be_displayed.into_iter().map(Into::into).collect(),
+            is_filtering_craftable: value.is_filtering_craftable,
+            is_gui_open: value.is_gui_open,
+            is_furnace_filtering_craftable: value.is_furnace_filtering_craftable,
+            is_furnace_gui_open: value.is_furnace_gui_open,
+            is_blasting_furnace_filtering_craftable: value.is_blasting_furnace_filtering_craftable,
+            is_blasting_furnace_gui_open: value.is_blasting_furnace_gui_open,
+            is_smoker_filtering_craftable: value.is_smoker_filtering_craftable,
+            is_smoker_gui_open: value.is_smoker_gui_open,
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct BorrowedPlayer<'a> {
+    pub game_type: GameType,
+    pub previous_game_type: GameType,
+    pub score: i64,
+    #[serde(borrow)]
+    pub dimension: Cow<'a, str>,
+    pub selected_item_slot: u32,
+    #[serde(borrow)]
+    pub selected_item: BorrowedItem<'a>,
+    #[serde(borrow)]
+    pub spawn_dimension: Option<BorrowedStr<'a>>,
+    pub spawn_x: i64,
+    pub spawn_y: i64,
+    pub spawn_z: i64,
+    pub spawn_forced: Option<bool>,
+    pub sleep_timer: u16,
+    pub food_exhaustion_level: f32,
+    pub food_saturation_level: f32,
+    pub food_tick_timer: u32,
+    pub xp_level: u32,
+    pub xp_p: f32,
+    pub xp_total: i32,
+    pub xp_seed: i32,
+    #[serde(borrow)]
+    pub inventory: Vec<BorrowedItem<'a>>,
+    #[serde(borrow)]
+    pub ender_items: Vec<BorrowedItem<'a>>,
+    pub abilities: Abilities,
+    pub entered_nether_position: Option<(f64, f64, f64)>,
+    #[serde(borrow)]
+    pub root_vehicle: Option<([u32; 4], BorrowedEntity<'a>)>,
+    #[serde(borrow)]
+    pub shoulder_entity_left: Option<BorrowedEntity<'a>>,
+    #[serde(borrow)]
+    pub shoulder_entity_right: Option<BorrowedEntity<'a>>,
+    pub seen_credits: bool,
+    #[serde(borrow)]
+    pub recipe_book: BorrowedRecipeBook<'a>,
+}
+
+impl From<BorrowedPlayer<'_>> for Player {
+    fn from(value: BorrowedPlayer<'_>) -> Self {
+        Player {
+            game_type: value.game_type,
+            previous_game_type: value.previous_game_type,
+            score: value.score,
+            dimension: value.dimension.into(),
+            selected_item_slot: value.selected_item_slot,
+            selected_item: value.selected_item.into(),
+            spawn_dimension: value.spawn_dimension.map(Into::into),
+            spawn_x: value.spawn_x,
+            spawn_y: value.spawn_y,
+            spawn_z: value.spawn_z,
+            spawn_forced: value.spawn_forced,
+            sleep_timer: value.sleep_timer,
+            food_exhaustion_level: value.food_exhaustion_level,
+            food_saturation_level: value.food_saturation_level,
+            food_tick_timer: value.food_tick_timer,
+            xp_level: value.xp_level,
+            xp_p: value.xp_p,
+            xp_total: value.xp_total,
+            xp_seed: value.xp_seed,
+            inventory: value.inventory.into_iter().map(Into::into).collect(),
+            ender_items: value.ender_items.into_iter().map(Into::into).collect(),
+            abilities: value.abilities,
+            entered_nether_position: value.entered_nether_position,
+            root_vehicle: value
+                .root_vehicle
+                .map(|(uuid, entity)| (uuid, entity.into())),
+            shoulder_entity_left: value.shoulder_entity_left.map(Into::into),
+            shoulder_entity_right: value.shoulder_entity_right.map(Into::into),
+            seen_credits: value.seen_credits,
+            recipe_book: value.recipe_book.into(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct BorrowedPlayers<'a> {
+    #[serde(borrow)]
+    pub players: Vec<BorrowedPlayer<'a>>,
+}
+
+impl From<BorrowedPlayers<'_>> for Players {
+    fn from(value: BorrowedPlayers<'_>) -> Self {
+        Players {
+            players: value.players.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Borrowable for Players {
+    type Borrowed<'de> = BorrowedPlayers<'de>;
+}
+
+#[derive(serde::Deserialize)]
+pub struct ProjectedPlayer {
+    pub game_type: GameType,
+    pub previous_game_type: IgnoredAny,
+    pub score: IgnoredAny,
+    pub dimension: IgnoredAny,
+    pub selected_item_slot: IgnoredAny,
+    pub selected_item: IgnoredAny,
+    pub spawn_dimension: IgnoredAny,
+    pub spawn_x: IgnoredAny,
+    pub spawn_y: IgnoredAny,
+    pub spawn_z: IgnoredAny,
+    pub spawn_forced: IgnoredAny,
+    pub sleep_timer: IgnoredAny,
+    pub food_exhaustion_level: IgnoredAny,
+    pub food_saturation_level: IgnoredAny,
+    pub food_tick_timer: IgnoredAny,
+    pub xp_level: IgnoredAny,
+    pub xp_p: IgnoredAny,
+    pub xp_total: IgnoredAny,
+    pub xp_seed: IgnoredAny,
+    pub inventory: IgnoredAny,
+    pub ender_items: IgnoredAny,
+    pub abilities: IgnoredAny,
+    pub entered_nether_position: IgnoredAny,
+    pub root_vehicle: IgnoredAny,
+    pub shoulder_entity_left: IgnoredAny,
+    pub shoulder_entity_right: IgnoredAny,
+    pub seen_credits: IgnoredAny,
+    pub recipe_book: IgnoredAny,
+}
+
+#[derive(serde::Deserialize)]
+pub struct ProjectedPlayers {
+    pub players: Vec<ProjectedPlayer>,
+}
+
+impl From<ProjectedPlayers> for Vec<GameType> {
+    fn from(value: ProjectedPlayers) -> Self {
+        value
+            .players
+            .into_iter()
+            .map(|player| player.game_type)
+            .collect()
+    }
+}
+
+impl Projectable for Players {
+    type Fields = Vec<GameType>;
+    type Projection = ProjectedPlayers;
+
+    fn project(&self) -> Self::Fields {
+        self.players.iter().map(|player| player.game_type).collect()
+    }
+}
+
+impl ReadRandom for Players {
+    fn read_random(&self, indices: &[usize]) {
+        for &i in indices {
+            black_box(&self.players[i].game_type);
+        }
+    }
+}
+
+/// prost messages that only have `Player.game_type`; the rest of each player is skipped unparsed.
+#[cfg(feature = "prost")]
+pub mod projected_pb {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Player {
+        #[prost(int32, tag = "1")]
+        pub game_type: i32,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Players {
+        #[prost(message, repeated, tag = "1")]
+        pub players: Vec<Player>,
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<projected_pb::Players> for Vec<GameType> {
+    fn from(value: projected_pb::Players) -> Self {
+        value
+            .players
+            .into_iter()
+            .map(|player| pb::GameType::try_from(player.game_type).unwrap().into())
+            .collect()
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Project for Players {
+    type Projection = projected_pb::Players;
+}
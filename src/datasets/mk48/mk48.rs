@@ -0,0 +1,821 @@
+// Written by hand to follow the output of Thrift Compiler (0.17.0). build.rs doesn't regenerate
+// it, so keep it in step with the .thrift schema by hand.
+
+#![allow(unused_imports)]
+#![allow(unused_extern_crates)]
+#![allow(clippy::too_many_arguments, clippy::type_complexity, clippy::vec_box)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::{From, TryFrom};
+use std::default::Default;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+use thrift::OrderedFloat;
+use thrift::{ApplicationError, ApplicationErrorKind, ProtocolError, ProtocolErrorKind, TThriftClient};
+use thrift::protocol::{TFieldIdentifier, TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType, TInputProtocol, TOutputProtocol, TSerializable, TSetIdentifier, TStructIdentifier, TType};
+use thrift::protocol::field_id;
+use thrift::protocol::verify_expected_message_type;
+use thrift::protocol::verify_expected_sequence_number;
+use thrift::protocol::verify_expected_service_call;
+use thrift::protocol::verify_required_field_exists;
+use thrift::server::TProcessor;
+
+//
+// EntityType
+//
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct EntityType(pub i32);
+
+impl EntityType {
+  pub const ARLEIGH_BURKE: EntityType = EntityType(0);
+  pub const BISMARCK: EntityType = EntityType(1);
+  pub const CLEMENCEAU: EntityType = EntityType(2);
+  pub const FLETCHER: EntityType = EntityType(3);
+  pub const G5: EntityType = EntityType(4);
+  pub const IOWA: EntityType = EntityType(5);
+  pub const KOLKATA: EntityType = EntityType(6);
+  pub const OSA: EntityType = EntityType(7);
+  pub const YASEN: EntityType = EntityType(8);
+  pub const ZUBR: EntityType = EntityType(9);
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::ARLEIGH_BURKE,
+    Self::BISMARCK,
+    Self::CLEMENCEAU,
+    Self::FLETCHER,
+    Self::G5,
+    Self::IOWA,
+    Self::KOLKATA,
+    Self::OSA,
+    Self::YASEN,
+    Self::ZUBR,
+  ];
+}
+
+impl TSerializable for EntityType {
+  #[allow(clippy::trivially_copy_pass_by_ref)]
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    o_prot.write_i32(self.0)
+  }
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<EntityType> {
+    let enum_value = i_prot.read_i32()?;
+    Ok(EntityType::from(enum_value))
+  }
+}
+
+impl From<i32> for EntityType {
+  fn from(i: i32) -> Self {
+    match i {
+      0 => EntityType::ARLEIGH_BURKE,
+      1 => EntityType::BISMARCK,
+      2 => EntityType::CLEMENCEAU,
+      3 => EntityType::FLETCHER,
+      4 => EntityType::G5,
+      5 => EntityType::IOWA,
+      6 => EntityType::KOLKATA,
+      7 => EntityType::OSA,
+      8 => EntityType::YASEN,
+      9 => EntityType::ZUBR,
+      _ => EntityType(i)
+    }
+  }
+}
+
+impl From<&i32> for EntityType {
+  fn from(i: &i32) -> Self {
+    EntityType::from(*i)
+  }
+}
+
+impl From<EntityType> for i32 {
+  fn from(e: EntityType) -> i32 {
+    e.0
+  }
+}
+
+impl From<&EntityType> for i32 {
+  fn from(e: &EntityType) -> i32 {
+    e.0
+  }
+}
+
+//
+// Vector2f
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vector2f {
+  pub x: OrderedFloat<f64>,
+  pub y: OrderedFloat<f64>,
+}
+
+impl Vector2f {
+  pub fn new(x: OrderedFloat<f64>, y: OrderedFloat<f64>) -> Vector2f {
+    Vector2f {
+      x,
+      y,
+    }
+  }
+}
+
+impl TSerializable for Vector2f {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Vector2f> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<OrderedFloat<f64>> = None;
+    let mut f_2: Option<OrderedFloat<f64>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_2 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Vector2f.x", &f_1)?;
+    verify_required_field_exists("Vector2f.y", &f_2)?;
+    let ret = Vector2f {
+      x: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      y: f_2.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Vector2f");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x", TType::Double, 1))?;
+    o_prot.write_double(self.x.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("y", TType::Double, 2))?;
+    o_prot.write_double(self.y.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Transform
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Transform {
+  pub altitude: i8,
+  pub angle: i16,
+  pub position: Vector2f,
+  pub velocity: i16,
+}
+
+impl Transform {
+  pub fn new(altitude: i8, angle: i16, position: Vector2f, velocity: i16) -> Transform {
+    Transform {
+      altitude,
+      angle,
+      position,
+      velocity,
+    }
+  }
+}
+
+impl TSerializable for Transform {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Transform> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i8> = None;
+    let mut f_2: Option<i16> = None;
+    let mut f_3: Option<Vector2f> = None;
+    let mut f_4: Option<i16> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i8()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_i16()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = Vector2f::read_from_in_protocol(i_prot)?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = i_prot.read_i16()?;
+          f_4 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Transform.altitude", &f_1)?;
+    verify_required_field_exists("Transform.angle", &f_2)?;
+    verify_required_field_exists("Transform.position", &f_3)?;
+    verify_required_field_exists("Transform.velocity", &f_4)?;
+    let ret = Transform {
+      altitude: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      angle: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      position: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      velocity: f_4.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Transform");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("altitude", TType::I08, 1))?;
+    o_prot.write_i8(self.altitude)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("angle", TType::I16, 2))?;
+    o_prot.write_i16(self.angle)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("position", TType::Struct, 3))?;
+    self.position.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("velocity", TType::I16, 4))?;
+    o_prot.write_i16(self.velocity)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Guidance
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Guidance {
+  pub angle: i16,
+  pub submerge: bool,
+  pub velocity: i16,
+}
+
+impl Guidance {
+  pub fn new(angle: i16, submerge: bool, velocity: i16) -> Guidance {
+    Guidance {
+      angle,
+      submerge,
+      velocity,
+    }
+  }
+}
+
+impl TSerializable for Guidance {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Guidance> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i16> = None;
+    let mut f_2: Option<bool> = None;
+    let mut f_3: Option<i16> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i16()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_bool()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_i16()?;
+          f_3 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Guidance.angle", &f_1)?;
+    verify_required_field_exists("Guidance.submerge", &f_2)?;
+    verify_required_field_exists("Guidance.velocity", &f_3)?;
+    let ret = Guidance {
+      angle: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      submerge: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      velocity: f_3.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Guidance");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("angle", TType::I16, 1))?;
+    o_prot.write_i16(self.angle)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("submerge", TType::Bool, 2))?;
+    o_prot.write_bool(self.submerge)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("velocity", TType::I16, 3))?;
+    o_prot.write_i16(self.velocity)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Contact
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Contact {
+  pub damage: i8,
+  pub entity_id: i32,
+  pub entity_type: Option<EntityType>,
+  pub guidance: Guidance,
+  pub player_id: Option<i16>,
+  pub reloads: Vec<bool>,
+  pub transform: Transform,
+  pub turret_angles: Vec<i16>,
+}
+
+impl Contact {
+  pub fn new<F3, F5>(damage: i8, entity_id: i32, entity_type: F3, guidance: Guidance, player_id: F5, reloads: Vec<bool>, transform: Transform, turret_angles: Vec<i16>) -> Contact where F3: Into<Option<EntityType>>, F5: Into<Option<i16>> {
+    Contact {
+      damage,
+      entity_id,
+      entity_type: entity_type.into(),
+      guidance,
+      player_id: player_id.into(),
+      reloads,
+      transform,
+      turret_angles,
+    }
+  }
+}
+
+impl TSerializable for Contact {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Contact> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i8> = None;
+    let mut f_2: Option<i32> = None;
+    let mut f_3: Option<EntityType> = None;
+    let mut f_4: Option<Guidance> = None;
+    let mut f_5: Option<i16> = None;
+    let mut f_6: Option<Vec<bool>> = None;
+    let mut f_7: Option<Transform> = None;
+    let mut f_8: Option<Vec<i16>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i8()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_i32()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = EntityType::read_from_in_protocol(i_prot)?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = Guidance::read_from_in_protocol(i_prot)?;
+          f_4 = Some(val);
+        },
+        5 => {
+          let val = i_prot.read_i16()?;
+          f_5 = Some(val);
+        },
+        6 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<bool> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_0 = i_prot.read_bool()?;
+            val.push(list_elem_0);
+          }
+          i_prot.read_list_end()?;
+          f_6 = Some(val);
+        },
+        7 => {
+          let val = Transform::read_from_in_protocol(i_prot)?;
+          f_7 = Some(val);
+        },
+        8 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<i16> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_1 = i_prot.read_i16()?;
+            val.push(list_elem_1);
+          }
+          i_prot.read_list_end()?;
+          f_8 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Contact.damage", &f_1)?;
+    verify_required_field_exists("Contact.entity_id", &f_2)?;
+    verify_required_field_exists("Contact.guidance", &f_4)?;
+    verify_required_field_exists("Contact.reloads", &f_6)?;
+    verify_required_field_exists("Contact.transform", &f_7)?;
+    verify_required_field_exists("Contact.turret_angles", &f_8)?;
+    let ret = Contact {
+      damage: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      entity_id: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      entity_type: f_3,
+      guidance: f_4.expect("auto-generated code should have checked for presence of required fields"),
+      player_id: f_5,
+      reloads: f_6.expect("auto-generated code should have checked for presence of required fields"),
+      transform: f_7.expect("auto-generated code should have checked for presence of required fields"),
+      turret_angles: f_8.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Contact");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("damage", TType::I08, 1))?;
+    o_prot.write_i8(self.damage)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("entity_id", TType::I32, 2))?;
+    o_prot.write_i32(self.entity_id)?;
+    o_prot.write_field_end()?;
+    if let Some(fld_var) = self.entity_type {
+      o_prot.write_field_begin(&TFieldIdentifier::new("entity_type", TType::I32, 3))?;
+      fld_var.write_to_out_protocol(o_prot)?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_begin(&TFieldIdentifier::new("guidance", TType::Struct, 4))?;
+    self.guidance.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    if let Some(fld_var) = self.player_id {
+      o_prot.write_field_begin(&TFieldIdentifier::new("player_id", TType::I16, 5))?;
+      o_prot.write_i16(fld_var)?;
+      o_prot.write_field_end()?
+    }
+    o_prot.write_field_begin(&TFieldIdentifier::new("reloads", TType::List, 6))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Bool, self.reloads.len() as i32))?;
+    for e in &self.reloads {
+      o_prot.write_bool(*e)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("transform", TType::Struct, 7))?;
+    self.transform.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("turret_angles", TType::List, 8))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::I16, self.turret_angles.len() as i32))?;
+    for e in &self.turret_angles {
+      o_prot.write_i16(*e)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// ChunkId
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ChunkId {
+  pub x: i8,
+  pub y: i8,
+}
+
+impl ChunkId {
+  pub fn new(x: i8, y: i8) -> ChunkId {
+    ChunkId {
+      x,
+      y,
+    }
+  }
+}
+
+impl TSerializable for ChunkId {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<ChunkId> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i8> = None;
+    let mut f_2: Option<i8> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i8()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_i8()?;
+          f_2 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("ChunkId.x", &f_1)?;
+    verify_required_field_exists("ChunkId.y", &f_2)?;
+    let ret = ChunkId {
+      x: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      y: f_2.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("ChunkId");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("x", TType::I08, 1))?;
+    o_prot.write_i8(self.x)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("y", TType::I08, 2))?;
+    o_prot.write_i8(self.y)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// TerrainUpdate
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TerrainUpdate {
+  pub chunk_id: ChunkId,
+  pub data: Vec<u8>,
+}
+
+impl TerrainUpdate {
+  pub fn new(chunk_id: ChunkId, data: Vec<u8>) -> TerrainUpdate {
+    TerrainUpdate {
+      chunk_id,
+      data,
+    }
+  }
+}
+
+impl TSerializable for TerrainUpdate {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<TerrainUpdate> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<ChunkId> = None;
+    let mut f_2: Option<Vec<u8>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = ChunkId::read_from_in_protocol(i_prot)?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_bytes()?;
+          f_2 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("TerrainUpdate.chunk_id", &f_1)?;
+    verify_required_field_exists("TerrainUpdate.data", &f_2)?;
+    let ret = TerrainUpdate {
+      chunk_id: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      data: f_2.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("TerrainUpdate");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("chunk_id", TType::Struct, 1))?;
+    self.chunk_id.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("data", TType::String, 2))?;
+    o_prot.write_bytes(&self.data)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Update
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Update {
+  pub contacts: Vec<Contact>,
+  pub score: i32,
+  pub world_radius: OrderedFloat<f64>,
+  pub terrain_updates: Vec<TerrainUpdate>,
+}
+
+impl Update {
+  pub fn new(contacts: Vec<Contact>, score: i32, world_radius: OrderedFloat<f64>, terrain_updates: Vec<TerrainUpdate>) -> Update {
+    Update {
+      contacts,
+      score,
+      world_radius,
+      terrain_updates,
+    }
+  }
+}
+
+impl TSerializable for Update {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Update> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<Contact>> = None;
+    let mut f_2: Option<i32> = None;
+    let mut f_3: Option<OrderedFloat<f64>> = None;
+    let mut f_4: Option<Vec<TerrainUpdate>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Contact> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_2 = Contact::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_2);
+          }
+          i_prot.read_list_end()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_i32()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          f_3 = Some(val);
+        },
+        4 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<TerrainUpdate> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_3 = TerrainUpdate::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_3);
+          }
+          i_prot.read_list_end()?;
+          f_4 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Update.contacts", &f_1)?;
+    verify_required_field_exists("Update.score", &f_2)?;
+    verify_required_field_exists("Update.world_radius", &f_3)?;
+    verify_required_field_exists("Update.terrain_updates", &f_4)?;
+    let ret = Update {
+      contacts: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      score: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      world_radius: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      terrain_updates: f_4.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Update");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("contacts", TType::List, 1))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.contacts.len() as i32))?;
+    for e in &self.contacts {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("score", TType::I32, 2))?;
+    o_prot.write_i32(self.score)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("world_radius", TType::Double, 3))?;
+    o_prot.write_double(self.world_radius.into())?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("terrain_updates", TType::List, 4))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.terrain_updates.len() as i32))?;
+    for e in &self.terrain_updates {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Updates
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Updates {
+  pub updates: Vec<Update>,
+}
+
+impl Updates {
+  pub fn new(updates: Vec<Update>) -> Updates {
+    Updates {
+      updates,
+    }
+  }
+}
+
+impl TSerializable for Updates {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Updates> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<Update>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Update> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_4 = Update::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_4);
+          }
+          i_prot.read_list_end()?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Updates.updates", &f_1)?;
+    let ret = Updates {
+      updates: f_1.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Updates");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("updates", TType::List, 1))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.updates.len() as i32))?;
+    for e in &self.updates {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
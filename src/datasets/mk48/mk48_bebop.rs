@@ -0,0 +1,683 @@
+// Written by hand to follow the output of bebopc 3.2.3, which downloads from GitHub and so
+// couldn't be run when this was added. build.rs doesn't regenerate it, so keep it in step with the
+// .bop schema by hand.
+
+#![allow(warnings)]
+
+use ::bebop::FixedSized as _;
+use ::bebop::SubRecord as _;
+use ::core::convert::TryInto as _;
+use ::std::io::Write as _;
+
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EntityType {
+    ArleighBurke = 0,
+    Bismarck = 1,
+    Clemenceau = 2,
+    Fletcher = 3,
+    G5 = 4,
+    Iowa = 5,
+    Kolkata = 6,
+    Osa = 7,
+    Yasen = 8,
+    Zubr = 9,
+}
+
+impl ::core::convert::TryFrom<u8> for EntityType {
+    type Error = ::bebop::DeserializeError;
+
+    fn try_from(value: u8) -> ::bebop::DeResult<Self> {
+        match value {
+            0 => Ok(EntityType::ArleighBurke),
+            1 => Ok(EntityType::Bismarck),
+            2 => Ok(EntityType::Clemenceau),
+            3 => Ok(EntityType::Fletcher),
+            4 => Ok(EntityType::G5),
+            5 => Ok(EntityType::Iowa),
+            6 => Ok(EntityType::Kolkata),
+            7 => Ok(EntityType::Osa),
+            8 => Ok(EntityType::Yasen),
+            9 => Ok(EntityType::Zubr),
+            d => Err(::bebop::DeserializeError::InvalidEnumDiscriminator(
+                d.into(),
+            )),
+        }
+    }
+}
+
+impl ::core::convert::From<EntityType> for u8 {
+    fn from(value: EntityType) -> Self {
+        match value {
+            EntityType::ArleighBurke => 0,
+            EntityType::Bismarck => 1,
+            EntityType::Clemenceau => 2,
+            EntityType::Fletcher => 3,
+            EntityType::G5 => 4,
+            EntityType::Iowa => 5,
+            EntityType::Kolkata => 6,
+            EntityType::Osa => 7,
+            EntityType::Yasen => 8,
+            EntityType::Zubr => 9,
+        }
+    }
+}
+
+impl<'raw> ::bebop::SubRecord<'raw> for EntityType {
+    const MIN_SERIALIZED_SIZE: usize = ::std::mem::size_of::<u8>();
+    const EXACT_SERIALIZED_SIZE: Option<usize> = Some(::std::mem::size_of::<u8>());
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        ::std::mem::size_of::<u8>()
+    }
+
+    ::bebop::define_serialize_chained!(*Self => |zelf, dest| {
+        u8::from(zelf)._serialize_chained(dest)
+    });
+
+    #[inline]
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let (n, v) = u8::_deserialize_chained(raw)?;
+        Ok((n, v.try_into()?))
+    }
+}
+
+impl ::bebop::FixedSized for EntityType {
+    const SERIALIZED_SIZE: usize = ::std::mem::size_of::<u8>();
+}
+
+#[derive(Clone, Debug, PartialEq, Copy)]
+#[repr(packed)]
+pub struct Vector2f {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl ::bebop::FixedSized for Vector2f {}
+
+impl<'raw> ::bebop::SubRecord<'raw> for Vector2f {
+    const MIN_SERIALIZED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const EXACT_SERIALIZED_SIZE: Option<usize> = Some(Self::SERIALIZED_SIZE);
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+
+    ::bebop::define_serialize_chained!(*Self => |zelf, dest| {
+        Ok(
+            ::bebop::packed_read!(zelf.x)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.y)._serialize_chained(dest)?
+        )
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        if raw.len() - i < Self::MIN_SERIALIZED_SIZE {
+            let missing = Self::MIN_SERIALIZED_SIZE - (raw.len() - i);
+            return Err(::bebop::DeserializeError::MoreDataExpected(missing));
+        }
+
+        let (read, v0) = <f32>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v1) = <f32>::_deserialize_chained(&raw[i..])?;
+        i += read;
+
+        Ok((i, Self { x: v0, y: v1 }))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for Vector2f {}
+
+#[derive(Clone, Debug, PartialEq, Copy)]
+#[repr(packed)]
+pub struct Transform {
+    pub altitude: u8,
+    pub angle: u16,
+    pub position: Vector2f,
+    pub velocity: i16,
+}
+
+impl ::bebop::FixedSized for Transform {}
+
+impl<'raw> ::bebop::SubRecord<'raw> for Transform {
+    const MIN_SERIALIZED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const EXACT_SERIALIZED_SIZE: Option<usize> = Some(Self::SERIALIZED_SIZE);
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+
+    ::bebop::define_serialize_chained!(*Self => |zelf, dest| {
+        Ok(
+            ::bebop::packed_read!(zelf.altitude)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.angle)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.position)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.velocity)._serialize_chained(dest)?
+        )
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        if raw.len() - i < Self::MIN_SERIALIZED_SIZE {
+            let missing = Self::MIN_SERIALIZED_SIZE - (raw.len() - i);
+            return Err(::bebop::DeserializeError::MoreDataExpected(missing));
+        }
+
+        let (read, v0) = <u8>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v1) = <u16>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v2) = <Vector2f>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v3) = <i16>::_deserialize_chained(&raw[i..])?;
+        i += read;
+
+        Ok((
+            i,
+            Self {
+                altitude: v0,
+                angle: v1,
+                position: v2,
+                velocity: v3,
+            },
+        ))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for Transform {}
+
+#[derive(Clone, Debug, PartialEq, Copy)]
+#[repr(packed)]
+pub struct Guidance {
+    pub angle: u16,
+    pub submerge: bool,
+    pub velocity: i16,
+}
+
+impl ::bebop::FixedSized for Guidance {}
+
+impl<'raw> ::bebop::SubRecord<'raw> for Guidance {
+    const MIN_SERIALIZED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const EXACT_SERIALIZED_SIZE: Option<usize> = Some(Self::SERIALIZED_SIZE);
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+
+    ::bebop::define_serialize_chained!(*Self => |zelf, dest| {
+        Ok(
+            ::bebop::packed_read!(zelf.angle)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.submerge)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.velocity)._serialize_chained(dest)?
+        )
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        if raw.len() - i < Self::MIN_SERIALIZED_SIZE {
+            let missing = Self::MIN_SERIALIZED_SIZE - (raw.len() - i);
+            return Err(::bebop::DeserializeError::MoreDataExpected(missing));
+        }
+
+        let (read, v0) = <u16>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v1) = <bool>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v2) = <i16>::_deserialize_chained(&raw[i..])?;
+        i += read;
+
+        Ok((
+            i,
+            Self {
+                angle: v0,
+                submerge: v1,
+                velocity: v2,
+            },
+        ))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for Guidance {}
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Contact<'raw> {
+    /// Field 1
+    pub damage: ::core::option::Option<u8>,
+    /// Field 2
+    pub entity_id: ::core::option::Option<u32>,
+    /// Field 3
+    pub entity_type: ::core::option::Option<EntityType>,
+    /// Field 4
+    pub guidance: ::core::option::Option<Guidance>,
+    /// Field 5
+    pub player_id: ::core::option::Option<u16>,
+    /// Field 6
+    pub reloads: ::core::option::Option<::bebop::SliceWrapper<'raw, bool>>,
+    /// Field 7
+    pub transform: ::core::option::Option<Transform>,
+    /// Field 8
+    pub turret_angles: ::core::option::Option<::bebop::SliceWrapper<'raw, u16>>,
+}
+
+impl<'raw> ::bebop::SubRecord<'raw> for Contact<'raw> {
+    const MIN_SERIALIZED_SIZE: usize = ::bebop::LEN_SIZE + 1;
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        ::bebop::LEN_SIZE
+            + 1
+            + self
+                .damage
+                .as_ref()
+                .map(|v| v.serialized_size() + 1)
+                .unwrap_or(0)
+            + self
+                .entity_id
+                .as_ref()
+                .map(|v| v.serialized_size() + 1)
+                .unwrap_or(0)
+            + self
+                .entity_type
+                .as_ref()
+                .map(|v| v.serialized_size() + 1)
+                .unwrap_or(0)
+            + self
+                .guidance
+                .as_ref()
+                .map(|v| v.serialized_size() + 1)
+                .unwrap_or(0)
+            + self
+                .player_id
+                .as_ref()
+                .map(|v| v.serialized_size() + 1)
+                .unwrap_or(0)
+            + self
+                .reloads
+                .as_ref()
+                .map(|v| v.serialized_size() + 1)
+                .unwrap_or(0)
+            + self
+                .transform
+                .as_ref()
+                .map(|v| v.serialized_size() + 1)
+                .unwrap_or(0)
+            + self
+                .turret_angles
+                .as_ref()
+                .map(|v| v.serialized_size() + 1)
+                .unwrap_or(0)
+    }
+
+    ::bebop::define_serialize_chained!(Self => |zelf, dest| {
+        let size = zelf.serialized_size();
+        ::bebop::write_len(dest, size - ::bebop::LEN_SIZE)?;
+        if let Some(ref v) = zelf.damage {
+            1u8._serialize_chained(dest)?;
+            v._serialize_chained(dest)?;
+        }
+        if let Some(ref v) = zelf.entity_id {
+            2u8._serialize_chained(dest)?;
+            v._serialize_chained(dest)?;
+        }
+        if let Some(ref v) = zelf.entity_type {
+            3u8._serialize_chained(dest)?;
+            v._serialize_chained(dest)?;
+        }
+        if let Some(ref v) = zelf.guidance {
+            4u8._serialize_chained(dest)?;
+            v._serialize_chained(dest)?;
+        }
+        if let Some(ref v) = zelf.player_id {
+            5u8._serialize_chained(dest)?;
+            v._serialize_chained(dest)?;
+        }
+        if let Some(ref v) = zelf.reloads {
+            6u8._serialize_chained(dest)?;
+            v._serialize_chained(dest)?;
+        }
+        if let Some(ref v) = zelf.transform {
+            7u8._serialize_chained(dest)?;
+            v._serialize_chained(dest)?;
+        }
+        if let Some(ref v) = zelf.turret_angles {
+            8u8._serialize_chained(dest)?;
+            v._serialize_chained(dest)?;
+        }
+        0u8._serialize_chained(dest)?;
+        Ok(size)
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        let len = ::bebop::read_len(&raw[i..])? + ::bebop::LEN_SIZE;
+        i += ::bebop::LEN_SIZE;
+
+        #[cfg(not(feature = "unchecked"))]
+        if len == 0 {
+            return Err(::bebop::DeserializeError::CorruptFrame);
+        }
+
+        if raw.len() < len {
+            return Err(::bebop::DeserializeError::MoreDataExpected(len - raw.len()));
+        }
+
+        let mut _damage = None;
+        let mut _entity_id = None;
+        let mut _entity_type = None;
+        let mut _guidance = None;
+        let mut _player_id = None;
+        let mut _reloads = None;
+        let mut _transform = None;
+        let mut _turret_angles = None;
+
+        #[cfg(not(feature = "unchecked"))]
+        let mut last = 0;
+
+        while i < len {
+            let di = raw[i];
+
+            #[cfg(not(feature = "unchecked"))]
+            if di != 0 {
+                if di < last {
+                    return Err(::bebop::DeserializeError::CorruptFrame);
+                }
+                last = di;
+            }
+
+            i += 1;
+            match di {
+                0 => {
+                    break;
+                }
+                1 => {
+                    #[cfg(not(feature = "unchecked"))]
+                    if _damage.is_some() {
+                        return Err(::bebop::DeserializeError::DuplicateMessageField);
+                    }
+                    let (read, value) = <u8>::_deserialize_chained(&raw[i..])?;
+                    i += read;
+                    _damage = Some(value)
+                }
+                2 => {
+                    #[cfg(not(feature = "unchecked"))]
+                    if _entity_id.is_some() {
+                        return Err(::bebop::DeserializeError::DuplicateMessageField);
+                    }
+                    let (read, value) = <u32>::_deserialize_chained(&raw[i..])?;
+                    i += read;
+                    _entity_id = Some(value)
+                }
+                3 => {
+                    #[cfg(not(feature = "unchecked"))]
+                    if _entity_type.is_some() {
+                        return Err(::bebop::DeserializeError::DuplicateMessageField);
+                    }
+                    let (read, value) = <EntityType>::_deserialize_chained(&raw[i..])?;
+                    i += read;
+                    _entity_type = Some(value)
+                }
+                4 => {
+                    #[cfg(not(feature = "unchecked"))]
+                    if _guidance.is_some() {
+                        return Err(::bebop::DeserializeError::DuplicateMessageField);
+                    }
+                    let (read, value) = <Guidance>::_deserialize_chained(&raw[i..])?;
+                    i += read;
+                    _guidance = Some(value)
+                }
+                5 => {
+                    #[cfg(not(feature = "unchecked"))]
+                    if _player_id.is_some() {
+                        return Err(::bebop::DeserializeError::DuplicateMessageField);
+                    }
+                    let (read, value) = <u16>::_deserialize_chained(&raw[i..])?;
+                    i += read;
+                    _player_id = Some(value)
+                }
+                6 => {
+                    #[cfg(not(feature = "unchecked"))]
+                    if _reloads.is_some() {
+                        return Err(::bebop::DeserializeError::DuplicateMessageField);
+                    }
+                    let (read, value) =
+                        <::bebop::SliceWrapper<'raw, bool>>::_deserialize_chained(&raw[i..])?;
+                    i += read;
+                    _reloads = Some(value)
+                }
+                7 => {
+                    #[cfg(not(feature = "unchecked"))]
+                    if _transform.is_some() {
+                        return Err(::bebop::DeserializeError::DuplicateMessageField);
+                    }
+                    let (read, value) = <Transform>::_deserialize_chained(&raw[i..])?;
+                    i += read;
+                    _transform = Some(value)
+                }
+                8 => {
+                    #[cfg(not(feature = "unchecked"))]
+                    if _turret_angles.is_some() {
+                        return Err(::bebop::DeserializeError::DuplicateMessageField);
+                    }
+                    let (read, value) =
+                        <::bebop::SliceWrapper<'raw, u16>>::_deserialize_chained(&raw[i..])?;
+                    i += read;
+                    _turret_angles = Some(value)
+                }
+                _ => {
+                    i = len;
+                    break;
+                }
+            }
+        }
+
+        if i != len {
+            debug_assert!(i > len);
+            return Err(::bebop::DeserializeError::CorruptFrame);
+        }
+
+        Ok((
+            i,
+            Self {
+                damage: _damage,
+                entity_id: _entity_id,
+                entity_type: _entity_type,
+                guidance: _guidance,
+                player_id: _player_id,
+                reloads: _reloads,
+                transform: _transform,
+                turret_angles: _turret_angles,
+            },
+        ))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for Contact<'raw> {}
+
+#[derive(Clone, Debug, PartialEq, Copy)]
+#[repr(packed)]
+pub struct ChunkId {
+    pub x: u8,
+    pub y: u8,
+}
+
+impl ::bebop::FixedSized for ChunkId {}
+
+impl<'raw> ::bebop::SubRecord<'raw> for ChunkId {
+    const MIN_SERIALIZED_SIZE: usize = Self::SERIALIZED_SIZE;
+    const EXACT_SERIALIZED_SIZE: Option<usize> = Some(Self::SERIALIZED_SIZE);
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+
+    ::bebop::define_serialize_chained!(*Self => |zelf, dest| {
+        Ok(
+            ::bebop::packed_read!(zelf.x)._serialize_chained(dest)? +
+            ::bebop::packed_read!(zelf.y)._serialize_chained(dest)?
+        )
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        if raw.len() - i < Self::MIN_SERIALIZED_SIZE {
+            let missing = Self::MIN_SERIALIZED_SIZE - (raw.len() - i);
+            return Err(::bebop::DeserializeError::MoreDataExpected(missing));
+        }
+
+        let (read, v0) = <u8>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v1) = <u8>::_deserialize_chained(&raw[i..])?;
+        i += read;
+
+        Ok((i, Self { x: v0, y: v1 }))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for ChunkId {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TerrainUpdate<'raw> {
+    pub chunk_id: ChunkId,
+    pub data: ::bebop::SliceWrapper<'raw, u8>,
+}
+
+impl<'raw> ::bebop::SubRecord<'raw> for TerrainUpdate<'raw> {
+    const MIN_SERIALIZED_SIZE: usize =
+        <ChunkId>::MIN_SERIALIZED_SIZE + <::bebop::SliceWrapper<'raw, u8>>::MIN_SERIALIZED_SIZE;
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.chunk_id.serialized_size() + self.data.serialized_size()
+    }
+
+    ::bebop::define_serialize_chained!(Self => |zelf, dest| {
+        Ok(
+            zelf.chunk_id._serialize_chained(dest)? +
+            zelf.data._serialize_chained(dest)?
+        )
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        if raw.len() - i < Self::MIN_SERIALIZED_SIZE {
+            let missing = Self::MIN_SERIALIZED_SIZE - (raw.len() - i);
+            return Err(::bebop::DeserializeError::MoreDataExpected(missing));
+        }
+
+        let (read, v0) = <ChunkId>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v1) = <::bebop::SliceWrapper<'raw, u8>>::_deserialize_chained(&raw[i..])?;
+        i += read;
+
+        Ok((
+            i,
+            Self {
+                chunk_id: v0,
+                data: v1,
+            },
+        ))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for TerrainUpdate<'raw> {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Update<'raw> {
+    pub contacts: ::std::vec::Vec<Contact<'raw>>,
+    pub score: u32,
+    pub world_radius: f32,
+    pub terrain_updates: ::std::vec::Vec<TerrainUpdate<'raw>>,
+}
+
+impl<'raw> ::bebop::SubRecord<'raw> for Update<'raw> {
+    const MIN_SERIALIZED_SIZE: usize = <::std::vec::Vec<Contact<'raw>>>::MIN_SERIALIZED_SIZE
+        + <u32>::MIN_SERIALIZED_SIZE
+        + <f32>::MIN_SERIALIZED_SIZE
+        + <::std::vec::Vec<TerrainUpdate<'raw>>>::MIN_SERIALIZED_SIZE;
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.contacts.serialized_size()
+            + self.score.serialized_size()
+            + self.world_radius.serialized_size()
+            + self.terrain_updates.serialized_size()
+    }
+
+    ::bebop::define_serialize_chained!(Self => |zelf, dest| {
+        Ok(
+            zelf.contacts._serialize_chained(dest)? +
+            zelf.score._serialize_chained(dest)? +
+            zelf.world_radius._serialize_chained(dest)? +
+            zelf.terrain_updates._serialize_chained(dest)?
+        )
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        if raw.len() - i < Self::MIN_SERIALIZED_SIZE {
+            let missing = Self::MIN_SERIALIZED_SIZE - (raw.len() - i);
+            return Err(::bebop::DeserializeError::MoreDataExpected(missing));
+        }
+
+        let (read, v0) = <::std::vec::Vec<Contact<'raw>>>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v1) = <u32>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v2) = <f32>::_deserialize_chained(&raw[i..])?;
+        i += read;
+        let (read, v3) = <::std::vec::Vec<TerrainUpdate<'raw>>>::_deserialize_chained(&raw[i..])?;
+        i += read;
+
+        Ok((
+            i,
+            Self {
+                contacts: v0,
+                score: v1,
+                world_radius: v2,
+                terrain_updates: v3,
+            },
+        ))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for Update<'raw> {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Updates<'raw> {
+    pub updates: ::std::vec::Vec<Update<'raw>>,
+}
+
+impl<'raw> ::bebop::SubRecord<'raw> for Updates<'raw> {
+    const MIN_SERIALIZED_SIZE: usize = <::std::vec::Vec<Update<'raw>>>::MIN_SERIALIZED_SIZE;
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.updates.serialized_size()
+    }
+
+    ::bebop::define_serialize_chained!(Self => |zelf, dest| {
+        Ok(
+            zelf.updates._serialize_chained(dest)?
+        )
+    });
+
+    fn _deserialize_chained(raw: &'raw [u8]) -> ::bebop::DeResult<(usize, Self)> {
+        let mut i = 0;
+        if raw.len() - i < Self::MIN_SERIALIZED_SIZE {
+            let missing = Self::MIN_SERIALIZED_SIZE - (raw.len() - i);
+            return Err(::bebop::DeserializeError::MoreDataExpected(missing));
+        }
+
+        let (read, v0) = <::std::vec::Vec<Update<'raw>>>::_deserialize_chained(&raw[i..])?;
+        i += read;
+
+        Ok((i, Self { updates: v0 }))
+    }
+}
+
+impl<'raw> ::bebop::Record<'raw> for Updates<'raw> {}
@@ -0,0 +1,79 @@
+//! SimpleSerialize containers for the mk48 data set.
+//!
+//! SSZ only has unsigned integers, so signed integers are stored as their two's complement and
+//! floats as their bits.
+
+use ssz_derive::{Decode, Encode};
+
+#[derive(Encode, Decode)]
+#[ssz(enum_behaviour = "tag")]
+pub enum EntityType {
+    ArleighBurke,
+    Bismarck,
+    Clemenceau,
+    Fletcher,
+    G5,
+    Iowa,
+    Kolkata,
+    Osa,
+    Yasen,
+    Zubr,
+}
+
+#[derive(Encode, Decode)]
+pub struct Vector2f {
+    pub x: u32,
+    pub y: u32,
+}
+
+#[derive(Encode, Decode)]
+pub struct Transform {
+    pub altitude: u8,
+    pub angle: u16,
+    pub position: Vector2f,
+    pub velocity: u16,
+}
+
+#[derive(Encode, Decode)]
+pub struct Guidance {
+    pub angle: u16,
+    pub submerge: bool,
+    pub velocity: u16,
+}
+
+#[derive(Encode, Decode)]
+pub struct Contact {
+    pub damage: u8,
+    pub entity_id: u32,
+    pub entity_type: Option<EntityType>,
+    pub guidance: Guidance,
+    pub player_id: Option<u16>,
+    pub reloads: Vec<bool>,
+    pub transform: Transform,
+    pub turret_angles: Vec<u16>,
+}
+
+#[derive(Encode, Decode)]
+pub struct ChunkId {
+    pub x: u8,
+    pub y: u8,
+}
+
+#[derive(Encode, Decode)]
+pub struct TerrainUpdate {
+    pub chunk_id: ChunkId,
+    pub data: Vec<u8>,
+}
+
+#[derive(Encode, Decode)]
+pub struct Update {
+    pub contacts: Vec<Contact>,
+    pub score: u32,
+    pub world_radius: u32,
+    pub terrain_updates: Vec<TerrainUpdate>,
+}
+
+#[derive(Encode, Decode)]
+pub struct Updates {
+    pub updates: Vec<Update>,
+}
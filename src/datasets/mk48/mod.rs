@@ -1,3 +1,5 @@
+#[cfg(feature = "bebop")]
+pub mod mk48_bebop;
 #[cfg(any(feature = "capnp", feature = "prost"))]
 pub mod mk48_capnp;
 #[cfg(feature = "flatbuffers")]
@@ -7,7 +9,17 @@ pub mod mk48_fb;
 #[cfg(feature = "prost")]
 #[path = "prost.mk48.rs"]
 pub mod mk48_prost;
-
+#[cfg(feature = "protobuf")]
+pub mod mk48_protobuf;
+#[cfg(feature = "ssz")]
+pub mod mk48_ssz;
+#[cfg(feature = "thrift")]
+#[path = "mk48.rs"]
+#[allow(clippy::all)]
+pub mod mk48_thrift;
+
+#[cfg(feature = "bebop")]
+use bebop::{Record, SeResult, SliceWrapper};
 #[cfg(feature = "flatbuffers")]
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
 #[cfg(any(feature = "capnp", feature = "prost"))]
@@ -16,19 +28,34 @@ pub use mk48_capnp as cp;
 pub use mk48_fb::mk_48 as fb;
 #[cfg(feature = "prost")]
 use mk48_prost as pb;
+#[cfg(feature = "thrift")]
+use mk48_thrift as th;
 #[cfg(feature = "nanoserde")]
 use nanoserde::{DeBin, SerBin};
 use rand::Rng;
+use serde::de::IgnoredAny;
+#[cfg(feature = "thrift")]
+use thrift::OrderedFloat;
 #[cfg(feature = "wiring")]
 use wiring::prelude::{Unwiring, Wiring};
 
+#[cfg(feature = "apache-avro")]
+use crate::bench_avro;
+#[cfg(feature = "bebop")]
+use crate::bench_bebop;
 #[cfg(feature = "capnp")]
 use crate::bench_capnp;
+#[cfg(feature = "ssz")]
+use crate::bench_ethereum_ssz;
 #[cfg(feature = "flatbuffers")]
 use crate::bench_flatbuffers;
 #[cfg(feature = "prost")]
 use crate::bench_prost;
-use crate::{generate_vec, Generate};
+#[cfg(feature = "protobuf")]
+use crate::bench_protobuf;
+#[cfg(feature = "thrift")]
+use crate::bench_thrift;
+use crate::{generate_vec, Generate, Projectable};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Enumeration))]
@@ -40,6 +67,7 @@ use crate::{generate_vec, Generate};
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -135,6 +163,7 @@ impl EntityType {
     }
 }
 
+// Hand-written, like Update's: the derive can't weight choices or pass drawn values down.
 impl Generate for EntityType {
     fn generate<R: Rng>(rng: &mut R) -> Self {
         use EntityType::*;
@@ -234,6 +263,157 @@ impl From<pb::EntityType> for EntityType {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl From<EntityType> for mk48_protobuf::EntityType {
+    #[inline]
+    fn from(value: EntityType) -> Self {
+        match value {
+            EntityType::ArleighBurke => mk48_protobuf::EntityType::ARLEIGH_BURKE,
+            EntityType::Bismarck => mk48_protobuf::EntityType::BISMARCK,
+            EntityType::Clemenceau => mk48_protobuf::EntityType::CLEMENCEAU,
+            EntityType::Fletcher => mk48_protobuf::EntityType::FLETCHER,
+            EntityType::G5 => mk48_protobuf::EntityType::G5,
+            EntityType::Iowa => mk48_protobuf::EntityType::IOWA,
+            EntityType::Kolkata => mk48_protobuf::EntityType::KOLKATA,
+            EntityType::Osa => mk48_protobuf::EntityType::OSA,
+            EntityType::Yasen => mk48_protobuf::EntityType::YASEN,
+            EntityType::Zubr => mk48_protobuf::EntityType::ZUBR,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mk48_protobuf::EntityType> for EntityType {
+    fn from(value: mk48_protobuf::EntityType) -> Self {
+        match value {
+            mk48_protobuf::EntityType::ARLEIGH_BURKE => EntityType::ArleighBurke,
+            mk48_protobuf::EntityType::BISMARCK => EntityType::Bismarck,
+            mk48_protobuf::EntityType::CLEMENCEAU => EntityType::Clemenceau,
+            mk48_protobuf::EntityType::FLETCHER => EntityType::Fletcher,
+            mk48_protobuf::EntityType::G5 => EntityType::G5,
+            mk48_protobuf::EntityType::IOWA => EntityType::Iowa,
+            mk48_protobuf::EntityType::KOLKATA => EntityType::Kolkata,
+            mk48_protobuf::EntityType::OSA => EntityType::Osa,
+            mk48_protobuf::EntityType::YASEN => EntityType::Yasen,
+            mk48_protobuf::EntityType::ZUBR => EntityType::Zubr,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<EntityType> for th::EntityType {
+    #[inline]
+    fn from(value: EntityType) -> Self {
+        match value {
+            EntityType::ArleighBurke => th::EntityType::ARLEIGH_BURKE,
+            EntityType::Bismarck => th::EntityType::BISMARCK,
+            EntityType::Clemenceau => th::EntityType::CLEMENCEAU,
+            EntityType::Fletcher => th::EntityType::FLETCHER,
+            EntityType::G5 => th::EntityType::G5,
+            EntityType::Iowa => th::EntityType::IOWA,
+            EntityType::Kolkata => th::EntityType::KOLKATA,
+            EntityType::Osa => th::EntityType::OSA,
+            EntityType::Yasen => th::EntityType::YASEN,
+            EntityType::Zubr => th::EntityType::ZUBR,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::EntityType> for EntityType {
+    fn from(value: th::EntityType) -> Self {
+        match value {
+            th::EntityType::ARLEIGH_BURKE => EntityType::ArleighBurke,
+            th::EntityType::BISMARCK => EntityType::Bismarck,
+            th::EntityType::CLEMENCEAU => EntityType::Clemenceau,
+            th::EntityType::FLETCHER => EntityType::Fletcher,
+            th::EntityType::G5 => EntityType::G5,
+            th::EntityType::IOWA => EntityType::Iowa,
+            th::EntityType::KOLKATA => EntityType::Kolkata,
+            th::EntityType::OSA => EntityType::Osa,
+            th::EntityType::YASEN => EntityType::Yasen,
+            th::EntityType::ZUBR => EntityType::Zubr,
+            _ => panic!("invalid entity type"),
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<EntityType> for mk48_bebop::EntityType {
+    #[inline]
+    fn from(value: EntityType) -> Self {
+        match value {
+            EntityType::ArleighBurke => mk48_bebop::EntityType::ArleighBurke,
+            EntityType::Bismarck => mk48_bebop::EntityType::Bismarck,
+            EntityType::Clemenceau => mk48_bebop::EntityType::Clemenceau,
+            EntityType::Fletcher => mk48_bebop::EntityType::Fletcher,
+            EntityType::G5 => mk48_bebop::EntityType::G5,
+            EntityType::Iowa => mk48_bebop::EntityType::Iowa,
+            EntityType::Kolkata => mk48_bebop::EntityType::Kolkata,
+            EntityType::Osa => mk48_bebop::EntityType::Osa,
+            EntityType::Yasen => mk48_bebop::EntityType::Yasen,
+            EntityType::Zubr => mk48_bebop::EntityType::Zubr,
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<mk48_bebop::EntityType> for EntityType {
+    #[inline]
+    fn from(value: mk48_bebop::EntityType) -> Self {
+        match value {
+            mk48_bebop::EntityType::ArleighBurke => EntityType::ArleighBurke,
+            mk48_bebop::EntityType::Bismarck => EntityType::Bismarck,
+            mk48_bebop::EntityType::Clemenceau => EntityType::Clemenceau,
+            mk48_bebop::EntityType::Fletcher => EntityType::Fletcher,
+            mk48_bebop::EntityType::G5 => EntityType::G5,
+            mk48_bebop::EntityType::Iowa => EntityType::Iowa,
+            mk48_bebop::EntityType::Kolkata => EntityType::Kolkata,
+            mk48_bebop::EntityType::Osa => EntityType::Osa,
+            mk48_bebop::EntityType::Yasen => EntityType::Yasen,
+            mk48_bebop::EntityType::Zubr => EntityType::Zubr,
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<EntityType> for mk48_ssz::EntityType {
+    #[inline]
+    fn from(value: EntityType) -> Self {
+        match value {
+            EntityType::ArleighBurke => mk48_ssz::EntityType::ArleighBurke,
+            EntityType::Bismarck => mk48_ssz::EntityType::Bismarck,
+            EntityType::Clemenceau => mk48_ssz::EntityType::Clemenceau,
+            EntityType::Fletcher => mk48_ssz::EntityType::Fletcher,
+            EntityType::G5 => mk48_ssz::EntityType::G5,
+            EntityType::Iowa => mk48_ssz::EntityType::Iowa,
+            EntityType::Kolkata => mk48_ssz::EntityType::Kolkata,
+            EntityType::Osa => mk48_ssz::EntityType::Osa,
+            EntityType::Yasen => mk48_ssz::EntityType::Yasen,
+            EntityType::Zubr => mk48_ssz::EntityType::Zubr,
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<mk48_ssz::EntityType> for EntityType {
+    #[inline]
+    fn from(value: mk48_ssz::EntityType) -> Self {
+        match value {
+            mk48_ssz::EntityType::ArleighBurke => EntityType::ArleighBurke,
+            mk48_ssz::EntityType::Bismarck => EntityType::Bismarck,
+            mk48_ssz::EntityType::Clemenceau => EntityType::Clemenceau,
+            mk48_ssz::EntityType::Fletcher => EntityType::Fletcher,
+            mk48_ssz::EntityType::G5 => EntityType::G5,
+            mk48_ssz::EntityType::Iowa => EntityType::Iowa,
+            mk48_ssz::EntityType::Kolkata => EntityType::Kolkata,
+            mk48_ssz::EntityType::Osa => EntityType::Osa,
+            mk48_ssz::EntityType::Yasen => EntityType::Yasen,
+            mk48_ssz::EntityType::Zubr => EntityType::Zubr,
+        }
+    }
+}
+
 fn generate_submerge(rng: &mut impl Rng, entity_type: EntityType) -> bool {
     entity_type.is_sub() && rng.gen_bool(0.9)
 }
@@ -269,6 +449,7 @@ fn generate_velocity(rng: &mut impl Rng) -> i16 {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -372,6 +553,144 @@ impl From<pb::Transform> for Transform {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Transform {
+    type Message = mk48_protobuf::Transform;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            altitude: self.altitude.into(),
+            angle: self.angle.into(),
+            position: Some(mk48_protobuf::Vector2f {
+                x: self.position.0,
+                y: self.position.1,
+                ..Default::default()
+            })
+            .into(),
+            velocity: self.velocity.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mk48_protobuf::Vector2f> for (f32, f32) {
+    fn from(value: mk48_protobuf::Vector2f) -> Self {
+        (value.x, value.y)
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mk48_protobuf::Transform> for Transform {
+    fn from(value: mk48_protobuf::Transform) -> Self {
+        Transform {
+            altitude: value.altitude.try_into().unwrap(),
+            angle: value.angle.try_into().unwrap(),
+            position: value.position.unwrap().into(),
+            velocity: value.velocity.try_into().unwrap(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Transform {
+    type Message = th::Transform;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            altitude: self.altitude,
+            angle: self.angle as i16,
+            position: th::Vector2f {
+                x: OrderedFloat(self.position.0.into()),
+                y: OrderedFloat(self.position.1.into()),
+            },
+            velocity: self.velocity,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Vector2f> for (f32, f32) {
+    fn from(value: th::Vector2f) -> Self {
+        (value.x.0 as f32, value.y.0 as f32)
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Transform> for Transform {
+    fn from(value: th::Transform) -> Self {
+        Transform {
+            altitude: value.altitude,
+            angle: value.angle as u16,
+            position: value.position.into(),
+            velocity: value.velocity,
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<Transform> for mk48_bebop::Transform {
+    #[inline]
+    fn from(value: Transform) -> Self {
+        Self {
+            altitude: value.altitude as u8,
+            angle: value.angle,
+            position: mk48_bebop::Vector2f {
+                x: value.position.0,
+                y: value.position.1,
+            },
+            velocity: value.velocity,
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<mk48_bebop::Transform> for Transform {
+    #[inline]
+    fn from(value: mk48_bebop::Transform) -> Self {
+        Transform {
+            altitude: value.altitude as i8,
+            angle: value.angle,
+            position: (value.position.x, value.position.y),
+            velocity: value.velocity,
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<Transform> for mk48_ssz::Transform {
+    #[inline]
+    fn from(value: Transform) -> Self {
+        Self {
+            altitude: value.altitude as u8,
+            angle: value.angle,
+            position: mk48_ssz::Vector2f {
+                x: value.position.0.to_bits(),
+                y: value.position.1.to_bits(),
+            },
+            velocity: value.velocity as u16,
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<mk48_ssz::Transform> for Transform {
+    #[inline]
+    fn from(value: mk48_ssz::Transform) -> Self {
+        Transform {
+            altitude: value.altitude as i8,
+            angle: value.angle,
+            position: (
+                f32::from_bits(value.position.x),
+                f32::from_bits(value.position.y),
+            ),
+            velocity: value.velocity as i16,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
@@ -382,6 +701,7 @@ impl From<pb::Transform> for Transform {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -462,6 +782,105 @@ impl From<pb::Guidance> for Guidance {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Guidance {
+    type Message = mk48_protobuf::Guidance;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            angle: self.angle.into(),
+            submerge: self.submerge,
+            velocity: self.velocity.into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mk48_protobuf::Guidance> for Guidance {
+    fn from(value: mk48_protobuf::Guidance) -> Self {
+        Guidance {
+            angle: value.angle.try_into().unwrap(),
+            submerge: value.submerge,
+            velocity: value.velocity.try_into().unwrap(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Guidance {
+    type Message = th::Guidance;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            angle: self.angle as i16,
+            submerge: self.submerge,
+            velocity: self.velocity,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Guidance> for Guidance {
+    fn from(value: th::Guidance) -> Self {
+        Guidance {
+            angle: value.angle as u16,
+            submerge: value.submerge,
+            velocity: value.velocity,
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<Guidance> for mk48_bebop::Guidance {
+    #[inline]
+    fn from(value: Guidance) -> Self {
+        Self {
+            angle: value.angle,
+            submerge: value.submerge,
+            velocity: value.velocity,
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<mk48_bebop::Guidance> for Guidance {
+    #[inline]
+    fn from(value: mk48_bebop::Guidance) -> Self {
+        Guidance {
+            angle: value.angle,
+            submerge: value.submerge,
+            velocity: value.velocity,
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<Guidance> for mk48_ssz::Guidance {
+    #[inline]
+    fn from(value: Guidance) -> Self {
+        Self {
+            angle: value.angle,
+            submerge: value.submerge,
+            velocity: value.velocity as u16,
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<mk48_ssz::Guidance> for Guidance {
+    #[inline]
+    fn from(value: mk48_ssz::Guidance) -> Self {
+        Guidance {
+            angle: value.angle,
+            submerge: value.submerge,
+            velocity: value.velocity as i16,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
@@ -472,6 +891,7 @@ impl From<pb::Guidance> for Guidance {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -663,6 +1083,147 @@ impl From<pb::Contact> for Contact {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Contact {
+    type Message = mk48_protobuf::Contact;
+
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            damage: self.damage as u32,
+            entity_id: self.entity_id,
+            entity_type: self
+                .entity_type
+                .map(|entity_type| mk48_protobuf::EntityType::from(entity_type).into()),
+            guidance: Some(self.guidance.serialize_protobuf()).into(),
+            player_id: self.player_id.map(Into::into),
+            reloads: self.reloads.clone(),
+            transform: Some(self.transform.serialize_protobuf()).into(),
+            turret_angles: self.turret_angles.iter().map(|&a| a as u32).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mk48_protobuf::Contact> for Contact {
+    fn from(value: mk48_protobuf::Contact) -> Self {
+        Contact {
+            damage: value.damage.try_into().unwrap(),
+            entity_id: value.entity_id,
+            entity_type: value.entity_type.map(|et| et.unwrap().into()),
+            guidance: value.guidance.unwrap().into(),
+            player_id: value.player_id.map(|id| id.try_into().unwrap()),
+            reloads: value.reloads,
+            transform: value.transform.unwrap().into(),
+            turret_angles: value
+                .turret_angles
+                .into_iter()
+                .map(|a| a.try_into().unwrap())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Contact {
+    type Message = th::Contact;
+
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            damage: self.damage as i8,
+            entity_id: self.entity_id as i32,
+            entity_type: self.entity_type.map(Into::into),
+            guidance: self.guidance.serialize_thrift(),
+            player_id: self.player_id.map(|id| id as i16),
+            reloads: self.reloads.clone(),
+            transform: self.transform.serialize_thrift(),
+            turret_angles: self.turret_angles.iter().map(|&a| a as i16).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Contact> for Contact {
+    fn from(value: th::Contact) -> Self {
+        Contact {
+            damage: value.damage as u8,
+            entity_id: value.entity_id as u32,
+            entity_type: value.entity_type.map(Into::into),
+            guidance: value.guidance.into(),
+            player_id: value.player_id.map(|id| id as u16),
+            reloads: value.reloads,
+            transform: value.transform.into(),
+            turret_angles: value.turret_angles.into_iter().map(|a| a as u16).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl<'a> From<&'a Contact> for mk48_bebop::Contact<'a> {
+    #[inline]
+    fn from(value: &'a Contact) -> Self {
+        Self {
+            damage: Some(value.damage),
+            entity_id: Some(value.entity_id),
+            entity_type: value.entity_type.map(Into::into),
+            guidance: Some(value.guidance.into()),
+            player_id: value.player_id,
+            reloads: Some(SliceWrapper::Cooked(&value.reloads)),
+            transform: Some(value.transform.into()),
+            turret_angles: Some(SliceWrapper::Cooked(&value.turret_angles)),
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<mk48_bebop::Contact<'_>> for Contact {
+    fn from(value: mk48_bebop::Contact<'_>) -> Self {
+        Contact {
+            damage: value.damage.unwrap(),
+            entity_id: value.entity_id.unwrap(),
+            entity_type: value.entity_type.map(Into::into),
+            guidance: value.guidance.unwrap().into(),
+            player_id: value.player_id,
+            reloads: value.reloads.unwrap().iter().collect(),
+            transform: value.transform.unwrap().into(),
+            turret_angles: value.turret_angles.unwrap().iter().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<&Contact> for mk48_ssz::Contact {
+    #[inline]
+    fn from(value: &Contact) -> Self {
+        Self {
+            damage: value.damage,
+            entity_id: value.entity_id,
+            entity_type: value.entity_type.map(Into::into),
+            guidance: value.guidance.into(),
+            player_id: value.player_id,
+            reloads: value.reloads.clone(),
+            transform: value.transform.into(),
+            turret_angles: value.turret_angles.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<mk48_ssz::Contact> for Contact {
+    fn from(value: mk48_ssz::Contact) -> Self {
+        Contact {
+            damage: value.damage,
+            entity_id: value.entity_id,
+            entity_type: value.entity_type.map(Into::into),
+            guidance: value.guidance.into(),
+            player_id: value.player_id,
+            reloads: value.reloads,
+            transform: value.transform.into(),
+            turret_angles: value.turret_angles,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
@@ -673,6 +1234,7 @@ impl From<pb::Contact> for Contact {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -782,6 +1344,114 @@ impl From<pb::TerrainUpdate> for TerrainUpdate {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for TerrainUpdate {
+    type Message = mk48_protobuf::TerrainUpdate;
+
+    fn serialize_protobuf(&self) -> Self::Message {
+        Self::Message {
+            chunk_id: Some(mk48_protobuf::ChunkId {
+                x: self.chunk_id.0 as i32,
+                y: self.chunk_id.1 as i32,
+                ..Default::default()
+            })
+            .into(),
+            data: self.data.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mk48_protobuf::ChunkId> for (i8, i8) {
+    fn from(value: mk48_protobuf::ChunkId) -> Self {
+        (value.x.try_into().unwrap(), value.y.try_into().unwrap())
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mk48_protobuf::TerrainUpdate> for TerrainUpdate {
+    fn from(value: mk48_protobuf::TerrainUpdate) -> Self {
+        TerrainUpdate {
+            chunk_id: value.chunk_id.unwrap().into(),
+            data: value.data,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for TerrainUpdate {
+    type Message = th::TerrainUpdate;
+
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            chunk_id: th::ChunkId {
+                x: self.chunk_id.0,
+                y: self.chunk_id.1,
+            },
+            data: self.data.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::TerrainUpdate> for TerrainUpdate {
+    fn from(value: th::TerrainUpdate) -> Self {
+        TerrainUpdate {
+            chunk_id: (value.chunk_id.x, value.chunk_id.y),
+            data: value.data,
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl<'a> From<&'a TerrainUpdate> for mk48_bebop::TerrainUpdate<'a> {
+    #[inline]
+    fn from(value: &'a TerrainUpdate) -> Self {
+        Self {
+            chunk_id: mk48_bebop::ChunkId {
+                x: value.chunk_id.0 as u8,
+                y: value.chunk_id.1 as u8,
+            },
+            data: SliceWrapper::Cooked(&value.data),
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<mk48_bebop::TerrainUpdate<'_>> for TerrainUpdate {
+    fn from(value: mk48_bebop::TerrainUpdate<'_>) -> Self {
+        TerrainUpdate {
+            chunk_id: (value.chunk_id.x as i8, value.chunk_id.y as i8),
+            data: value.data.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<&TerrainUpdate> for mk48_ssz::TerrainUpdate {
+    #[inline]
+    fn from(value: &TerrainUpdate) -> Self {
+        Self {
+            chunk_id: mk48_ssz::ChunkId {
+                x: value.chunk_id.0 as u8,
+                y: value.chunk_id.1 as u8,
+            },
+            data: value.data.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<mk48_ssz::TerrainUpdate> for TerrainUpdate {
+    fn from(value: mk48_ssz::TerrainUpdate) -> Self {
+        TerrainUpdate {
+            chunk_id: (value.chunk_id.x as i8, value.chunk_id.y as i8),
+            data: value.data,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
@@ -792,6 +1462,7 @@ impl From<pb::TerrainUpdate> for TerrainUpdate {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -919,6 +1590,122 @@ impl From<pb::Update> for Update {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Update {
+    type Message = mk48_protobuf::Update;
+
+    fn serialize_protobuf(&self) -> Self::Message {
+        let mut result = Self::Message::default();
+        for contact in self.contacts.iter() {
+            result.contacts.push(contact.serialize_protobuf());
+        }
+        result.score = self.score;
+        result.world_radius = self.world_radius;
+        for terrain_update in self.terrain_updates.iter() {
+            result
+                .terrain_updates
+                .push(terrain_update.serialize_protobuf());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mk48_protobuf::Update> for Update {
+    fn from(value: mk48_protobuf::Update) -> Self {
+        Update {
+            contacts: value.contacts.into_iter().map(Into::into).collect(),
+            score: value.score,
+            world_radius: value.world_radius,
+            terrain_updates: value.terrain_updates.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Update {
+    type Message = th::Update;
+
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            contacts: self
+                .contacts
+                .iter()
+                .map(|contact| contact.serialize_thrift())
+                .collect(),
+            score: self.score as i32,
+            world_radius: OrderedFloat(self.world_radius.into()),
+            terrain_updates: self
+                .terrain_updates
+                .iter()
+                .map(|terrain_update| terrain_update.serialize_thrift())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Update> for Update {
+    fn from(value: th::Update) -> Self {
+        Update {
+            contacts: value.contacts.into_iter().map(Into::into).collect(),
+            score: value.score as u32,
+            world_radius: value.world_radius.0 as f32,
+            terrain_updates: value.terrain_updates.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl<'a> From<&'a Update> for mk48_bebop::Update<'a> {
+    #[inline]
+    fn from(value: &'a Update) -> Self {
+        Self {
+            contacts: value.contacts.iter().map(Into::into).collect(),
+            score: value.score,
+            world_radius: value.world_radius,
+            terrain_updates: value.terrain_updates.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<mk48_bebop::Update<'_>> for Update {
+    fn from(value: mk48_bebop::Update<'_>) -> Self {
+        Update {
+            contacts: value.contacts.into_iter().map(Into::into).collect(),
+            score: value.score,
+            world_radius: value.world_radius,
+            terrain_updates: value.terrain_updates.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<&Update> for mk48_ssz::Update {
+    #[inline]
+    fn from(value: &Update) -> Self {
+        Self {
+            contacts: value.contacts.iter().map(Into::into).collect(),
+            score: value.score,
+            world_radius: value.world_radius.to_bits(),
+            terrain_updates: value.terrain_updates.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<mk48_ssz::Update> for Update {
+    fn from(value: mk48_ssz::Update) -> Self {
+        Update {
+            contacts: value.contacts.into_iter().map(Into::into).collect(),
+            score: value.score,
+            world_radius: f32::from_bits(value.world_radius),
+            terrain_updates: value.terrain_updates.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
@@ -929,6 +1716,7 @@ impl From<pb::Update> for Update {
 )]
 #[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
 #[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
@@ -951,6 +1739,11 @@ pub struct Updates {
     pub updates: Vec<Update>,
 }
 
+#[cfg(feature = "apache-avro")]
+impl bench_avro::Schema for Updates {
+    const SCHEMA: &'static str = include_str!("mk48.avsc");
+}
+
 #[cfg(feature = "flatbuffers")]
 impl<'a> bench_flatbuffers::Serialize<'a> for Updates {
     type Target = fb::Updates<'a>;
@@ -1008,3 +1801,157 @@ impl From<pb::Updates> for Updates {
         }
     }
 }
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Updates {
+    type Message = mk48_protobuf::Updates;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        let mut result = Self::Message::default();
+        for update in self.updates.iter() {
+            result.updates.push(update.serialize_protobuf());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<mk48_protobuf::Updates> for Updates {
+    fn from(value: mk48_protobuf::Updates) -> Self {
+        Updates {
+            updates: value.updates.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Updates {
+    type Message = th::Updates;
+
+    fn serialize_thrift(&self) -> Self::Message {
+        Self::Message {
+            updates: self
+                .updates
+                .iter()
+                .map(|update| update.serialize_thrift())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<th::Updates> for Updates {
+    fn from(value: th::Updates) -> Self {
+        Updates {
+            updates: value.updates.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl bench_bebop::Serialize for Updates {
+    type Message<'raw> = mk48_bebop::Updates<'raw>;
+
+    #[inline]
+    fn serialize_bebop<W: std::io::Write>(&self, dest: &mut W) -> SeResult<usize> {
+        mk48_bebop::Updates {
+            updates: self.updates.iter().map(Into::into).collect(),
+        }
+        .serialize(dest)
+    }
+}
+
+#[cfg(feature = "bebop")]
+impl From<mk48_bebop::Updates<'_>> for Updates {
+    fn from(value: mk48_bebop::Updates<'_>) -> Self {
+        Updates {
+            updates: value.updates.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl bench_ethereum_ssz::Serialize for Updates {
+    type Message = mk48_ssz::Updates;
+
+    #[inline]
+    fn serialize_ssz(&self) -> Self::Message {
+        Self::Message {
+            updates: self.updates.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<mk48_ssz::Updates> for Updates {
+    fn from(value: mk48_ssz::Updates) -> Self {
+        Updates {
+            updates: value.updates.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct ProjectedUpdate {
+    pub contacts: IgnoredAny,
+    pub score: u32,
+    pub world_radius: IgnoredAny,
+    pub terrain_updates: IgnoredAny,
+}
+
+#[derive(serde::Deserialize)]
+pub struct ProjectedUpdates {
+    pub updates: Vec<ProjectedUpdate>,
+}
+
+impl From<ProjectedUpdates> for Vec<u32> {
+    fn from(value: ProjectedUpdates) -> Self {
+        value
+            .updates
+            .into_iter()
+            .map(|update| update.score)
+            .collect()
+    }
+}
+
+impl Projectable for Updates {
+    type Fields = Vec<u32>;
+    type Projection = ProjectedUpdates;
+
+    fn project(&self) -> Self::Fields {
+        self.updates.iter().map(|update| update.score).collect()
+    }
+}
+
+/// A reduced `Updates` message that keeps the score and skips contacts and terrain updates.
+#[cfg(feature = "prost")]
+pub mod projected_pb {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Update {
+        #[prost(uint32, tag = "2")]
+        pub score: u32,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Updates {
+        #[prost(message, repeated, tag = "1")]
+        pub updates: Vec<Update>,
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<projected_pb::Updates> for Vec<u32> {
+    fn from(value: projected_pb::Updates) -> Self {
+        value
+            .updates
+            .into_iter()
+            .map(|update| update.score)
+            .collect()
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Project for Updates {
+    type Projection = projected_pb::Updates;
+}
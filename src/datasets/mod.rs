@@ -3,3 +3,4 @@ pub mod mesh;
 pub mod minecraft_savedata;
 pub mod mk48;
 pub mod page;
+pub mod tree;
@@ -1,7 +1,18 @@
 use rkyv::{Archive, Deserialize, Serialize};
 
 #[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
-#[derive(Archive, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[derive(Archive, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct PageHeader {
     pub page_id: u32,
     pub previous_id: u32,
@@ -13,7 +24,18 @@ pub struct PageHeader {
 pub const HEADER_SIZE: usize = 32;
 pub const PAGE_SIZE: usize = size_of::<Page>();
 #[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
-#[derive(Archive, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[derive(Archive, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Page {
     pub header: PageHeader,
     pub data: [u8; 4064],
@@ -0,0 +1,793 @@
+#[cfg(feature = "capnp")]
+pub mod tree_capnp;
+#[cfg(feature = "flatbuffers")]
+#[path = "tree_generated.rs"]
+#[allow(unused_imports, clippy::all)]
+pub mod tree_fb;
+#[cfg(feature = "prost")]
+#[path = "prost.tree.rs"]
+pub mod tree_prost;
+#[cfg(feature = "protobuf")]
+pub mod tree_protobuf;
+#[cfg(feature = "quick-protobuf")]
+pub mod tree_quick_protobuf;
+#[cfg(feature = "thrift")]
+#[path = "tree.rs"]
+#[allow(clippy::all)]
+pub mod tree_thrift;
+
+#[cfg(feature = "flatbuffers")]
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+#[cfg(feature = "nanoserde")]
+use nanoserde::{DeBin, SerBin};
+use rand::Rng;
+#[cfg(feature = "thrift")]
+use thrift::OrderedFloat;
+#[cfg(feature = "capnp")]
+pub use tree_capnp as cp;
+#[cfg(feature = "flatbuffers")]
+pub use tree_fb::tree as fb;
+// pb-rs qualifies the generated types with the last component of the proto package
+#[cfg(feature = "quick-protobuf")]
+use tree_quick_protobuf as tree;
+#[cfg(feature = "wiring")]
+use wiring::prelude::{Unwiring, Wiring};
+
+#[cfg(feature = "capnp")]
+use crate::bench_capnp;
+#[cfg(feature = "flatbuffers")]
+use crate::bench_flatbuffers;
+#[cfg(feature = "prost")]
+use crate::bench_prost;
+#[cfg(feature = "protobuf")]
+use crate::bench_protobuf;
+#[cfg(feature = "quick-protobuf")]
+use crate::bench_quick_protobuf;
+#[cfg(feature = "thrift")]
+use crate::bench_thrift;
+use crate::Generate;
+
+/// The deepest a generated tree gets. This keeps the deepest trees under the default nesting limits of
+/// the formats that have one, the tightest of which is the 64 tables that the flatbuffers verifier
+/// accepts. Every level of objects nests three of them.
+pub const MAX_DEPTH: usize = 20;
+
+const KEYS: [&str; 16] = [
+    "id",
+    "name",
+    "type",
+    "value",
+    "children",
+    "items",
+    "data",
+    "meta",
+    "version",
+    "enabled",
+    "count",
+    "path",
+    "tags",
+    "parent",
+    "created",
+    "description",
+];
+
+const STRINGS: [&str; 12] = [
+    "",
+    "true",
+    "null",
+    "hello, world",
+    "/usr/local/bin",
+    "2021-10-29T16:00:00Z",
+    "text/html; charset=utf-8",
+    "Lorem ipsum dolor sit amet",
+    "consectetur adipiscing elit",
+    "0123456789abcdef",
+    "https://github.com/djkoloski/rust_serialization_benchmark",
+    "\u{1f980}",
+];
+
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
+#[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(
+        serialize_bounds(
+            __S: rkyv::ser::Writer + rkyv::ser::Allocator,
+            __S::Error: rkyv::rancor::Source,
+        ),
+        deserialize_bounds(__D::Error: rkyv::rancor::Source),
+        bytecheck(bounds(
+            __C: rkyv::validation::ArchiveContext,
+            __C::Error: rkyv::rancor::Source,
+        )),
+    )
+)]
+#[cfg_attr(
+    feature = "scale",
+    derive(parity_scale_codec_derive::Encode, parity_scale_codec_derive::Decode)
+)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[cfg_attr(
+    feature = "simd-json",
+    derive(simd_json_derive::Serialize, simd_json_derive::Deserialize)
+)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "savefile", derive(savefile_derive::Savefile))]
+#[cfg_attr(feature = "nanoserde", derive(nanoserde::SerBin, nanoserde::DeBin))]
+#[cfg_attr(feature = "wiring", derive(Wiring, Unwiring), tag(u8))]
+pub enum Node {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(#[cfg_attr(feature = "rkyv", rkyv(omit_bounds))] Vec<Node>),
+    Object(#[cfg_attr(feature = "rkyv", rkyv(omit_bounds))] Vec<Member>),
+}
+
+impl Node {
+    /// Generates a node `depth` levels deep. One child of each array or object carries the depth
+    /// down, and its siblings stay shallow so that the size of a tree grows linearly with its depth.
+    fn generate_with_depth<R: Rng>(rng: &mut R, depth: usize) -> Self {
+        if depth == 0 {
+            return match rng.gen_range(0..4) {
+                0 => Self::Null,
+                1 => Self::Bool(rng.gen_bool(0.5)),
+                2 => Self::Number(if rng.gen_bool(0.5) {
+                    rng.gen_range(-1000..1000) as f64
+                } else {
+                    rng.gen_range(-1.0e6..1.0e6)
+                }),
+                3 => Self::String(STRINGS[rng.gen_range(0..STRINGS.len())].into()),
+                _ => unreachable!(),
+            };
+        }
+
+        let len = rng.gen_range(1..=5);
+        let deep = rng.gen_range(0..len);
+        let child = |rng: &mut R, i: usize| {
+            let depth = if i == deep {
+                depth - 1
+            } else {
+                rng.gen_range(0..=(depth - 1).min(1))
+            };
+            Self::generate_with_depth(rng, depth)
+        };
+        if rng.gen_bool(0.5) {
+            Self::Array((0..len).map(|i| child(rng, i)).collect())
+        } else {
+            Self::Object(
+                (0..len)
+                    .map(|i| Member {
+                        key: KEYS[rng.gen_range(0..KEYS.len())].into(),
+                        value: child(rng, i),
+                    })
+                    .collect(),
+            )
+        }
+    }
+}
+
+// This can't be derived: the derive only handles enums with unit variants, and each child has to be
+// told how much deeper it can go, which `Generate::generate` has no way to pass down.
+impl Generate for Node {
+    fn generate<R: Rng>(rng: &mut R) -> Self {
+        let depth = rng.gen_range(1..=MAX_DEPTH);
+        Self::generate_with_depth(rng, depth)
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for Node {
+    type Target = fb::Node<'a>;
+
+    #[inline]
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let (value_type, value) = match self {
+            Self::Null => (fb::Value::NONE, None),
+            Self::Bool(value) => {
+                let mut builder = fb::BoolValueBuilder::new(fbb);
+                builder.add_value(*value);
+                (
+                    fb::Value::BoolValue,
+                    Some(builder.finish().as_union_value()),
+                )
+            }
+            Self::Number(value) => {
+                let mut builder = fb::NumberValueBuilder::new(fbb);
+                builder.add_value(*value);
+                (
+                    fb::Value::NumberValue,
+                    Some(builder.finish().as_union_value()),
+                )
+            }
+            Self::String(value) => {
+                let value = fbb.create_string(value);
+                let mut builder = fb::StringValueBuilder::new(fbb);
+                builder.add_value(value);
+                (
+                    fb::Value::StringValue,
+                    Some(builder.finish().as_union_value()),
+                )
+            }
+            Self::Array(nodes) => {
+                let mut children = Vec::new();
+                for node in nodes.iter() {
+                    children.push(node.serialize_fb(fbb));
+                }
+                let nodes = fbb.create_vector(&children);
+                let mut builder = fb::ArrayValueBuilder::new(fbb);
+                builder.add_nodes(nodes);
+                (
+                    fb::Value::ArrayValue,
+                    Some(builder.finish().as_union_value()),
+                )
+            }
+            Self::Object(members) => {
+                let mut children = Vec::new();
+                for member in members.iter() {
+                    children.push(member.serialize_fb(fbb));
+                }
+                let members = fbb.create_vector(&children);
+                let mut builder = fb::ObjectValueBuilder::new(fbb);
+                builder.add_members(members);
+                (
+                    fb::Value::ObjectValue,
+                    Some(builder.finish().as_union_value()),
+                )
+            }
+        };
+
+        // The type is only added along with a value, so that null nodes stay valid unions in the
+        // update buffer, which forces default values to be written.
+        let mut builder = fb::NodeBuilder::new(fbb);
+        if let Some(value) = value {
+            builder.add_value_type(value_type);
+            builder.add_value(value);
+        }
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for Node {
+    type Reader = cp::node::Reader<'a>;
+    type Builder = cp::node::Builder<'a>;
+
+    #[inline]
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        use capnp::text::Reader;
+
+        match self {
+            Self::Null => builder.set_null(()),
+            Self::Bool(value) => builder.set_bool(*value),
+            Self::Number(value) => builder.set_number(*value),
+            Self::String(value) => builder.set_string(Reader(value.as_bytes())),
+            Self::Array(nodes) => {
+                let mut array = builder.reborrow().init_array(nodes.len() as u32);
+                for (i, node) in nodes.iter().enumerate() {
+                    node.serialize_capnp(&mut array.reborrow().get(i as u32));
+                }
+            }
+            Self::Object(members) => {
+                let mut object = builder.reborrow().init_object(members.len() as u32);
+                for (i, member) in members.iter().enumerate() {
+                    member.serialize_capnp(&mut object.reborrow().get(i as u32));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for Node {
+    type Message = tree_prost::Node;
+
+    #[inline]
+    fn serialize_pb(&self) -> Self::Message {
+        use tree_prost::node::Value;
+
+        let value = match self {
+            Self::Null => Value::Null(tree_prost::Null::Null.into()),
+            Self::Bool(value) => Value::Bool(*value),
+            Self::Number(value) => Value::Number(*value),
+            Self::String(value) => Value::String(value.clone()),
+            Self::Array(nodes) => Value::Array(tree_prost::Array {
+                nodes: nodes.iter().map(|node| node.serialize_pb()).collect(),
+            }),
+            Self::Object(members) => Value::Object(tree_prost::Object {
+                members: members.iter().map(|member| member.serialize_pb()).collect(),
+            }),
+        };
+        tree_prost::Node { value: Some(value) }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<tree_prost::Node> for Node {
+    fn from(value: tree_prost::Node) -> Self {
+        use tree_prost::node::Value;
+
+        match value.value.unwrap() {
+            Value::Null(_) => Self::Null,
+            Value::Bool(value) => Self::Bool(value),
+            Value::Number(value) => Self::Number(value),
+            Value::String(value) => Self::String(value),
+            Value::Array(array) => Self::Array(array.nodes.into_iter().map(Into::into).collect()),
+            Value::Object(object) => {
+                Self::Object(object.members.into_iter().map(Into::into).collect())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Node {
+    type Message = tree_protobuf::Node;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        use tree_protobuf::node::Value;
+
+        let value = match self {
+            Self::Null => Value::Null(tree_protobuf::Null::NULL.into()),
+            Self::Bool(value) => Value::Bool(*value),
+            Self::Number(value) => Value::Number(*value),
+            Self::String(value) => Value::String(value.clone()),
+            Self::Array(nodes) => Value::Array(tree_protobuf::Array {
+                nodes: nodes.iter().map(|node| node.serialize_protobuf()).collect(),
+                ..Default::default()
+            }),
+            Self::Object(members) => Value::Object(tree_protobuf::Object {
+                members: members
+                    .iter()
+                    .map(|member| member.serialize_protobuf())
+                    .collect(),
+                ..Default::default()
+            }),
+        };
+        tree_protobuf::Node {
+            value: Some(value),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<tree_protobuf::Node> for Node {
+    fn from(value: tree_protobuf::Node) -> Self {
+        use tree_protobuf::node::Value;
+
+        match value.value.unwrap() {
+            Value::Null(_) => Self::Null,
+            Value::Bool(value) => Self::Bool(value),
+            Value::Number(value) => Self::Number(value),
+            Value::String(value) => Self::String(value),
+            Value::Array(array) => Self::Array(array.nodes.into_iter().map(Into::into).collect()),
+            Value::Object(object) => {
+                Self::Object(object.members.into_iter().map(Into::into).collect())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl bench_quick_protobuf::Serialize for Node {
+    type Message<'a> = tree_quick_protobuf::Node<'a>;
+
+    #[inline]
+    fn serialize_qpb(&self) -> Self::Message<'_> {
+        use tree_quick_protobuf::mod_Node::OneOfvalue;
+
+        let value = match self {
+            Self::Null => OneOfvalue::null(tree_quick_protobuf::Null::NULL),
+            Self::Bool(value) => OneOfvalue::bool_pb(*value),
+            Self::Number(value) => OneOfvalue::number(*value),
+            Self::String(value) => OneOfvalue::string(value.as_str().into()),
+            Self::Array(nodes) => OneOfvalue::array(tree_quick_protobuf::Array {
+                nodes: nodes.iter().map(|node| node.serialize_qpb()).collect(),
+            }),
+            Self::Object(members) => OneOfvalue::object(tree_quick_protobuf::Object {
+                members: members
+                    .iter()
+                    .map(|member| member.serialize_qpb())
+                    .collect(),
+            }),
+        };
+        tree_quick_protobuf::Node { value }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<tree_quick_protobuf::Node<'_>> for Node {
+    fn from(value: tree_quick_protobuf::Node<'_>) -> Self {
+        use tree_quick_protobuf::mod_Node::OneOfvalue;
+
+        match value.value {
+            OneOfvalue::null(_) => Self::Null,
+            OneOfvalue::bool_pb(value) => Self::Bool(value),
+            OneOfvalue::number(value) => Self::Number(value),
+            OneOfvalue::string(value) => Self::String(value.into_owned()),
+            OneOfvalue::array(array) => {
+                Self::Array(array.nodes.into_iter().map(Into::into).collect())
+            }
+            OneOfvalue::object(object) => {
+                Self::Object(object.members.into_iter().map(Into::into).collect())
+            }
+            OneOfvalue::None => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Node {
+    type Message = tree_thrift::Node;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        use tree_thrift::{Array, Node, Null, Object};
+
+        match self {
+            Self::Null => Node::NullValue(Null::NULL),
+            Self::Bool(value) => Node::BoolValue(*value),
+            Self::Number(value) => Node::NumberValue(OrderedFloat(*value)),
+            Self::String(value) => Node::StringValue(value.clone()),
+            Self::Array(nodes) => Node::ArrayValue(Array {
+                nodes: nodes.iter().map(|node| node.serialize_thrift()).collect(),
+            }),
+            Self::Object(members) => Node::ObjectValue(Object {
+                members: members
+                    .iter()
+                    .map(|member| member.serialize_thrift())
+                    .collect(),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<tree_thrift::Node> for Node {
+    fn from(value: tree_thrift::Node) -> Self {
+        use tree_thrift::Node;
+
+        match value {
+            Node::NullValue(_) => Self::Null,
+            Node::BoolValue(value) => Self::Bool(value),
+            Node::NumberValue(value) => Self::Number(value.0),
+            Node::StringValue(value) => Self::String(value),
+            Node::ArrayValue(array) => {
+                Self::Array(array.nodes.into_iter().map(Into::into).collect())
+            }
+            Node::ObjectValue(object) => {
+                Self::Object(object.members.into_iter().map(Into::into).collect())
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
+#[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(
+    feature = "scale",
+    derive(parity_scale_codec_derive::Encode, parity_scale_codec_derive::Decode)
+)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[cfg_attr(
+    feature = "simd-json",
+    derive(simd_json_derive::Serialize, simd_json_derive::Deserialize)
+)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "savefile", derive(savefile_derive::Savefile))]
+#[cfg_attr(feature = "nanoserde", derive(nanoserde::SerBin, nanoserde::DeBin))]
+#[cfg_attr(feature = "wiring", derive(Wiring, Unwiring))]
+pub struct Member {
+    pub key: String,
+    pub value: Node,
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for Member {
+    type Target = fb::Member<'a>;
+
+    #[inline]
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let key = fbb.create_string(&self.key);
+        let value = self.value.serialize_fb(fbb);
+
+        let mut builder = fb::MemberBuilder::new(fbb);
+        builder.add_key(key);
+        builder.add_value(value);
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for Member {
+    type Reader = cp::member::Reader<'a>;
+    type Builder = cp::member::Builder<'a>;
+
+    #[inline]
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        use capnp::text::Reader;
+
+        builder.set_key(Reader(self.key.as_bytes()));
+        self.value
+            .serialize_capnp(&mut builder.reborrow().init_value());
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for Member {
+    type Message = tree_prost::Member;
+
+    #[inline]
+    fn serialize_pb(&self) -> Self::Message {
+        tree_prost::Member {
+            key: self.key.clone(),
+            value: Some(self.value.serialize_pb()),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<tree_prost::Member> for Member {
+    fn from(value: tree_prost::Member) -> Self {
+        Member {
+            key: value.key,
+            value: value.value.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Member {
+    type Message = tree_protobuf::Member;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        tree_protobuf::Member {
+            key: self.key.clone(),
+            value: Some(self.value.serialize_protobuf()).into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<tree_protobuf::Member> for Member {
+    fn from(value: tree_protobuf::Member) -> Self {
+        Member {
+            key: value.key,
+            value: value.value.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl bench_quick_protobuf::Serialize for Member {
+    type Message<'a> = tree_quick_protobuf::Member<'a>;
+
+    #[inline]
+    fn serialize_qpb(&self) -> Self::Message<'_> {
+        tree_quick_protobuf::Member {
+            key: self.key.as_str().into(),
+            value: Some(self.value.serialize_qpb()),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<tree_quick_protobuf::Member<'_>> for Member {
+    fn from(value: tree_quick_protobuf::Member<'_>) -> Self {
+        Member {
+            key: value.key.into_owned(),
+            value: value.value.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Member {
+    type Message = tree_thrift::Member;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        tree_thrift::Member {
+            key: self.key.clone(),
+            value: self.value.serialize_thrift(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<tree_thrift::Member> for Member {
+    fn from(value: tree_thrift::Member) -> Self {
+        Member {
+            key: value.key,
+            value: value.value.into(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
+#[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(feature = "musli", derive(musli::Encode, musli::Decode))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(
+    feature = "scale",
+    derive(parity_scale_codec_derive::Encode, parity_scale_codec_derive::Decode)
+)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[cfg_attr(
+    feature = "simd-json",
+    derive(simd_json_derive::Serialize, simd_json_derive::Deserialize)
+)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "savefile", derive(savefile_derive::Savefile))]
+#[cfg_attr(feature = "nanoserde", derive(nanoserde::SerBin, nanoserde::DeBin))]
+#[cfg_attr(feature = "wiring", derive(Wiring, Unwiring))]
+pub struct Trees {
+    pub trees: Vec<Node>,
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for Trees {
+    type Target = fb::Trees<'a>;
+
+    #[inline]
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let mut trees = Vec::new();
+        for tree in self.trees.iter() {
+            trees.push(tree.serialize_fb(fbb));
+        }
+        let trees = fbb.create_vector(&trees);
+
+        let mut builder = fb::TreesBuilder::new(fbb);
+        builder.add_trees(trees);
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for Trees {
+    type Reader = cp::trees::Reader<'a>;
+    type Builder = cp::trees::Builder<'a>;
+
+    #[inline]
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        let mut trees = builder.reborrow().init_trees(self.trees.len() as u32);
+        for (i, value) in self.trees.iter().enumerate() {
+            value.serialize_capnp(&mut trees.reborrow().get(i as u32));
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for Trees {
+    type Message = tree_prost::Trees;
+
+    #[inline]
+    fn serialize_pb(&self) -> Self::Message {
+        let mut result = Self::Message::default();
+        for tree in self.trees.iter() {
+            result.trees.push(tree.serialize_pb());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<tree_prost::Trees> for Trees {
+    fn from(value: tree_prost::Trees) -> Self {
+        Trees {
+            trees: value.trees.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Trees {
+    type Message = tree_protobuf::Trees;
+
+    #[inline]
+    fn serialize_protobuf(&self) -> Self::Message {
+        let mut result = Self::Message::default();
+        for tree in self.trees.iter() {
+            result.trees.push(tree.serialize_protobuf());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<tree_protobuf::Trees> for Trees {
+    fn from(value: tree_protobuf::Trees) -> Self {
+        Trees {
+            trees: value.trees.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl bench_quick_protobuf::Serialize for Trees {
+    type Message<'a> = tree_quick_protobuf::Trees<'a>;
+
+    #[inline]
+    fn serialize_qpb(&self) -> Self::Message<'_> {
+        let mut result = Self::Message::default();
+        for tree in self.trees.iter() {
+            result.trees.push(tree.serialize_qpb());
+        }
+        result
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<tree_quick_protobuf::Trees<'_>> for Trees {
+    fn from(value: tree_quick_protobuf::Trees<'_>) -> Self {
+        Trees {
+            trees: value.trees.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl bench_thrift::Serialize for Trees {
+    type Message = tree_thrift::Trees;
+
+    #[inline]
+    fn serialize_thrift(&self) -> Self::Message {
+        tree_thrift::Trees {
+            trees: self
+                .trees
+                .iter()
+                .map(|tree| tree.serialize_thrift())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<tree_thrift::Trees> for Trees {
+    fn from(value: tree_thrift::Trees) -> Self {
+        Trees {
+            trees: value.trees.into_iter().map(Into::into).collect(),
+        }
+    }
+}
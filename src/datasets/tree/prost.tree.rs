@@ -0,0 +1,69 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Node {
+    #[prost(oneof = "node::Value", tags = "1, 2, 3, 4, 5, 6")]
+    pub value: ::core::option::Option<node::Value>,
+}
+/// Nested message and enum types in `Node`.
+pub mod node {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(enumeration = "super::Null", tag = "1")]
+        Null(i32),
+        #[prost(bool, tag = "2")]
+        Bool(bool),
+        #[prost(double, tag = "3")]
+        Number(f64),
+        #[prost(string, tag = "4")]
+        String(::prost::alloc::string::String),
+        #[prost(message, tag = "5")]
+        Array(super::Array),
+        #[prost(message, tag = "6")]
+        Object(super::Object),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Array {
+    #[prost(message, repeated, tag = "1")]
+    pub nodes: ::prost::alloc::vec::Vec<Node>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Object {
+    #[prost(message, repeated, tag = "1")]
+    pub members: ::prost::alloc::vec::Vec<Member>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Member {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub value: ::core::option::Option<Node>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trees {
+    #[prost(message, repeated, tag = "1")]
+    pub trees: ::prost::alloc::vec::Vec<Node>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Null {
+    Null = 0,
+}
+impl Null {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Null::Null => "NULL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NULL" => Some(Self::Null),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,482 @@
+// Written by hand to follow the output of Thrift Compiler (0.17.0). build.rs doesn't regenerate
+// it, so keep it in step with the .thrift schema by hand.
+
+#![allow(unused_imports)]
+#![allow(unused_extern_crates)]
+#![allow(clippy::too_many_arguments, clippy::type_complexity, clippy::vec_box)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::{From, TryFrom};
+use std::default::Default;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+use thrift::OrderedFloat;
+use thrift::{ApplicationError, ApplicationErrorKind, ProtocolError, ProtocolErrorKind, TThriftClient};
+use thrift::protocol::{TFieldIdentifier, TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType, TInputProtocol, TOutputProtocol, TSerializable, TSetIdentifier, TStructIdentifier, TType};
+use thrift::protocol::field_id;
+use thrift::protocol::verify_expected_message_type;
+use thrift::protocol::verify_expected_sequence_number;
+use thrift::protocol::verify_expected_service_call;
+use thrift::protocol::verify_required_field_exists;
+use thrift::server::TProcessor;
+
+//
+// Null
+//
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Null(pub i32);
+
+impl Null {
+  pub const NULL: Null = Null(0);
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NULL,
+  ];
+}
+
+impl TSerializable for Null {
+  #[allow(clippy::trivially_copy_pass_by_ref)]
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    o_prot.write_i32(self.0)
+  }
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Null> {
+    let enum_value = i_prot.read_i32()?;
+    Ok(Null::from(enum_value))
+  }
+}
+
+impl From<i32> for Null {
+  fn from(i: i32) -> Self {
+    match i {
+      0 => Null::NULL,
+      _ => Null(i)
+    }
+  }
+}
+
+impl From<&i32> for Null {
+  fn from(i: &i32) -> Self {
+    Null::from(*i)
+  }
+}
+
+impl From<Null> for i32 {
+  fn from(e: Null) -> i32 {
+    e.0
+  }
+}
+
+impl From<&Null> for i32 {
+  fn from(e: &Null) -> i32 {
+    e.0
+  }
+}
+
+//
+// Node
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Node {
+  NullValue(Null),
+  BoolValue(bool),
+  NumberValue(OrderedFloat<f64>),
+  StringValue(String),
+  ArrayValue(Array),
+  ObjectValue(Object),
+}
+
+impl TSerializable for Node {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Node> {
+    let mut ret: Option<Node> = None;
+    let mut received_field_count = 0;
+    i_prot.read_struct_begin()?;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = Null::read_from_in_protocol(i_prot)?;
+          if ret.is_none() {
+            ret = Some(Node::NullValue(val));
+          }
+          received_field_count += 1;
+        },
+        2 => {
+          let val = i_prot.read_bool()?;
+          if ret.is_none() {
+            ret = Some(Node::BoolValue(val));
+          }
+          received_field_count += 1;
+        },
+        3 => {
+          let val = OrderedFloat::from(i_prot.read_double()?);
+          if ret.is_none() {
+            ret = Some(Node::NumberValue(val));
+          }
+          received_field_count += 1;
+        },
+        4 => {
+          let val = i_prot.read_string()?;
+          if ret.is_none() {
+            ret = Some(Node::StringValue(val));
+          }
+          received_field_count += 1;
+        },
+        5 => {
+          let val = Array::read_from_in_protocol(i_prot)?;
+          if ret.is_none() {
+            ret = Some(Node::ArrayValue(val));
+          }
+          received_field_count += 1;
+        },
+        6 => {
+          let val = Object::read_from_in_protocol(i_prot)?;
+          if ret.is_none() {
+            ret = Some(Node::ObjectValue(val));
+          }
+          received_field_count += 1;
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+          received_field_count += 1;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    if received_field_count == 0 {
+      Err(
+        thrift::Error::Protocol(
+          ProtocolError::new(
+            ProtocolErrorKind::InvalidData,
+            "received empty union from remote Node"
+          )
+        )
+      )
+    } else if received_field_count > 1 {
+      Err(
+        thrift::Error::Protocol(
+          ProtocolError::new(
+            ProtocolErrorKind::InvalidData,
+            "received multiple fields for union from remote Node"
+          )
+        )
+      )
+    } else {
+      Ok(ret.expect("return value should have been constructed"))
+    }
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Node");
+    o_prot.write_struct_begin(&struct_ident)?;
+    match *self {
+      Node::NullValue(f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("null_value", TType::I32, 1))?;
+        f.write_to_out_protocol(o_prot)?;
+        o_prot.write_field_end()?;
+      },
+      Node::BoolValue(f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("bool_value", TType::Bool, 2))?;
+        o_prot.write_bool(f)?;
+        o_prot.write_field_end()?;
+      },
+      Node::NumberValue(f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("number_value", TType::Double, 3))?;
+        o_prot.write_double(f.into())?;
+        o_prot.write_field_end()?;
+      },
+      Node::StringValue(ref f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("string_value", TType::String, 4))?;
+        o_prot.write_string(f)?;
+        o_prot.write_field_end()?;
+      },
+      Node::ArrayValue(ref f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("array_value", TType::Struct, 5))?;
+        f.write_to_out_protocol(o_prot)?;
+        o_prot.write_field_end()?;
+      },
+      Node::ObjectValue(ref f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("object_value", TType::Struct, 6))?;
+        f.write_to_out_protocol(o_prot)?;
+        o_prot.write_field_end()?;
+      },
+    }
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Array
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Array {
+  pub nodes: Vec<Node>,
+}
+
+impl Array {
+  pub fn new(nodes: Vec<Node>) -> Array {
+    Array {
+      nodes,
+    }
+  }
+}
+
+impl TSerializable for Array {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Array> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<Node>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Node> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_0 = Node::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_0);
+          }
+          i_prot.read_list_end()?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Array.nodes", &f_1)?;
+    let ret = Array {
+      nodes: f_1.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Array");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("nodes", TType::List, 1))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.nodes.len() as i32))?;
+    for e in &self.nodes {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Object
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Object {
+  pub members: Vec<Member>,
+}
+
+impl Object {
+  pub fn new(members: Vec<Member>) -> Object {
+    Object {
+      members,
+    }
+  }
+}
+
+impl TSerializable for Object {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Object> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<Member>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Member> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_1 = Member::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_1);
+          }
+          i_prot.read_list_end()?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Object.members", &f_1)?;
+    let ret = Object {
+      members: f_1.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Object");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("members", TType::List, 1))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.members.len() as i32))?;
+    for e in &self.members {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Member
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Member {
+  pub key: String,
+  pub value: Node,
+}
+
+impl Member {
+  pub fn new(key: String, value: Node) -> Member {
+    Member {
+      key,
+      value,
+    }
+  }
+}
+
+impl TSerializable for Member {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Member> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<String> = None;
+    let mut f_2: Option<Node> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_string()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = Node::read_from_in_protocol(i_prot)?;
+          f_2 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Member.key", &f_1)?;
+    verify_required_field_exists("Member.value", &f_2)?;
+    let ret = Member {
+      key: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      value: f_2.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Member");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("key", TType::String, 1))?;
+    o_prot.write_string(&self.key)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("value", TType::Struct, 2))?;
+    self.value.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Trees
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Trees {
+  pub trees: Vec<Node>,
+}
+
+impl Trees {
+  pub fn new(trees: Vec<Node>) -> Trees {
+    Trees {
+      trees,
+    }
+  }
+}
+
+impl TSerializable for Trees {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Trees> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<Node>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Node> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_2 = Node::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_2);
+          }
+          i_prot.read_list_end()?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Trees.trees", &f_1)?;
+    let ret = Trees {
+      trees: f_1.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Trees");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("trees", TType::List, 1))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.trees.len() as i32))?;
+    for e in &self.trees {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
@@ -0,0 +1,32 @@
+namespace rs tree
+
+enum Null {
+    NULL = 0
+}
+
+// Thrift reserves `bool` and `string`, so the values are named after their types instead.
+union Node {
+    1: Null null_value
+    2: bool bool_value
+    3: double number_value
+    4: string string_value
+    5: Array array_value
+    6: Object object_value
+}
+
+struct Array {
+    1: required list<Node> nodes
+}
+
+struct Object {
+    1: required list<Member> members
+}
+
+struct Member {
+    1: required string key
+    2: required Node value
+}
+
+struct Trees {
+    1: required list<Node> trees
+}
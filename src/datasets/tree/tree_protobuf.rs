@@ -0,0 +1,1122 @@
+// This file is generated by rust-protobuf 3.5.1. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `datasets/tree/tree.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_5_1;
+
+// @@protoc_insertion_point(message:prost.tree.Node)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Node {
+    // message oneof groups
+    pub value: ::std::option::Option<node::Value>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.tree.Node.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Node {
+    fn default() -> &'a Node {
+        <Node as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Node {
+    pub fn new() -> Node {
+        ::std::default::Default::default()
+    }
+
+    // .prost.tree.Null null = 1;
+
+    pub fn null(&self) -> Null {
+        match self.value {
+            ::std::option::Option::Some(node::Value::Null(v)) => ::protobuf::EnumOrUnknown::enum_value_or_default(&v),
+            _ => Null::NULL,
+        }
+    }
+
+    pub fn clear_null(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_null(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(node::Value::Null(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_null(&mut self, v: Null) {
+        self.value = ::std::option::Option::Some(node::Value::Null(::protobuf::EnumOrUnknown::new(v)))
+    }
+
+    // bool bool = 2;
+
+    pub fn bool(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(node::Value::Bool(v)) => v,
+            _ => false,
+        }
+    }
+
+    pub fn clear_bool(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_bool(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(node::Value::Bool(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bool(&mut self, v: bool) {
+        self.value = ::std::option::Option::Some(node::Value::Bool(v))
+    }
+
+    // double number = 3;
+
+    pub fn number(&self) -> f64 {
+        match self.value {
+            ::std::option::Option::Some(node::Value::Number(v)) => v,
+            _ => 0.,
+        }
+    }
+
+    pub fn clear_number(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_number(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(node::Value::Number(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_number(&mut self, v: f64) {
+        self.value = ::std::option::Option::Some(node::Value::Number(v))
+    }
+
+    // string string = 4;
+
+    pub fn string(&self) -> &str {
+        match self.value {
+            ::std::option::Option::Some(node::Value::String(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    pub fn clear_string(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_string(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(node::Value::String(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_string(&mut self, v: ::std::string::String) {
+        self.value = ::std::option::Option::Some(node::Value::String(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_string(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(node::Value::String(_)) = self.value {
+        } else {
+            self.value = ::std::option::Option::Some(node::Value::String(::std::string::String::new()));
+        }
+        match self.value {
+            ::std::option::Option::Some(node::Value::String(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_string(&mut self) -> ::std::string::String {
+        if self.has_string() {
+            match self.value.take() {
+                ::std::option::Option::Some(node::Value::String(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .prost.tree.Array array = 5;
+
+    pub fn array(&self) -> &Array {
+        match self.value {
+            ::std::option::Option::Some(node::Value::Array(ref v)) => v,
+            _ => <Array as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_array(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_array(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(node::Value::Array(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_array(&mut self, v: Array) {
+        self.value = ::std::option::Option::Some(node::Value::Array(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_array(&mut self) -> &mut Array {
+        if let ::std::option::Option::Some(node::Value::Array(_)) = self.value {
+        } else {
+            self.value = ::std::option::Option::Some(node::Value::Array(Array::new()));
+        }
+        match self.value {
+            ::std::option::Option::Some(node::Value::Array(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_array(&mut self) -> Array {
+        if self.has_array() {
+            match self.value.take() {
+                ::std::option::Option::Some(node::Value::Array(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Array::new()
+        }
+    }
+
+    // .prost.tree.Object object = 6;
+
+    pub fn object(&self) -> &Object {
+        match self.value {
+            ::std::option::Option::Some(node::Value::Object(ref v)) => v,
+            _ => <Object as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_object(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_object(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(node::Value::Object(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_object(&mut self, v: Object) {
+        self.value = ::std::option::Option::Some(node::Value::Object(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_object(&mut self) -> &mut Object {
+        if let ::std::option::Option::Some(node::Value::Object(_)) = self.value {
+        } else {
+            self.value = ::std::option::Option::Some(node::Value::Object(Object::new()));
+        }
+        match self.value {
+            ::std::option::Option::Some(node::Value::Object(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_object(&mut self) -> Object {
+        if self.has_object() {
+            match self.value.take() {
+                ::std::option::Option::Some(node::Value::Object(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Object::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_enum_accessors::<_, _>(
+            "null",
+            |message: &Node| match &message.value {
+                ::std::option::Option::Some(node::Value::Null(e)) => ::std::option::Option::Some(*e),
+                _ => ::std::option::Option::None,
+            },
+            |message: &mut Node, e: ::protobuf::EnumOrUnknown<Null>| {
+                message.value = ::std::option::Option::Some(node::Value::Null(e));
+            },
+            Null::NULL,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "bool",
+            Node::has_bool,
+            Node::bool,
+            Node::set_bool,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "number",
+            Node::has_number,
+            Node::number,
+            Node::set_number,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "string",
+            Node::has_string,
+            Node::string,
+            Node::set_string,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Array>(
+            "array",
+            Node::has_array,
+            Node::array,
+            Node::mut_array,
+            Node::set_array,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Object>(
+            "object",
+            Node::has_object,
+            Node::object,
+            Node::mut_object,
+            Node::set_object,
+        ));
+        oneofs.push(node::Value::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Node>(
+            "Node",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Node {
+    const NAME: &'static str = "Node";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.value = ::std::option::Option::Some(node::Value::Null(is.read_enum_or_unknown()?));
+                },
+                16 => {
+                    self.value = ::std::option::Option::Some(node::Value::Bool(is.read_bool()?));
+                },
+                25 => {
+                    self.value = ::std::option::Option::Some(node::Value::Number(is.read_double()?));
+                },
+                34 => {
+                    self.value = ::std::option::Option::Some(node::Value::String(is.read_string()?));
+                },
+                42 => {
+                    self.value = ::std::option::Option::Some(node::Value::Array(is.read_message()?));
+                },
+                50 => {
+                    self.value = ::std::option::Option::Some(node::Value::Object(is.read_message()?));
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.value {
+            match v {
+                &node::Value::Null(v) => {
+                    my_size += ::protobuf::rt::int32_size(1, v.value());
+                },
+                &node::Value::Bool(v) => {
+                    my_size += 1 + 1;
+                },
+                &node::Value::Number(v) => {
+                    my_size += 1 + 8;
+                },
+                &node::Value::String(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+                &node::Value::Array(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &node::Value::Object(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let ::std::option::Option::Some(ref v) = self.value {
+            match v {
+                &node::Value::Null(v) => {
+                    os.write_enum(1, ::protobuf::EnumOrUnknown::value(&v))?;
+                },
+                &node::Value::Bool(v) => {
+                    os.write_bool(2, v)?;
+                },
+                &node::Value::Number(v) => {
+                    os.write_double(3, v)?;
+                },
+                &node::Value::String(ref v) => {
+                    os.write_string(4, v)?;
+                },
+                &node::Value::Array(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+                &node::Value::Object(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Node {
+        Node::new()
+    }
+
+    fn clear(&mut self) {
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.value = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Node {
+        static instance: Node = Node {
+            value: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Node {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Node").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Node {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Node {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Node`
+pub mod node {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:prost.tree.Node.value)
+    pub enum Value {
+        // @@protoc_insertion_point(oneof_field:prost.tree.Node.null)
+        Null(::protobuf::EnumOrUnknown<super::Null>),
+        // @@protoc_insertion_point(oneof_field:prost.tree.Node.bool)
+        Bool(bool),
+        // @@protoc_insertion_point(oneof_field:prost.tree.Node.number)
+        Number(f64),
+        // @@protoc_insertion_point(oneof_field:prost.tree.Node.string)
+        String(::std::string::String),
+        // @@protoc_insertion_point(oneof_field:prost.tree.Node.array)
+        Array(super::Array),
+        // @@protoc_insertion_point(oneof_field:prost.tree.Node.object)
+        Object(super::Object),
+    }
+
+    impl ::protobuf::Oneof for Value {
+    }
+
+    impl ::protobuf::OneofFull for Value {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::Node as ::protobuf::MessageFull>::descriptor().oneof_by_name("value").unwrap()).clone()
+        }
+    }
+
+    impl Value {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Value>("value")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:prost.tree.Array)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Array {
+    // message fields
+    // @@protoc_insertion_point(field:prost.tree.Array.nodes)
+    pub nodes: ::std::vec::Vec<Node>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.tree.Array.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Array {
+    fn default() -> &'a Array {
+        <Array as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Array {
+    pub fn new() -> Array {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "nodes",
+            |m: &Array| { &m.nodes },
+            |m: &mut Array| { &mut m.nodes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Array>(
+            "Array",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Array {
+    const NAME: &'static str = "Array";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.nodes.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.nodes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.nodes {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Array {
+        Array::new()
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Array {
+        static instance: Array = Array {
+            nodes: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Array {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Array").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Array {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Array {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.tree.Object)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Object {
+    // message fields
+    // @@protoc_insertion_point(field:prost.tree.Object.members)
+    pub members: ::std::vec::Vec<Member>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.tree.Object.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Object {
+    fn default() -> &'a Object {
+        <Object as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Object {
+    pub fn new() -> Object {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "members",
+            |m: &Object| { &m.members },
+            |m: &mut Object| { &mut m.members },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Object>(
+            "Object",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Object {
+    const NAME: &'static str = "Object";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.members.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.members {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.members {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Object {
+        Object::new()
+    }
+
+    fn clear(&mut self) {
+        self.members.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Object {
+        static instance: Object = Object {
+            members: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Object {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Object").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Object {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Object {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.tree.Member)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Member {
+    // message fields
+    // @@protoc_insertion_point(field:prost.tree.Member.key)
+    pub key: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.tree.Member.value)
+    pub value: ::protobuf::MessageField<Node>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.tree.Member.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Member {
+    fn default() -> &'a Member {
+        <Member as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Member {
+    pub fn new() -> Member {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "key",
+            |m: &Member| { &m.key },
+            |m: &mut Member| { &mut m.key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Node>(
+            "value",
+            |m: &Member| { &m.value },
+            |m: &mut Member| { &mut m.value },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Member>(
+            "Member",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Member {
+    const NAME: &'static str = "Member";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.key = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.value)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if let Some(v) = self.value.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if let Some(v) = self.value.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Member {
+        Member::new()
+    }
+
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Member {
+        static instance: Member = Member {
+            key: ::std::string::String::new(),
+            value: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Member {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Member").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Member {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Member {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.tree.Trees)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Trees {
+    // message fields
+    // @@protoc_insertion_point(field:prost.tree.Trees.trees)
+    pub trees: ::std::vec::Vec<Node>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.tree.Trees.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Trees {
+    fn default() -> &'a Trees {
+        <Trees as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Trees {
+    pub fn new() -> Trees {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "trees",
+            |m: &Trees| { &m.trees },
+            |m: &mut Trees| { &mut m.trees },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Trees>(
+            "Trees",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Trees {
+    const NAME: &'static str = "Trees";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.trees.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.trees {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.trees {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Trees {
+        Trees::new()
+    }
+
+    fn clear(&mut self) {
+        self.trees.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Trees {
+        static instance: Trees = Trees {
+            trees: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Trees {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Trees").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Trees {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Trees {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:prost.tree.Null)
+pub enum Null {
+    // @@protoc_insertion_point(enum_value:prost.tree.Null.NULL)
+    NULL = 0,
+}
+
+impl ::protobuf::Enum for Null {
+    const NAME: &'static str = "Null";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Null> {
+        match value {
+            0 => ::std::option::Option::Some(Null::NULL),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<Null> {
+        match str {
+            "NULL" => ::std::option::Option::Some(Null::NULL),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [Null] = &[
+        Null::NULL,
+    ];
+}
+
+impl ::protobuf::EnumFull for Null {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("Null").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for Null {
+    fn default() -> Self {
+        Null::NULL
+    }
+}
+
+impl Null {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Null>("Null")
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x18datasets/tree/tree.proto\x12\nprost.tree\"\xda\x01\n\x04Node\x12&\
+    \n\x04null\x18\x01\x20\x01(\x0e2\x10.prost.tree.NullH\0R\x04null\x12\x14\
+    \n\x04bool\x18\x02\x20\x01(\x08H\0R\x04bool\x12\x18\n\x06number\x18\x03\
+    \x20\x01(\x01H\0R\x06number\x12\x18\n\x06string\x18\x04\x20\x01(\tH\0R\
+    \x06string\x12)\n\x05array\x18\x05\x20\x01(\x0b2\x11.prost.tree.ArrayH\0\
+    R\x05array\x12,\n\x06object\x18\x06\x20\x01(\x0b2\x12.prost.tree.ObjectH\
+    \0R\x06objectB\x07\n\x05value\"/\n\x05Array\x12&\n\x05nodes\x18\x01\x20\
+    \x03(\x0b2\x10.prost.tree.NodeR\x05nodes\"6\n\x06Object\x12,\n\x07member\
+    s\x18\x01\x20\x03(\x0b2\x12.prost.tree.MemberR\x07members\"B\n\x06Member\
+    \x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12&\n\x05value\x18\x02\x20\
+    \x01(\x0b2\x10.prost.tree.NodeR\x05value\"/\n\x05Trees\x12&\n\x05trees\
+    \x18\x01\x20\x03(\x0b2\x10.prost.tree.NodeR\x05trees*\x10\n\x04Null\x12\
+    \x08\n\x04NULL\x10\0b\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(0);
+            let mut messages = ::std::vec::Vec::with_capacity(5);
+            messages.push(Node::generated_message_descriptor_data());
+            messages.push(Array::generated_message_descriptor_data());
+            messages.push(Object::generated_message_descriptor_data());
+            messages.push(Member::generated_message_descriptor_data());
+            messages.push(Trees::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(Null::generated_enum_descriptor_data());
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -0,0 +1,250 @@
+// Automatically generated rust module for 'tree.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Null {
+    NULL = 0,
+}
+
+impl Default for Null {
+    fn default() -> Self {
+        Null::NULL
+    }
+}
+
+impl From<i32> for Null {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Null::NULL,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Null {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "NULL" => Null::NULL,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Node<'a> {
+    pub value: tree::mod_Node::OneOfvalue<'a>,
+}
+
+impl<'a> MessageRead<'a> for Node<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.value = tree::mod_Node::OneOfvalue::null(r.read_enum(bytes)?),
+                Ok(16) => msg.value = tree::mod_Node::OneOfvalue::bool_pb(r.read_bool(bytes)?),
+                Ok(25) => msg.value = tree::mod_Node::OneOfvalue::number(r.read_double(bytes)?),
+                Ok(34) => msg.value = tree::mod_Node::OneOfvalue::string(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(42) => msg.value = tree::mod_Node::OneOfvalue::array(r.read_message::<tree::Array>(bytes)?),
+                Ok(50) => msg.value = tree::mod_Node::OneOfvalue::object(r.read_message::<tree::Object>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Node<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + match self.value {
+            tree::mod_Node::OneOfvalue::null(ref m) => 1 + sizeof_varint(*(m) as u64),
+            tree::mod_Node::OneOfvalue::bool_pb(ref m) => 1 + sizeof_varint(*(m) as u64),
+            tree::mod_Node::OneOfvalue::number(_) => 1 + 8,
+            tree::mod_Node::OneOfvalue::string(ref m) => 1 + sizeof_len((m).len()),
+            tree::mod_Node::OneOfvalue::array(ref m) => 1 + sizeof_len((m).get_size()),
+            tree::mod_Node::OneOfvalue::object(ref m) => 1 + sizeof_len((m).get_size()),
+            tree::mod_Node::OneOfvalue::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        match self.value {            tree::mod_Node::OneOfvalue::null(ref m) => { w.write_with_tag(8, |w| w.write_enum(*m as i32))? },
+            tree::mod_Node::OneOfvalue::bool_pb(ref m) => { w.write_with_tag(16, |w| w.write_bool(*m))? },
+            tree::mod_Node::OneOfvalue::number(ref m) => { w.write_with_tag(25, |w| w.write_double(*m))? },
+            tree::mod_Node::OneOfvalue::string(ref m) => { w.write_with_tag(34, |w| w.write_string(&**m))? },
+            tree::mod_Node::OneOfvalue::array(ref m) => { w.write_with_tag(42, |w| w.write_message(m))? },
+            tree::mod_Node::OneOfvalue::object(ref m) => { w.write_with_tag(50, |w| w.write_message(m))? },
+            tree::mod_Node::OneOfvalue::None => {},
+    }        Ok(())
+    }
+}
+
+pub mod mod_Node {
+
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfvalue<'a> {
+    null(tree::Null),
+    bool_pb(bool),
+    number(f64),
+    string(Cow<'a, str>),
+    array(tree::Array<'a>),
+    object(tree::Object<'a>),
+    None,
+}
+
+impl<'a> Default for OneOfvalue<'a> {
+    fn default() -> Self {
+        OneOfvalue::None
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Array<'a> {
+    pub nodes: Vec<tree::Node<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Array<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.nodes.push(r.read_message::<tree::Node>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Array<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.nodes.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.nodes { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Object<'a> {
+    pub members: Vec<tree::Member<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Object<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.members.push(r.read_message::<tree::Member>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Object<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.members.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.members { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Member<'a> {
+    pub key: Cow<'a, str>,
+    pub value: Option<tree::Node<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Member<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.key = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => msg.value = Some(r.read_message::<tree::Node>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Member<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.key == "" { 0 } else { 1 + sizeof_len((&self.key).len()) }
+        + self.value.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.key != "" { w.write_with_tag(10, |w| w.write_string(&**&self.key))?; }
+        if let Some(ref s) = self.value { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Trees<'a> {
+    pub trees: Vec<tree::Node<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Trees<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.trees.push(r.read_message::<tree::Node>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Trees<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.trees.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.trees { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
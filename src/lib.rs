@@ -3,6 +3,10 @@
 // wiring causes this clippy lint everywhere
 #![cfg_attr(feature = "wiring", allow(clippy::manual_async_fn))]
 
+#[cfg(feature = "apache-avro")]
+pub mod bench_avro;
+#[cfg(feature = "bebop")]
+pub mod bench_bebop;
 #[cfg(feature = "bilrost")]
 pub mod bench_bilrost;
 #[cfg(feature = "bincode")]
@@ -13,6 +17,10 @@ pub mod bench_bincode1;
 pub mod bench_bitcode;
 #[cfg(feature = "borsh")]
 pub mod bench_borsh;
+#[cfg(feature = "bson")]
+pub mod bench_bson;
+#[cfg(feature = "bytemuck")]
+pub mod bench_bytemuck;
 #[cfg(feature = "capnp")]
 pub mod bench_capnp;
 #[cfg(feature = "cbor4ii")]
@@ -23,10 +31,20 @@ pub mod bench_ciborium;
 pub mod bench_databuf;
 #[cfg(feature = "dlhn")]
 pub mod bench_dlhn;
+#[cfg(feature = "ssz")]
+pub mod bench_ethereum_ssz;
 #[cfg(feature = "flatbuffers")]
 pub mod bench_flatbuffers;
+#[cfg(feature = "flexbuffers")]
+pub mod bench_flexbuffers;
+#[cfg(feature = "ion-rs")]
+pub mod bench_ion_rs;
 #[cfg(feature = "msgpacker")]
 pub mod bench_msgpacker;
+#[cfg(feature = "musli")]
+pub mod bench_musli;
+#[cfg(feature = "musli-zerocopy")]
+pub mod bench_musli_zerocopy;
 #[cfg(feature = "nachricht-serde")]
 pub mod bench_nachricht_serde;
 #[cfg(feature = "nanoserde")]
@@ -39,6 +57,14 @@ pub mod bench_postcard;
 pub mod bench_pot;
 #[cfg(feature = "prost")]
 pub mod bench_prost;
+#[cfg(feature = "protobuf")]
+pub mod bench_protobuf;
+#[cfg(feature = "quick-protobuf")]
+pub mod bench_quick_protobuf;
+#[cfg(feature = "quick-xml")]
+pub mod bench_quick_xml;
+#[cfg(feature = "rasn")]
+pub mod bench_rasn;
 #[cfg(feature = "rkyv")]
 pub mod bench_rkyv;
 #[cfg(feature = "rmp-serde")]
@@ -55,18 +81,35 @@ pub mod bench_serde_brief;
 pub mod bench_serde_cbor;
 #[cfg(feature = "serde_json")]
 pub mod bench_serde_json;
+#[cfg(feature = "serde_yaml")]
+pub mod bench_serde_yaml;
 #[cfg(feature = "simd-json")]
 pub mod bench_simd_json;
+#[cfg(feature = "sonic-rs")]
+pub mod bench_sonic_rs;
 #[cfg(feature = "speedy")]
 pub mod bench_speedy;
+#[cfg(feature = "thrift")]
+pub mod bench_thrift;
+#[cfg(feature = "toml")]
+pub mod bench_toml;
+#[cfg(feature = "transmute")]
 pub mod bench_transmute;
 #[cfg(feature = "wiring")]
 pub mod bench_wiring;
+#[cfg(feature = "zerocopy")]
+pub mod bench_zerocopy;
 pub mod datasets;
-use core::{mem, ops};
+use core::{fmt, mem, ops};
+use std::time::{Duration, Instant};
 
+use criterion::black_box;
+use hdrhistogram::Histogram;
 use rand::Rng;
 
+/// Derives `Generate`, see the `generate_derive` crate for the field attributes it takes.
+pub use generate_derive::Generate;
+
 pub trait Generate {
     fn generate<R: Rng>(rng: &mut R) -> Self;
 }
@@ -153,18 +196,174 @@ pub fn generate_vec<R: Rng, T: Generate>(rng: &mut R, range: ops::Range<usize>)
     result
 }
 
+/// A dataset type with a twin that borrows its strings from the buffer it is deserialized from.
+pub trait Borrowable: Sized {
+    type Borrowed<'de>: serde::Deserialize<'de> + Into<Self>;
+}
+
+/// A dataset type with a projection that only keeps the fields a typical consumer looks at.
+pub trait Projectable {
+    /// The fields that the projection keeps.
+    type Fields: PartialEq;
+    /// Deserializes the kept fields and skips everything else with `IgnoredAny`, so it needs a
+    /// self-describing format.
+    type Projection: serde::de::DeserializeOwned + Into<Self::Fields>;
+
+    fn project(&self) -> Self::Fields;
+}
+
+/// A dataset type that can be indexed like the buffers in the zero-copy `read_random` benchmarks.
+pub trait ReadRandom {
+    /// Reads the same fields out of the elements at `indices` as the zero-copy `read_random`
+    /// benchmarks do.
+    fn read_random(&self, indices: &[usize]);
+}
+
+/// Records that `lib` can't represent the `name` data set, along with the reason the formatter shows
+/// in its place.
+pub fn bench_skipped(name: &str, lib: &str, reason: &str) {
+    println!("{}/{}/skipped {}", name, lib, reason);
+}
+
+/// Encodes `data` and checks that it decodes back to the same value.
+///
+/// Returns the encoded data, or `None` after recording the bench as skipped if the format can't
+/// round-trip the data set.
+pub fn encode_or_skip<T: PartialEq, B, E: fmt::Display, D: fmt::Display>(
+    name: &str,
+    lib: &str,
+    data: &T,
+    encode: impl FnOnce(&T) -> Result<B, E>,
+    decode: impl FnOnce(&B) -> Result<T, D>,
+) -> Option<B> {
+    let reason = match encode(data) {
+        Err(e) => format!("can't serialize: {e}"),
+        Ok(encoded) => match decode(&encoded) {
+            Err(e) => format!("can't deserialize what it serialized: {e}"),
+            Ok(decoded) if decoded != *data => "deserializes to different data".to_string(),
+            Ok(_) => return Some(encoded),
+        },
+    };
+    bench_skipped(name, lib, &reason);
+    None
+}
+
+/// Records whether `lib` rejects an adversarial `message` when reading it with the given `limits`.
+pub fn bench_adversarial<E: fmt::Display>(
+    lib: &str,
+    message: &str,
+    limits: &str,
+    result: Result<(), E>,
+) {
+    let outcome = match result {
+        Ok(()) => "accepted".to_string(),
+        // Only keep the first line, some errors go on to describe where they happened.
+        Err(e) => format!(
+            "rejected ({})",
+            e.to_string().lines().next().unwrap_or_default()
+        ),
+    };
+    println!("{lib}/adversarial {message} with {limits}: {outcome}");
+}
+
+/// Finds the smallest limit up to `max` that `accepts`, assuming that every larger limit does too.
+pub fn smallest_limit(max: usize, accepts: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, max);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if accepts(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+/// Records how many elements the `name` data set was generated with.
+pub fn bench_scale(name: &str, elements: usize) {
+    println!("{}/scale {}", name, elements);
+}
+
+/// Records the PCG state that the `name` data set was generated from.
+pub fn bench_seed(name: &str, state: u64) {
+    println!("{}/seed {}", name, state);
+}
+
 pub fn bench_size(name: &str, lib: &str, bytes: &[u8]) {
-    println!("{}/{}/size {}", name, lib, bytes.len());
-    println!("{}/{}/zlib {}", name, lib, zlib_size(bytes));
-    println!("{}/{}/zstd {}", name, lib, zstd_size(bytes));
+    print_size(name, lib, "", bytes);
+}
+
+/// Records the size of another encoding from the same crate, such as a container around the
+/// encoding passed to `bench_size`.
+pub fn bench_size_variant(name: &str, lib: &str, variant: &str, bytes: &[u8]) {
+    print_size(name, lib, &format!(" ({variant})"), bytes);
+}
+
+fn print_size(name: &str, lib: &str, variant: &str, bytes: &[u8]) {
+    println!("{}/{}/size{} {}", name, lib, variant, bytes.len());
+    println!("{}/{}/zlib{} {}", name, lib, variant, zlib_size(bytes));
+    println!("{}/{}/zstd{} {}", name, lib, variant, zstd_size(bytes));
     println!(
-        "{}/{}/zstd_time {}",
+        "{}/{}/zstd_time{} {}",
         name,
         lib,
+        variant,
         bench_compression(|| zstd_size(bytes))
     );
 }
 
+/// Times individual calls to `f` and reports their latency percentiles.
+///
+/// Criterion only reports an estimate of the mean, which hides tail latency. This runs a separate
+/// measurement loop after the criterion benchmark and records each iteration into a histogram.
+/// The loop knows nothing about criterion's filter or `--test` and `--list` modes, so it only runs
+/// when `BENCH_LATENCY` is set.
+pub fn bench_latency<O>(name: &str, lib: &str, bench: &str, mut f: impl FnMut() -> O) {
+    bench_latency_batched(name, lib, bench, || (), |_| f());
+}
+
+/// Like `bench_latency`, but runs `setup` before each timed call to `routine`.
+pub fn bench_latency_batched<I, O>(
+    name: &str,
+    lib: &str,
+    bench: &str,
+    mut setup: impl FnMut() -> I,
+    mut routine: impl FnMut(&mut I) -> O,
+) {
+    const MIN_SAMPLES: u64 = 1_000;
+    const MAX_SAMPLES: u64 = 100_000;
+    const TARGET_TIME: Duration = Duration::from_secs(1);
+
+    if std::env::var_os("BENCH_LATENCY").is_none() {
+        return;
+    }
+
+    let mut histogram = Histogram::<u64>::new(3).unwrap();
+    let start = Instant::now();
+    while histogram.len() < MAX_SAMPLES
+        && (histogram.len() < MIN_SAMPLES || start.elapsed() < TARGET_TIME)
+    {
+        let mut input = setup();
+        let iteration_start = Instant::now();
+        let output = routine(black_box(&mut input));
+        let elapsed = iteration_start.elapsed();
+        black_box(output);
+        histogram.record(elapsed.as_nanos() as u64).unwrap();
+    }
+
+    for (percentile, quantile) in [("p50", 0.5), ("p90", 0.9), ("p99", 0.99), ("p999", 0.999)] {
+        println!(
+            "{}/{}/{}/{} {}",
+            name,
+            lib,
+            bench,
+            percentile,
+            histogram.value_at_quantile(quantile)
+        );
+    }
+}
+
 fn bench_compression(compress: impl Fn() -> usize) -> String {
     let start = std::time::Instant::now();
     let size = compress();
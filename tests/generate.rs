@@ -0,0 +1,206 @@
+//! Checks that the derived `Generate` impls draw the same data as the hand-written impls they
+//! replaced, so moving a type onto the derive doesn't change the data sets for a given seed.
+
+use rand::Rng;
+use rand_pcg::Lcg64Xsh32;
+use rust_serialization_benchmark::datasets::log::Address;
+use rust_serialization_benchmark::datasets::mesh::{Triangle, Vector3};
+use rust_serialization_benchmark::datasets::minecraft_savedata::{
+    Abilities, Entity, GameType, Item, Player, RecipeBook,
+};
+use rust_serialization_benchmark::{generate_vec, Generate};
+
+const SEEDS: u64 = 20;
+const STREAM: u64 = 5897932384;
+const LEN: usize = 100;
+
+/// Generates `LEN` values with both `derived` and `hand_written` from each seed, and checks that
+/// they're equal and leave the RNG in the same state.
+fn assert_same<T: PartialEq>(
+    derived: impl Fn(&mut Lcg64Xsh32) -> T,
+    hand_written: impl Fn(&mut Lcg64Xsh32) -> T,
+) {
+    for seed in 0..SEEDS {
+        let mut derived_rng = Lcg64Xsh32::new(seed, STREAM);
+        let mut hand_written_rng = Lcg64Xsh32::new(seed, STREAM);
+        for _ in 0..LEN {
+            assert!(derived(&mut derived_rng) == hand_written(&mut hand_written_rng));
+        }
+        assert_eq!(derived_rng.gen::<u64>(), hand_written_rng.gen::<u64>());
+    }
+}
+
+#[test]
+fn address() {
+    assert_same(Address::generate, |rng| Address {
+        x0: rng.gen_range(0..=255),
+        x1: rng.gen_range(0..=255),
+        x2: rng.gen_range(0..=255),
+        x3: rng.gen_range(0..=255),
+    });
+}
+
+fn vector3(rng: &mut Lcg64Xsh32) -> Vector3 {
+    Vector3 {
+        x: rng.gen(),
+        y: rng.gen(),
+        z: rng.gen(),
+    }
+}
+
+#[test]
+fn triangle() {
+    assert_same(Vector3::generate, vector3);
+    assert_same(Triangle::generate, |rng| Triangle {
+        v0: vector3(rng),
+        v1: vector3(rng),
+        v2: vector3(rng),
+        normal: vector3(rng),
+    });
+}
+
+#[test]
+fn game_type() {
+    assert_same(GameType::generate, |rng| match rng.gen_range(0..4) {
+        0 => GameType::Survival,
+        1 => GameType::Creative,
+        2 => GameType::Adventure,
+        3 => GameType::Spectator,
+        _ => unreachable!(),
+    });
+}
+
+#[test]
+fn item() {
+    const IDS: [&str; 8] = [
+        "dirt",
+        "stone",
+        "pickaxe",
+        "sand",
+        "gravel",
+        "shovel",
+        "chestplate",
+        "steak",
+    ];
+    assert_same(Item::generate, |rng| Item {
+        count: rng.gen(),
+        slot: rng.gen(),
+        id: IDS[rng.gen_range(0..IDS.len())].to_string(),
+    });
+}
+
+#[test]
+fn abilities() {
+    assert_same(Abilities::generate, |rng| Abilities {
+        walk_speed: rng.gen(),
+        fly_speed: rng.gen(),
+        may_fly: rng.gen_bool(0.5),
+        flying: rng.gen_bool(0.5),
+        invulnerable: rng.gen_bool(0.5),
+        may_build: rng.gen_bool(0.5),
+        instabuild: rng.gen_bool(0.5),
+    });
+}
+
+#[test]
+fn entity() {
+    const IDS: [&str; 8] = [
+        "cow", "sheep", "zombie", "skeleton", "spider", "creeper", "parrot", "bee",
+    ];
+    const CUSTOM_NAMES: [&str; 8] = [
+        "rainbow", "princess", "steve", "johnny", "missy", "coward", "fairy", "howard",
+    ];
+    assert_same(Entity::generate, |rng| Entity {
+        id: IDS[rng.gen_range(0..IDS.len())].to_string(),
+        pos: <(f64, f64, f64) as Generate>::generate(rng),
+        motion: <(f64, f64, f64) as Generate>::generate(rng),
+        rotation: <(f32, f32) as Generate>::generate(rng),
+        fall_distance: rng.gen(),
+        fire: rng.gen(),
+        air: rng.gen(),
+        on_ground: rng.gen_bool(0.5),
+        no_gravity: rng.gen_bool(0.5),
+        invulnerable: rng.gen_bool(0.5),
+        portal_cooldown: rng.gen(),
+        uuid: <[u32; 4] as Generate>::generate(rng),
+        custom_name: <Option<()> as Generate>::generate(rng)
+            .map(|_| CUSTOM_NAMES[rng.gen_range(0..CUSTOM_NAMES.len())].to_string()),
+        custom_name_visible: rng.gen_bool(0.5),
+        silent: rng.gen_bool(0.5),
+        glowing: rng.gen_bool(0.5),
+    });
+}
+
+#[test]
+fn recipe_book() {
+    const RECIPES: [&str; 8] = [
+        "pickaxe",
+        "torch",
+        "bow",
+        "crafting table",
+        "furnace",
+        "shears",
+        "arrow",
+        "tnt",
+    ];
+    const MAX_RECIPES: usize = 30;
+    const MAX_DISPLAYED_RECIPES: usize = 10;
+    assert_same(RecipeBook::generate, |rng| RecipeBook {
+        recipes: generate_vec::<_, ()>(rng, 0..MAX_RECIPES)
+            .iter()
+            .map(|_| RECIPES[rng.gen_range(0..RECIPES.len())].to_string())
+            .collect(),
+        to_be_displayed: generate_vec::<_, ()>(rng, 0..MAX_DISPLAYED_RECIPES)
+            .iter()
+            .map(|_| RECIPES[rng.gen_range(0..RECIPES.len())].to_string())
+            .collect(),
+        is_filtering_craftable: rng.gen_bool(0.5),
+        is_gui_open: rng.gen_bool(0.5),
+        is_furnace_filtering_craftable: rng.gen_bool(0.5),
+        is_furnace_gui_open: rng.gen_bool(0.5),
+        is_blasting_furnace_filtering_craftable: rng.gen_bool(0.5),
+        is_blasting_furnace_gui_open: rng.gen_bool(0.5),
+        is_smoker_filtering_craftable: rng.gen_bool(0.5),
+        is_smoker_gui_open: rng.gen_bool(0.5),
+    });
+}
+
+/// The fields of `Player` are checked against the derived impls of their own types, which the
+/// other tests compare with the hand-written ones.
+#[test]
+fn player() {
+    const DIMENSIONS: [&str; 3] = ["overworld", "nether", "end"];
+    const MAX_ITEMS: usize = 40;
+    const MAX_ENDER_ITEMS: usize = 27;
+    assert_same(Player::generate, |rng| Player {
+        game_type: GameType::generate(rng),
+        previous_game_type: GameType::generate(rng),
+        score: rng.gen(),
+        dimension: DIMENSIONS[rng.gen_range(0..DIMENSIONS.len())].to_string(),
+        selected_item_slot: rng.gen(),
+        selected_item: Item::generate(rng),
+        spawn_dimension: <Option<()> as Generate>::generate(rng)
+            .map(|_| DIMENSIONS[rng.gen_range(0..DIMENSIONS.len())].to_string()),
+        spawn_x: rng.gen(),
+        spawn_y: rng.gen(),
+        spawn_z: rng.gen(),
+        spawn_forced: <Option<bool> as Generate>::generate(rng),
+        sleep_timer: rng.gen(),
+        food_exhaustion_level: rng.gen(),
+        food_saturation_level: rng.gen(),
+        food_tick_timer: rng.gen(),
+        xp_level: rng.gen(),
+        xp_p: rng.gen(),
+        xp_total: rng.gen(),
+        xp_seed: rng.gen(),
+        inventory: generate_vec(rng, 0..MAX_ITEMS),
+        ender_items: generate_vec(rng, 0..MAX_ENDER_ITEMS),
+        abilities: Abilities::generate(rng),
+        entered_nether_position: <Option<(f64, f64, f64)> as Generate>::generate(rng),
+        root_vehicle: <Option<([u32; 4], Entity)> as Generate>::generate(rng),
+        shoulder_entity_left: <Option<Entity> as Generate>::generate(rng),
+        shoulder_entity_right: <Option<Entity> as Generate>::generate(rng),
+        seen_credits: rng.gen_bool(0.5),
+        recipe_book: RecipeBook::generate(rng),
+    });
+}
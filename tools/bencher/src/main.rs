@@ -3,6 +3,13 @@ use std::{fs, path::PathBuf, process::Command};
 use tempfile::NamedTempFile;
 use time::OffsetDateTime;
 
+const RKYV_FORMAT_FEATURES: &[&str] = &[
+    "rkyv-big_endian",
+    "rkyv-unaligned",
+    "rkyv-pointer_width_16",
+    "rkyv-pointer_width_64",
+];
+
 fn main() {
     let now = OffsetDateTime::now_utc();
 
@@ -43,11 +50,24 @@ fn main() {
 
     let mut log_path = bench_path.clone();
     log_path.set_extension("log");
-    let log = Command::new("cargo")
+    let mut log = Command::new("cargo")
         .args(["bench"])
+        .env("BENCH_LATENCY", "1")
         .output()
         .unwrap()
         .stdout;
+    // These change rkyv's format for the whole build, so they each get a separate run with nothing
+    // else enabled.
+    for feature in RKYV_FORMAT_FEATURES {
+        log.extend(
+            Command::new("cargo")
+                .args(["bench", "--no-default-features", "--features", feature])
+                .env("BENCH_LATENCY", "1")
+                .output()
+                .unwrap()
+                .stdout,
+        );
+    }
     fs::write(&log_path, log).unwrap();
 
     let mut config_path = PathBuf::from("tools");
@@ -1,5 +1,6 @@
 use std::{
-    collections::BTreeSet,
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
     fmt::{self, Display, Write},
     fs,
     path::PathBuf,
@@ -7,7 +8,10 @@ use std::{
 
 use clap::Parser;
 
-use schema::{Bench, Config, Dataset, Features, Results, Values};
+use schema::{
+    base_dataset_name, split_seed, Adversarial, Bench, Config, Dataset, Feature, Features, Results,
+    Values,
+};
 
 #[derive(Parser, Debug)]
 #[command(name = "formatter")]
@@ -99,9 +103,15 @@ fn format_values<T: Copy, U: Display>(
 
 fn write_crate_row(output: &mut String, feature: &str, features: &Features) -> fmt::Result {
     let package_id = features.get(feature).unwrap();
+    // Features that bench one of several encodings of a crate are named `<crate>-<encoding>`
+    let encoding = feature
+        .strip_prefix(package_id.name.as_str())
+        .and_then(|rest| rest.strip_prefix('-'))
+        .map(|encoding| format!(" ({encoding})"))
+        .unwrap_or_default();
     write!(
         output,
-        "| [{} {}][{feature}] |",
+        "| [{} {}{encoding}][{feature}] |",
         package_id.name, package_id.version
     )
 }
@@ -119,11 +129,24 @@ pub fn capitalize(s: &str) -> String {
         .join(" ")
 }
 
+/// Looks up the bench for a column, deriving `<bench>_p99` columns from the latency percentiles.
+fn column_bench<'a>(feature: &'a Feature, column: &str) -> Option<Cow<'a, Bench>> {
+    if let Some(bench) = column.strip_suffix("_p99") {
+        feature
+            .latencies
+            .get(bench)
+            .map(|values| Cow::Owned(Bench::Nanos(values.map(|latency| latency.p99))))
+    } else {
+        feature.benches.get(column).map(Cow::Borrowed)
+    }
+}
+
 fn build_tables(
     features: &Features,
     dataset: &Dataset,
     columns: &[&str],
     placeholder: &str,
+    show_skipped: bool,
 ) -> Result<Tables, fmt::Error> {
     let mut header = "| Crate |".to_string();
     for column in columns {
@@ -144,8 +167,8 @@ fn build_tables(
             dataset
                 .features
                 .values()
-                .filter_map(|feature| feature.benches.get(col))
-                .map(|bench| match bench {
+                .filter_map(|feature| column_bench(feature, col))
+                .map(|bench| match bench.as_ref() {
                     Bench::Nanos(values) => values.iter().cloned().reduce(f64::min).unwrap(),
                     Bench::Bytes(values) => values.iter().cloned().min().unwrap() as f64,
                 })
@@ -155,13 +178,20 @@ fn build_tables(
         .collect::<Vec<_>>();
 
     for (feature, crate_) in dataset.features.iter() {
-        if columns.iter().any(|&c| crate_.benches.contains_key(c)) {
+        if let Some(reason) = crate_.skipped.as_ref().filter(|_| show_skipped) {
+            let cells = format!(" *skipped: {}* |", reason.replace('|', "\\|"))
+                + &" |".repeat(columns.len() - 1);
+            for output in [&mut data, &mut comparison] {
+                write_crate_row(output, feature, features)?;
+                writeln!(output, "{cells}")?;
+            }
+        } else if columns.iter().any(|&c| column_bench(crate_, c).is_some()) {
             write_crate_row(&mut data, feature, features)?;
             write_crate_row(&mut comparison, feature, features)?;
 
             for (&column, &min) in columns.iter().zip(mins.iter()) {
-                if let Some(bench) = crate_.benches.get(column) {
-                    match bench {
+                if let Some(bench) = column_bench(crate_, column) {
+                    match bench.as_ref() {
                         Bench::Nanos(values) => {
                             format_values(values, &mut data, Nanos)?;
                             format_values(values, &mut comparison, |x| Percent(min, x))?;
@@ -188,6 +218,162 @@ fn build_tables(
     })
 }
 
+/// Builds a table of how long `bench` took per element at each scale of a `BENCH_SCALE` sweep.
+fn build_scaling_table(
+    features: &Features,
+    datasets: &[(usize, &Dataset)],
+    bench: &str,
+) -> Result<String, fmt::Error> {
+    let mut table = "| Crate |".to_string();
+    for (scale, _) in datasets.iter() {
+        write!(&mut table, " {scale} |")?;
+    }
+    write!(&mut table, "\n|---|")?;
+    for _ in datasets.iter() {
+        write!(&mut table, "--:|")?;
+    }
+    writeln!(&mut table)?;
+
+    let crates = datasets
+        .iter()
+        .flat_map(|(_, dataset)| dataset.features.keys())
+        .collect::<BTreeSet<_>>();
+    for feature in crates {
+        let per_element = datasets
+            .iter()
+            .map(
+                |&(scale, dataset)| match dataset.features.get(feature)?.benches.get(bench)? {
+                    Bench::Nanos(values) => values
+                        .iter()
+                        .cloned()
+                        .reduce(f64::min)
+                        .map(|nanos| nanos / scale as f64),
+                    Bench::Bytes(_) => None,
+                },
+            )
+            .collect::<Vec<_>>();
+        if per_element.iter().all(Option::is_none) {
+            continue;
+        }
+
+        write_crate_row(&mut table, feature, features)?;
+        for nanos in per_element {
+            match nanos {
+                Some(nanos) => write!(&mut table, " {} |", Nanos(nanos))?,
+                None => write!(&mut table, " † |")?,
+            }
+        }
+        writeln!(&mut table)?;
+    }
+
+    Ok(table)
+}
+
+/// Builds a table of the mean and relative standard deviation of each column over the seeds of a
+/// `BENCH_SEEDS` run.
+fn build_variation_table(
+    features: &Features,
+    datasets: &[&Dataset],
+    columns: &[&str],
+) -> Result<String, fmt::Error> {
+    let mut table = "| Crate |".to_string();
+    for column in columns.iter() {
+        write!(&mut table, " {} |", capitalize(column))?;
+    }
+    write!(&mut table, "\n|---|")?;
+    for _ in columns.iter() {
+        write!(&mut table, "--:|")?;
+    }
+    writeln!(&mut table)?;
+
+    let crates = datasets
+        .iter()
+        .flat_map(|dataset| dataset.features.keys())
+        .collect::<BTreeSet<_>>();
+    for feature in crates {
+        if !datasets.iter().any(|dataset| {
+            dataset
+                .features
+                .get(feature)
+                .is_some_and(|f| !f.benches.is_empty())
+        }) {
+            continue;
+        }
+
+        write_crate_row(&mut table, feature, features)?;
+        for &column in columns.iter() {
+            let benches = datasets
+                .iter()
+                .filter_map(|dataset| dataset.features.get(feature)?.benches.get(column))
+                .collect::<Vec<_>>();
+            let samples = benches
+                .iter()
+                .map(|bench| match bench {
+                    Bench::Nanos(values) => values.iter().cloned().reduce(f64::min).unwrap(),
+                    Bench::Bytes(values) => values.iter().cloned().min().unwrap() as f64,
+                })
+                .collect::<Vec<_>>();
+            if samples.len() < 2 {
+                write!(&mut table, " |")?;
+                continue;
+            }
+
+            let n = samples.len() as f64;
+            let mean = samples.iter().sum::<f64>() / n;
+            let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            let deviation = Percent(variance.sqrt(), mean);
+            match benches[0] {
+                Bench::Nanos(_) => write!(&mut table, " {} ± {deviation} |", Nanos(mean))?,
+                Bench::Bytes(_) => write!(
+                    &mut table,
+                    " {} ± {deviation} |",
+                    Bytes(mean.round() as u64)
+                )?,
+            }
+        }
+        writeln!(&mut table)?;
+    }
+
+    Ok(table)
+}
+
+/// Builds a table of whether each crate rejected the adversarial messages, with a row for each set of
+/// limits it read them with.
+fn build_adversarial_table(
+    features: &Features,
+    adversarial: &BTreeMap<String, Adversarial>,
+) -> Result<String, fmt::Error> {
+    let messages = adversarial
+        .values()
+        .flat_map(|limits| limits.values())
+        .flat_map(|outcomes| outcomes.keys())
+        .collect::<BTreeSet<_>>();
+
+    let mut table = "| Crate | Limits |".to_string();
+    for message in messages.iter() {
+        write!(&mut table, " {} |", capitalize(message))?;
+    }
+    write!(&mut table, "\n|---|---|")?;
+    for _ in messages.iter() {
+        write!(&mut table, "---|")?;
+    }
+    writeln!(&mut table)?;
+
+    for (feature, limits) in adversarial.iter() {
+        for (limits, outcomes) in limits.iter() {
+            write_crate_row(&mut table, feature, features)?;
+            write!(&mut table, " {limits} |")?;
+            for &message in messages.iter() {
+                let outcome = outcomes.get(message).map_or("", String::as_str);
+                write!(&mut table, " {} |", outcome.replace('|', "\\|"))?;
+            }
+            writeln!(&mut table)?;
+        }
+    }
+
+    Ok(table)
+}
+
 fn format(
     results: &Results,
     config: &Config,
@@ -197,12 +383,17 @@ fn format(
     const SERDE_COLS: &[&str] = &[
         "serialize",
         "deserialize",
+        "project",
+        "read_random",
+        "serialize_p99",
+        "deserialize_p99",
         "size",
         "zlib",
         "zstd",
         "zstd_time",
     ];
-    const ZCD_COLS: &[&str] = &["access", "read", "update"];
+    const ZCD_COLS: &[&str] = &["access", "read", "read_random", "update"];
+    const VARIATION_COLS: &[&str] = &["serialize", "deserialize", "size", "zlib", "zstd"];
 
     let mut runtime_info = format!(
         "\
@@ -230,8 +421,8 @@ fn format(
     let mut tables = String::new();
 
     for (dataset_name, dataset) in results.datasets.iter() {
-        let serde_tables = build_tables(&results.features, dataset, SERDE_COLS, "†")?;
-        let zcd_tables = build_tables(&results.features, dataset, ZCD_COLS, "‡")?;
+        let serde_tables = build_tables(&results.features, dataset, SERDE_COLS, "†", true)?;
+        let zcd_tables = build_tables(&results.features, dataset, ZCD_COLS, "‡", false)?;
 
         write!(
             &mut tables,
@@ -267,7 +458,7 @@ fn format(
             ",
             config
                 .descriptions
-                .get(dataset_name)
+                .get(base_dataset_name(dataset_name))
                 .map(|desc| desc.as_str())
                 .unwrap_or("Missing dataset description"),
             serde_tables.header,
@@ -281,11 +472,84 @@ fn format(
         )?;
     }
 
+    // Data sets generated at several scales by a `BENCH_SCALE` sweep
+    let mut sweeps = BTreeMap::<String, Vec<(usize, &Dataset)>>::new();
+    for (dataset_name, dataset) in results.datasets.iter() {
+        let base_name = base_dataset_name(dataset_name);
+        let (unseeded_name, seed) = split_seed(dataset_name);
+        if let Some(scale) = dataset.scale.filter(|_| base_name != unseeded_name) {
+            sweeps
+                .entry(format!("{base_name}{seed}"))
+                .or_default()
+                .push((scale, dataset));
+        }
+    }
+    for (sweep_name, datasets) in sweeps.iter_mut().filter(|(_, d)| d.len() > 1) {
+        datasets.sort_by_key(|&(scale, _)| scale);
+        write!(
+            &mut tables,
+            "\
+            ## `{sweep_name}` scaling\n\
+            \n\
+            Time per element at each number of elements. Lower is better.\n\
+            \n\
+            ### Serialize\n\
+            \n\
+            {}\n\
+            ### Deserialize\n\
+            \n\
+            {}\n\
+            ",
+            build_scaling_table(&results.features, datasets, "serialize")?,
+            build_scaling_table(&results.features, datasets, "deserialize")?,
+        )?;
+    }
+
+    // Data sets generated from several seeds by a `BENCH_SEEDS` run
+    let mut seed_runs = BTreeMap::<&str, Vec<&Dataset>>::new();
+    for (dataset_name, dataset) in results.datasets.iter() {
+        let (unseeded_name, seed) = split_seed(dataset_name);
+        if !seed.is_empty() {
+            seed_runs.entry(unseeded_name).or_default().push(dataset);
+        }
+    }
+    for (unseeded_name, datasets) in seed_runs.iter().filter(|(_, d)| d.len() > 1) {
+        write!(
+            &mut tables,
+            "\
+            ## `{unseeded_name}` seed variation\n\
+            \n\
+            Mean over {} seeds, ± relative standard deviation. A large deviation means that the \
+            crate's results depend on the particular values it was given.\n\
+            \n\
+            {}\n\
+            ",
+            datasets.len(),
+            build_variation_table(&results.features, datasets, VARIATION_COLS)?,
+        )?;
+    }
+
+    if !results.adversarial.is_empty() {
+        write!(
+            &mut tables,
+            "\
+            ## Adversarial messages\n\
+            \n\
+            Whether validation rejects a message nested far deeper than any of the data sets and one \
+            that claims far more elements than it has room for.\n\
+            \n\
+            {}\n\
+            ",
+            build_adversarial_table(&results.features, &results.adversarial)?,
+        )?;
+    }
+
     let mut links = String::new();
     let features = results
         .datasets
         .values()
         .flat_map(|dataset| dataset.features.keys())
+        .chain(results.adversarial.keys())
         .collect::<BTreeSet<_>>();
     for &feature in features.iter() {
         write!(
@@ -3,7 +3,7 @@ use clap::Parser;
 use regex::Regex;
 use std::{fs, path::PathBuf};
 
-use schema::{Bench, Config, Dataset, Feature, PackageId, Results};
+use schema::{Bench, Config, Dataset, Feature, Latency, PackageId, Results};
 
 #[derive(Parser, Debug)]
 #[command(name = "parser")]
@@ -49,8 +49,19 @@ fn main() {
     let time_benches_re = Regex::new(
         r"(?m)^([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/([a-z0-9_\-]+)(?: \(([a-z0-9_\-+ ]*)\))?\s+time:   \[\d+\.\d+ [µnm]s (\d+\.\d+ [µnm]s)"
     ).unwrap();
-    let size_benches_re =
-        Regex::new(r"(?m)^([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/(size|zlib|zstd) (\d+)").unwrap();
+    let size_benches_re = Regex::new(
+        r"(?m)^([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/(size|zlib|zstd)(?: \(([a-z0-9_\-+ ]*)\))? (\d+)",
+    )
+    .unwrap();
+    let latency_benches_re = Regex::new(
+        r"(?m)^([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/([a-z0-9_\-]+)(?: \(([a-z0-9_\-+ ]*)\))?\/(p50|p90|p99|p999) (\d+)"
+    ).unwrap();
+    let skipped_benches_re =
+        Regex::new(r"(?m)^([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/skipped (.+)$").unwrap();
+    let scale_re = Regex::new(r"(?m)^([a-z0-9_\-]+)\/scale (\d+)$").unwrap();
+    let seed_re = Regex::new(r"(?m)^([a-z0-9_\-]+)\/seed (\d+)$").unwrap();
+    let adversarial_re =
+        Regex::new(r"(?m)^([a-z0-9_\-]+)\/adversarial ([a-z ]+) with ([a-z ]+): (.+)$").unwrap();
 
     let mut results = Results {
         cpu_info,
@@ -107,7 +118,80 @@ fn main() {
             .entry(capture[3].to_string())
             .or_insert(Bench::bytes());
         let values = bench.unwrap_bytes();
-        values.primary = Some(capture[4].parse().unwrap());
+        let value = capture[5].parse().unwrap();
+        if let Some(variant) = capture.get(4) {
+            values.variants.insert(variant.as_str().to_string(), value);
+        } else {
+            values.primary = Some(value);
+        }
+    }
+
+    for capture in latency_benches_re.captures_iter(&log) {
+        let feature = &capture[2];
+        results
+            .features
+            .entry(feature.to_string())
+            .or_insert_with(|| find_package_id(feature, &config, &metadata));
+
+        let dataset = results.datasets.entry(capture[1].to_string()).or_default();
+        let package = dataset.features.entry(feature.to_string()).or_default();
+        let values = package.latencies.entry(capture[3].to_string()).or_default();
+
+        let latency = if let Some(variant) = capture.get(4) {
+            values
+                .variants
+                .entry(variant.as_str().to_string())
+                .or_default()
+        } else {
+            values.primary.get_or_insert_with(Latency::default)
+        };
+
+        let value = capture[6].parse().unwrap();
+        match &capture[5] {
+            "p50" => latency.p50 = value,
+            "p90" => latency.p90 = value,
+            "p99" => latency.p99 = value,
+            "p999" => latency.p999 = value,
+            _ => unreachable!(),
+        }
+    }
+
+    for capture in skipped_benches_re.captures_iter(&log) {
+        let feature = &capture[2];
+        results
+            .features
+            .entry(feature.to_string())
+            .or_insert_with(|| find_package_id(feature, &config, &metadata));
+
+        let dataset = results.datasets.entry(capture[1].to_string()).or_default();
+        let package = dataset.features.entry(feature.to_string()).or_default();
+        package.skipped = Some(capture[3].to_string());
+    }
+
+    for capture in scale_re.captures_iter(&log) {
+        let dataset = results.datasets.entry(capture[1].to_string()).or_default();
+        dataset.scale = Some(capture[2].parse().unwrap());
+    }
+
+    for capture in seed_re.captures_iter(&log) {
+        let dataset = results.datasets.entry(capture[1].to_string()).or_default();
+        dataset.seed = Some(capture[2].parse().unwrap());
+    }
+
+    for capture in adversarial_re.captures_iter(&log) {
+        let feature = &capture[1];
+        results
+            .features
+            .entry(feature.to_string())
+            .or_insert_with(|| find_package_id(feature, &config, &metadata));
+
+        results
+            .adversarial
+            .entry(feature.to_string())
+            .or_default()
+            .entry(capture[3].to_string())
+            .or_default()
+            .insert(capture[2].to_string(), capture[4].to_string());
     }
 
     fs::write(args.output, serde_json::to_string(&results).unwrap()).unwrap();
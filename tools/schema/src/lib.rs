@@ -35,21 +35,52 @@ pub struct Results {
     pub rustc_info: String,
     pub datasets: BTreeMap<String, Dataset>,
     pub features: Features,
+    /// How each crate's validation handled the adversarial messages. These don't depend on the data
+    /// set, so they're recorded once per crate.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub adversarial: BTreeMap<String, Adversarial>,
 }
 
 pub type Features = BTreeMap<String, PackageId>;
 
+/// The outcome of reading each adversarial message, keyed by the limits it was read with and then by
+/// the message. An outcome is either `accepted` or `rejected (<error>)`.
+pub type Adversarial = BTreeMap<String, BTreeMap<String, String>>;
+
 #[derive(Default, Deserialize, Serialize)]
 pub struct Dataset {
+    /// How many elements the data set was generated with, if the run recorded it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<usize>,
+    /// The PCG state the data set was generated from, if the run recorded it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
     pub features: BTreeMap<String, Feature>,
 }
 
+/// Strips the `-<count>`, `-seed<state>` and `-corpus` suffixes that `BENCH_SCALE`, `BENCH_SEEDS` and
+/// `BENCH_CORPUS` runs give their data sets, leaving the name of the data set they stand in for.
+pub fn base_dataset_name(name: &str) -> &str {
+    name.split_once('-').map_or(name, |(base, _)| base)
+}
+
+/// Splits a data set name into the part before its `-seed<state>` suffix and the suffix itself,
+/// which is empty if the data set wasn't part of a `BENCH_SEEDS` run.
+pub fn split_seed(name: &str) -> (&str, &str) {
+    name.rfind("-seed").map_or((name, ""), |i| name.split_at(i))
+}
+
 #[derive(Default, Deserialize, Serialize)]
 pub struct Feature {
     pub benches: HashMap<String, Bench>,
+    #[serde(default)]
+    pub latencies: HashMap<String, Values<Latency>>,
+    /// Why the crate wasn't benchmarked on the dataset, if it couldn't round-trip it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<String>,
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub enum Bench {
     Nanos(Values<f64>),
     Bytes(Values<u64>),
@@ -79,7 +110,7 @@ impl Bench {
     }
 }
 
-#[derive(Default, Deserialize, Serialize)]
+#[derive(Clone, Default, Deserialize, Serialize)]
 pub struct Values<T> {
     pub primary: Option<T>,
     pub variants: BTreeMap<String, T>,
@@ -89,4 +120,24 @@ impl<T> Values<T> {
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.variants.values().chain(self.primary.as_ref())
     }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Values<U> {
+        Values {
+            primary: self.primary.as_ref().map(&mut f),
+            variants: self
+                .variants
+                .iter()
+                .map(|(k, v)| (k.clone(), f(v)))
+                .collect(),
+        }
+    }
+}
+
+/// Per-iteration latency percentiles in nanoseconds.
+#[derive(Clone, Copy, Default, Deserialize, Serialize)]
+pub struct Latency {
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+    pub p999: f64,
 }